@@ -0,0 +1,81 @@
+//! Serde helpers for the date and time columns, used as `#[serde(with = "...")]`.
+//!
+//! Dates are stored as days since 1970-01-01 and times as microseconds since midnight, the values
+//! of the Date32 and Time64 columns. Going through integers rather than chrono's own serde impls,
+//! which parse strings, keeps the fields traceable by `Table::fields`.
+
+use chrono::{NaiveDate, NaiveTime, Timelike};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const EPOCH: NaiveDate = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+
+fn to_days(date: &NaiveDate) -> i32 {
+    date.signed_duration_since(EPOCH).num_days() as i32
+}
+
+fn from_days<E: serde::de::Error>(days: i32) -> Result<NaiveDate, E> {
+    EPOCH
+        .checked_add_signed(chrono::Duration::days(days.into()))
+        .ok_or_else(|| E::custom(format!("date out of range: {days} days")))
+}
+
+fn to_micros(time: &NaiveTime) -> i64 {
+    i64::from(time.num_seconds_from_midnight()) * 1_000_000 + i64::from(time.nanosecond() / 1_000)
+}
+
+fn from_micros<E: serde::de::Error>(micros: i64) -> Result<NaiveTime, E> {
+    let seconds = u32::try_from(micros.div_euclid(1_000_000)).map_err(E::custom)?;
+    let nanoseconds = micros.rem_euclid(1_000_000) as u32 * 1_000;
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanoseconds)
+        .ok_or_else(|| E::custom(format!("time out of range: {micros} µs")))
+}
+
+pub mod date {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+        to_days(date).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDate, D::Error> {
+        from_days(i32::deserialize(deserializer)?)
+    }
+}
+
+pub mod optional_date {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        date: &Option<NaiveDate>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        date.as_ref().map(to_days).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NaiveDate>, D::Error> {
+        Option::<i32>::deserialize(deserializer)?
+            .map(from_days)
+            .transpose()
+    }
+}
+
+pub mod optional_time {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        time: &Option<NaiveTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        time.as_ref().map(to_micros).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NaiveTime>, D::Error> {
+        Option::<i64>::deserialize(deserializer)?
+            .map(from_micros)
+            .transpose()
+    }
+}
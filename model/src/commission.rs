@@ -0,0 +1,48 @@
+use crate::Table;
+use arrow::datatypes::{DataType, TimeUnit};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// A commission meeting. Only the ids are set for meetings whose report does not exist.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commission {
+    pub session_id: String,
+    pub commission_id: String,
+    #[serde(with = "crate::column::optional_date")]
+    pub date: Option<NaiveDate>,
+    pub time_of_day: Option<String>,
+    #[serde(with = "crate::column::optional_time")]
+    pub start_time: Option<NaiveTime>,
+    #[serde(with = "crate::column::optional_time")]
+    pub end_time: Option<NaiveTime>,
+    /// Name of the commission, e.g. "GezondheidEnGelijkeKansen".
+    pub commission: Option<String>,
+    pub chair: Vec<String>,
+}
+
+impl Table for Commission {
+    const FILE_NAME: &'static str = "commissions.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[
+        ("date", DataType::Date32),
+        ("start_time", DataType::Time64(TimeUnit::Microsecond)),
+        ("end_time", DataType::Time64(TimeUnit::Microsecond)),
+    ];
+}
+
+/// An oral question in a commission meeting. The columns are those of `Question`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommissionQuestion {
+    pub question_id: String,
+    pub session_id: String,
+    /// The `commission_id` of the meeting.
+    pub meeting_id: String,
+    pub questioners: Vec<String>,
+    pub respondents: Vec<String>,
+    pub topics_nl: Vec<String>,
+    pub topics_fr: Vec<String>,
+    pub dossier_ids: Vec<String>,
+}
+
+impl Table for CommissionQuestion {
+    const FILE_NAME: &'static str = "commission_questions.parquet";
+}
@@ -0,0 +1,39 @@
+use crate::Table;
+use serde::{Deserialize, Serialize};
+
+/// A speech turn in a plenary meeting, or in the debate on a question in a commission meeting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Utterance {
+    pub session_id: String,
+    /// The `meeting_id` of the plenary meeting, or the `commission_id` of the commission meeting.
+    pub meeting_id: String,
+    /// "plenary" or "commission".
+    pub meeting_type: String,
+    /// The `question_id` in `questions.parquet` or `commission_questions.parquet`. Null for the
+    /// agenda items of a plenary meeting that are not a question.
+    pub question_id: Option<String>,
+    /// Position of the turn in the debate or agenda item, starting at 0.
+    pub sequence: i32,
+    /// The speaker as labelled in the report: "Alexia Bertrand (Open Vld)", "Minister Jan Jambon",
+    /// "De voorzitter".
+    pub speaker: String,
+    /// The `member_id` in `members.parquet`, null when the speaker is not a member or could not be
+    /// matched.
+    pub member_id: Option<String>,
+    /// Name of the minister or secretary of state who spoke, null for other speakers.
+    pub minister: Option<String>,
+    /// "chair", "minister" or "member".
+    pub role: String,
+    /// "nl" or "fr", detected from the text. Null when it could not be told.
+    pub language: Option<String>,
+    pub paragraphs: Vec<String>,
+    /// Number of the turn in the report, "07.01" for the first turn of item 7. Null for most turns
+    /// of the chair, which are not numbered.
+    pub marker: Option<String>,
+    /// The `item_id` in `agenda_items.parquet`. Null in commission meetings.
+    pub item_id: Option<String>,
+}
+
+impl Table for Utterance {
+    const FILE_NAME: &'static str = "utterances.parquet";
+}
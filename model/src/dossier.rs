@@ -0,0 +1,429 @@
+use crate::Table;
+use arrow::datatypes::DataType;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A dossier (bill, resolution, ...) referenced by a vote or proposition.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dossier {
+    pub session_id: String,
+    pub id: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    /// Null when the dossier page does not mention the date.
+    #[serde(with = "crate::column::optional_date")]
+    pub submission_date: Option<NaiveDate>,
+    #[serde(with = "crate::column::optional_date")]
+    pub end_date: Option<NaiveDate>,
+    #[serde(with = "crate::column::optional_date")]
+    pub vote_date: Option<NaiveDate>,
+    /// The name of its `DocumentType`: "WetsOntwerp". "Onbekend" when the page gives no type, and
+    /// the type as written on the page ("99 NIEUW TYPE") when it is not a known one.
+    pub document_type: String,
+    /// The name of its `DocumentStatus`: "Aangenomen". "Onbekend" when the page gives no status,
+    /// and the status as written on the page when it is not a known one.
+    pub status: String,
+    /// The first committee of the Chamber the dossier was sent to, as named on the dossier page:
+    /// "BINNENLANDSE ZAKEN, VEILIGHEID, MIGRATIE EN BESTUURSZAKEN". Null when it was not sent to a
+    /// committee.
+    pub committee: Option<String>,
+    /// The legislative procedure of the Constitution the dossier follows: "monocameral" (article
+    /// 74), "bicameral" (article 77) or "optional_bicameral" (article 78, the Senate can evoke it).
+    /// Null when the page does not state it.
+    pub procedure: Option<String>,
+}
+
+impl Table for Dossier {
+    const FILE_NAME: &'static str = "dossiers.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[
+        ("submission_date", DataType::Date32),
+        ("end_date", DataType::Date32),
+        ("vote_date", DataType::Date32),
+    ];
+}
+
+/// The type of a dossier or of a document in it. Dossier pages give the type of a dossier with its
+/// code ("02 WETSONTWERP") and the type of a document by its label only ("AMENDEMENT").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentType {
+    Begroting,
+    WetsOntwerp,
+    OvergezondenOntwerp,
+    WetsVoorstel,
+    VoorstelVanResolutie,
+    VoorstelTotHerziening,
+    Kaft,
+    VoorstelOnderzoekscommissie,
+    VoorstelReglement,
+    Verslag,
+    NietGeevoceerdOntwerp,
+    AanvullendVerslag,
+    VerwijzendVerslag,
+    Amendement,
+    Advies,
+    AdviesVanDeRaadVanState,
+    OpmerkingenVanHetRekenhof,
+    ArtikelenBijEersteStemmingAangenomen,
+    ArtikelenAangenomenInPlenum,
+    AangenomenTekst,
+    Beleidsnota,
+    Verantwoording,
+    TabellenOfLijsten,
+    Naturalisatielijsten,
+    Bijlage,
+    Errata,
+    /// The page gives no type. Named like `DocumentStatus::Onbekend`.
+    Onbekend,
+}
+
+impl DocumentType {
+    /// Every type with its code, if it has one, and its Dutch and French label.
+    ///
+    /// The codes are those the dossier pages of session 56 give for the type of a dossier; the
+    /// types of documents in a dossier are given by their label only and have none. A code missing
+    /// here is looked up by its label, and a type whose label is missing too is kept as written.
+    const ALL: &[(DocumentType, Option<u32>, &str, &str)] = &[
+        (DocumentType::Begroting, Some(1), "BEGROTING", "BUDGET"),
+        (
+            DocumentType::WetsOntwerp,
+            Some(2),
+            "WETSONTWERP",
+            "PROJET DE LOI",
+        ),
+        (
+            DocumentType::OvergezondenOntwerp,
+            Some(3),
+            "OVERGEZONDEN ONTWERP",
+            "PROJET TRANSMIS",
+        ),
+        (
+            DocumentType::WetsVoorstel,
+            Some(5),
+            "WETSVOORSTEL",
+            "PROPOSITION DE LOI",
+        ),
+        (
+            DocumentType::VoorstelVanResolutie,
+            Some(6),
+            "VOORSTEL VAN RESOLUTIE",
+            "PROPOSITION DE RÉSOLUTION",
+        ),
+        (
+            DocumentType::VoorstelTotHerziening,
+            Some(8),
+            "VOORSTEL TOT HERZIENING",
+            "PROPOSITION DE RÉVISION",
+        ),
+        (DocumentType::Kaft, Some(19), "KAFT", "COUVERTURE"),
+        (
+            DocumentType::VoorstelOnderzoekscommissie,
+            Some(20),
+            "VOORSTEL ONDERZOEKSCOMMISSIE",
+            "PROPOSITION COMMISSION D'ENQUÊTE",
+        ),
+        (
+            DocumentType::VoorstelReglement,
+            Some(21),
+            "VOORSTEL REGLEMENT",
+            "PROPOSITION RÈGLEMENT",
+        ),
+        (DocumentType::Verslag, Some(23), "VERSLAG", "RAPPORT"),
+        (
+            DocumentType::NietGeevoceerdOntwerp,
+            Some(68),
+            "NIET-GEEVOCEERD ONTWERP",
+            "PROJET NON ÉVOQUÉ",
+        ),
+        (
+            DocumentType::AanvullendVerslag,
+            None,
+            "AANVULLEND VERSLAG",
+            "RAPPORT COMPLÉMENTAIRE",
+        ),
+        (
+            DocumentType::VerwijzendVerslag,
+            None,
+            "VERSLAG (VERWIJZEND)",
+            "RAPPORT (RENVOI)",
+        ),
+        (DocumentType::Amendement, None, "AMENDEMENT", "AMENDEMENT"),
+        (DocumentType::Advies, None, "ADVIES", "AVIS"),
+        (
+            DocumentType::AdviesVanDeRaadVanState,
+            None,
+            "ADVIES VAN DE RAAD VAN STATE",
+            "AVIS DU CONSEIL D'ÉTAT",
+        ),
+        (
+            DocumentType::OpmerkingenVanHetRekenhof,
+            None,
+            "OPMERKINGEN VAN HET REKENHOF",
+            "COMMENTAIRES DE LA COUR DES COMPTES",
+        ),
+        (
+            DocumentType::ArtikelenBijEersteStemmingAangenomen,
+            None,
+            "ARTIKELEN BIJ 1E STEMMING AANGENOMEN",
+            "ARTICLES ADOPTÉS EN PREMIÈRE LECTURE",
+        ),
+        (
+            DocumentType::ArtikelenAangenomenInPlenum,
+            None,
+            "ARTIKELEN AANGENOMEN IN PLENUM",
+            "ARTICLES ADOPTÉS EN SÉANCE PLÉNIÈRE",
+        ),
+        (
+            DocumentType::AangenomenTekst,
+            None,
+            "AANGENOMEN TEKST",
+            "TEXTE ADOPTÉ",
+        ),
+        (
+            DocumentType::Beleidsnota,
+            None,
+            "BELEIDSNOTA",
+            "NOTE DE POLITIQUE GÉNÉRALE",
+        ),
+        (
+            DocumentType::Verantwoording,
+            None,
+            "VERANTWOORDING",
+            "JUSTIFICATION",
+        ),
+        (
+            DocumentType::TabellenOfLijsten,
+            None,
+            "TABELLEN OF LIJSTEN",
+            "TABLEAUX OU LISTES",
+        ),
+        (
+            DocumentType::Naturalisatielijsten,
+            None,
+            "NATURALISATIELIJSTEN",
+            "LISTES DE NATURALISATIONS",
+        ),
+        (DocumentType::Bijlage, None, "BIJLAGE", "ANNEXE"),
+        (DocumentType::Errata, None, "ERRATA", "ERRATA"),
+        (DocumentType::Onbekend, None, "ONBEKEND", "INCONNU"),
+    ];
+
+    /// Parses a type as written on a dossier page by its code ("02 WETSONTWERP") or else by its
+    /// Dutch or French label. `None` when it is not a known one.
+    pub fn parse(text: &str) -> Option<DocumentType> {
+        parse_coded(Self::ALL, text)
+    }
+
+    /// The code of the type on dossier pages: 2 for "02 WETSONTWERP". `None` for the types of
+    /// documents in a dossier, which pages give without one.
+    pub fn code(self) -> Option<u32> {
+        find(Self::ALL, self).1
+    }
+
+    /// The label on Dutch dossier pages, in capitals as there.
+    pub fn label_nl(self) -> &'static str {
+        find(Self::ALL, self).2
+    }
+
+    /// The label on French dossier pages.
+    pub fn label_fr(self) -> &'static str {
+        find(Self::ALL, self).3
+    }
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The status of a dossier, given with its code on the dossier page: "16 AANGENOMEN".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentStatus {
+    ZonderVoorwerp,
+    Verworpen,
+    Aangenomen,
+    Ingetrokken,
+    Vervallen,
+    /// The page gives no status, as for dossiers still being handled.
+    Onbekend,
+}
+
+impl DocumentStatus {
+    /// Every status with its code, if it has one, and its Dutch and French label.
+    ///
+    /// The codes are those the dossier pages of session 56 give, which only use 14, 15 and 16. The
+    /// pages of withdrawn and lapsed dossiers have not been seen, so those statuses are looked up
+    /// by their label until their code is known.
+    const ALL: &[(DocumentStatus, Option<u32>, &str, &str)] = &[
+        (
+            DocumentStatus::ZonderVoorwerp,
+            Some(14),
+            "ZONDER VOORWERP",
+            "SANS OBJET",
+        ),
+        (DocumentStatus::Verworpen, Some(15), "VERWORPEN", "REJETÉ"),
+        (DocumentStatus::Aangenomen, Some(16), "AANGENOMEN", "ADOPTÉ"),
+        (DocumentStatus::Ingetrokken, None, "INGETROKKEN", "RETIRÉ"),
+        (DocumentStatus::Vervallen, None, "VERVALLEN", "CADUC"),
+        (DocumentStatus::Onbekend, None, "ONBEKEND", "INCONNU"),
+    ];
+
+    /// Parses a status as written on a dossier page by its code ("16 AANGENOMEN") or else by its
+    /// Dutch or French label. `None` when it is not a known one.
+    pub fn parse(text: &str) -> Option<DocumentStatus> {
+        parse_coded(Self::ALL, text)
+    }
+
+    /// The code of the status on dossier pages: 16 for "16 AANGENOMEN".
+    pub fn code(self) -> Option<u32> {
+        find(Self::ALL, self).1
+    }
+
+    /// The label on Dutch dossier pages, in capitals as there.
+    pub fn label_nl(self) -> &'static str {
+        find(Self::ALL, self).2
+    }
+
+    /// The label on French dossier pages.
+    pub fn label_fr(self) -> &'static str {
+        find(Self::ALL, self).3
+    }
+}
+
+impl fmt::Display for DocumentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The entry of `value` in one of the `ALL` lists, which have one for every variant.
+fn find<T: Copy + PartialEq>(
+    all: &[(T, Option<u32>, &'static str, &'static str)],
+    value: T,
+) -> (T, Option<u32>, &'static str, &'static str) {
+    *all.iter().find(|entry| entry.0 == value).unwrap()
+}
+
+/// Looks up "02 WETSONTWERP" by its code, or by its label when the code is missing or unknown.
+fn parse_coded<T: Copy>(all: &[(T, Option<u32>, &str, &str)], text: &str) -> Option<T> {
+    let text = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase();
+    let (code, label) = match text.split_once(' ') {
+        Some((code, label)) if code.chars().all(|c| c.is_ascii_digit()) => {
+            (code.parse().ok(), label)
+        }
+        _ => (None, text.as_str()),
+    };
+    all.iter()
+        .find(|entry| code.is_some() && entry.1 == code)
+        .or_else(|| {
+            all.iter()
+                .find(|entry| entry.2 == label || entry.3 == label)
+        })
+        .map(|entry| entry.0)
+}
+
+/// A document in a dossier: the bill itself, amendments, reports, adopted texts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subdocument {
+    pub dossier_id: String,
+    pub id: String,
+    #[serde(with = "crate::column::optional_date")]
+    pub date: Option<NaiveDate>,
+    /// The name of its `DocumentType`, or the type as written on the page when it is not a known
+    /// one.
+    #[serde(rename = "type")]
+    pub document_type: String,
+    pub authors: Vec<String>,
+    /// Session of the dossier. Last, so the columns before it keep their position.
+    pub session_id: String,
+}
+
+impl Table for Subdocument {
+    const FILE_NAME: &'static str = "subdocuments.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[("date", DataType::Date32)];
+}
+
+/// A step in the procedure of a dossier, from the dossier page: its submission, the calendar of
+/// each committee and plenary meeting that handled it, its passage through the Senate, the royal
+/// assent and the publication.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DossierEvent {
+    pub session_id: String,
+    pub dossier_id: String,
+    #[serde(with = "crate::column::date")]
+    pub date: NaiveDate,
+    /// "submitted", "taken_into_consideration", "sent_to_committee", "council_of_state_requested",
+    /// "council_of_state_opinion", "discussed", "meeting", "adopted", "rejected", "without_object",
+    /// "without_report", "sent_back", "urgency_adopted", "urgency_rejected", "procedure" (the
+    /// article of the Constitution that applies), "ended", "sent_to_senate", "evoked",
+    /// "not_evoked", "senate_vote", "royal_assent", "published" (in the Belgian Official Journal),
+    /// "erratum_published" or "other".
+    pub kind: String,
+    /// "committee", "plenary" or "senate" for the steps in the calendar of a meeting, null for the
+    /// others.
+    pub stage: Option<String>,
+    /// The committee of a "committee" step.
+    pub committee: Option<String>,
+    /// The step as written on the page: "IN BESPREKING", "STEMMING OVER URGENTIE ===>
+    /// AANGENOMEN/ADOPTÉE".
+    pub description: String,
+    /// The `id` in `subdocuments.parquet` of the opinion of the Council of State, null for the
+    /// other steps.
+    pub document_id: Option<String>,
+}
+
+impl Table for DossierEvent {
+    const FILE_NAME: &'static str = "dossier_events.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[("date", DataType::Date32)];
+}
+
+/// A keyword the Chamber assigned to a dossier.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DossierKeyword {
+    pub session_id: String,
+    pub dossier_id: String,
+    /// As written on the dossier page: "ADMINISTRATIEVE RECHTSPRAAK".
+    pub keyword: String,
+    /// "main_descriptor" (the main Eurovoc descriptor), "descriptor" (the other Eurovoc
+    /// descriptors), "candidate_descriptor" (proposed for Eurovoc) or "free" (free keywords).
+    pub kind: String,
+}
+
+impl Table for DossierKeyword {
+    const FILE_NAME: &'static str = "dossier_keywords.parquet";
+}
+
+/// The text of the PDF of a document in a dossier, extracted by the scraper.
+///
+/// Chamber documents print the Dutch and French text side by side in two columns. Each column of
+/// such a page is assigned to the language it is written in, and a page in one column to its
+/// language as a whole. Columns in neither language, or too short to tell, are kept apart.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DocumentText {
+    pub session_id: String,
+    pub dossier_id: String,
+    /// Number of the document in the dossier: "001" for the main document, the `id` in
+    /// `subdocuments.parquet` for the others.
+    pub document_id: String,
+    /// The path of the PDF on the website: "/FLWB/PDF/56/0456/56K0456001.pdf".
+    pub url: String,
+    pub pages: i32,
+    /// The text of every page in reading order, the columns of a page one after the other.
+    pub text: String,
+    /// The Dutch columns and pages. Null when no page is in Dutch.
+    pub text_nl: Option<String>,
+    /// The French columns and pages. Null when no page is in French.
+    pub text_fr: Option<String>,
+    /// The columns and pages whose language could not be told: page numbers, tables, lists of
+    /// names, text in another language. Null when there are none.
+    pub text_other: Option<String>,
+}
+
+impl Table for DocumentText {
+    const FILE_NAME: &'static str = "document_texts.parquet";
+}
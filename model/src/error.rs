@@ -0,0 +1,60 @@
+use parquet::errors::ParquetError;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be opened or created.
+    Io(io::Error),
+    /// The file is not valid Parquet, or writing it failed.
+    Parquet(ParquetError),
+    /// The rows do not match the columns of the table.
+    Schema(serde_arrow::Error),
+    /// The file was written with another schema version, see `SCHEMA_VERSION`.
+    SchemaVersion(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "io error: {}", error),
+            Error::Parquet(error) => write!(f, "parquet error: {}", error),
+            Error::Schema(error) => write!(f, "schema error: {}", error),
+            Error::SchemaVersion(version) => write!(
+                f,
+                "schema version {} is not supported, expected {}",
+                version,
+                crate::SCHEMA_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            Error::Parquet(error) => Some(error),
+            Error::Schema(error) => Some(error),
+            Error::SchemaVersion(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
+
+impl From<ParquetError> for Error {
+    fn from(error: ParquetError) -> Self {
+        Error::Parquet(error)
+    }
+}
+
+impl From<serde_arrow::Error> for Error {
+    fn from(error: serde_arrow::Error) -> Self {
+        Error::Schema(error)
+    }
+}
@@ -0,0 +1,39 @@
+//! The tables of the dataset as Rust structs, with one Parquet reader and writer per table.
+//!
+//! ```no_run
+//! use model::{Table, Vote};
+//! use std::path::Path;
+//!
+//! let path = Path::new("web/src/data").join(Vote::FILE_NAME);
+//! let votes = Vote::read(&path)?;
+//! Vote::write(&path, &votes)?;
+//! # Ok::<(), model::Error>(())
+//! ```
+
+mod column;
+mod commission;
+mod debate;
+mod dossier;
+mod error;
+mod lobby;
+mod member;
+mod plenary;
+mod session;
+mod summary;
+mod table;
+
+pub use commission::{Commission, CommissionQuestion};
+pub use debate::Utterance;
+pub use dossier::{
+    DocumentStatus, DocumentText, DocumentType, Dossier, DossierEvent, DossierKeyword, Subdocument,
+};
+pub use error::Error;
+pub use lobby::LobbyEntry;
+pub use member::{Member, Remuneration};
+pub use plenary::{
+    AgendaItem, Attendance, Ballot, IdMapping, Meeting, Proposition, Question, Vote,
+    VoteCorrection, VoteExplanation, VoteIssue,
+};
+pub use session::Session;
+pub use summary::Summary;
+pub use table::{SCHEMA_VERSION, SCHEMA_VERSION_KEY, Table, schema_version};
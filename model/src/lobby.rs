@@ -0,0 +1,15 @@
+use crate::Table;
+use serde::{Deserialize, Serialize};
+
+/// An organisation in the lobby register.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LobbyEntry {
+    pub name: String,
+    pub contacts: String,
+    pub interests: String,
+    pub url: Option<String>,
+}
+
+impl Table for LobbyEntry {
+    const FILE_NAME: &'static str = "lobby.parquet";
+}
@@ -0,0 +1,55 @@
+use crate::Table;
+use arrow::datatypes::DataType;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// A member of parliament in a session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Member {
+    pub member_id: String,
+    pub session_id: String,
+    pub first_name: String,
+    pub last_name: String,
+    /// "M" or "F", null when unknown.
+    pub gender: Option<String>,
+    #[serde(with = "crate::column::optional_date")]
+    pub date_of_birth: Option<NaiveDate>,
+    pub place_of_birth: Option<String>,
+    /// Language code, e.g. "nl".
+    pub language: Option<String>,
+    pub constituency: Option<String>,
+    pub party: String,
+    pub fraction: Option<String>,
+    pub email: Option<String>,
+    /// Whether the member is a member of the current session.
+    pub active: bool,
+    /// Start of the mandate, null for members added by hand.
+    #[serde(with = "crate::column::optional_date")]
+    pub start: Option<NaiveDate>,
+}
+
+impl Table for Member {
+    const FILE_NAME: &'static str = "members.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[
+        ("date_of_birth", DataType::Date32),
+        ("start", DataType::Date32),
+    ];
+}
+
+/// A mandate outside parliament declared by a member, with the range its yearly remuneration falls
+/// in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Remuneration {
+    pub first_name: String,
+    pub last_name: String,
+    pub year: i32,
+    pub mandate: String,
+    pub institute: String,
+    /// In euro, null when the range could not be read.
+    pub remuneration_min: Option<i64>,
+    pub remuneration_max: Option<i64>,
+}
+
+impl Table for Remuneration {
+    const FILE_NAME: &'static str = "remunerations.parquet";
+}
@@ -0,0 +1,297 @@
+use crate::Table;
+use arrow::datatypes::{DataType, TimeUnit};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// A plenary meeting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Meeting {
+    pub session_id: String,
+    pub meeting_id: String,
+    #[serde(with = "crate::column::date")]
+    pub date: NaiveDate,
+    /// "morning", "afternoon" or "evening".
+    pub time_of_day: String,
+    /// Null when the report does not mention it.
+    #[serde(with = "crate::column::optional_time")]
+    pub start_time: Option<NaiveTime>,
+    #[serde(with = "crate::column::optional_time")]
+    pub end_time: Option<NaiveTime>,
+}
+
+impl Table for Meeting {
+    const FILE_NAME: &'static str = "meetings.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[
+        ("date", DataType::Date32),
+        ("start_time", DataType::Time64(TimeUnit::Microsecond)),
+        ("end_time", DataType::Time64(TimeUnit::Microsecond)),
+    ];
+}
+
+/// A decision in a plenary meeting: a roll-call vote, or a text adopted without one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vote {
+    /// Number of the roll-call vote as stated in the report ("Stemming/vote 3"). Decisions without
+    /// a roll call have no number and are identified by "d" followed by a hash of their agenda item
+    /// and text.
+    pub vote_id: String,
+    pub session_id: String,
+    pub meeting_id: String,
+    #[serde(with = "crate::column::date")]
+    pub date: NaiveDate,
+    pub title_nl: String,
+    pub title_fr: String,
+    /// Null when there was no roll call.
+    pub yes: Option<i32>,
+    pub no: Option<i32>,
+    pub abstain: Option<i32>,
+    /// Names of the members who voted yes, first name first.
+    pub members_yes: Vec<String>,
+    pub members_no: Vec<String>,
+    pub members_abstain: Vec<String>,
+    /// Null when the vote is not about a dossier.
+    pub dossier_id: Option<String>,
+    pub document_id: Option<String>,
+    /// Null when the vote is not about a motion.
+    pub motion_id: Option<String>,
+    /// "roll_call", "unanimous" (adopted without objection) or "show_of_hands" (by sitting and
+    /// standing). This and the columns after it come last, so the columns before them keep their
+    /// position.
+    pub vote_kind: String,
+    /// "adopted" or "rejected", as stated in the report. Null when the report does not state it.
+    pub outcome: Option<String>,
+    /// Majority the decision needed: "simple", "two_thirds" (revisions of the Constitution) or
+    /// "special" (special laws, which also need a majority in each language group).
+    pub majority: String,
+    /// Whether the names the report lists for this roll-call vote disagree with its tally: a choice
+    /// with a different number of names or a name listed twice. The counts and names are then not
+    /// to be trusted; the scraper reports the details in
+    /// `scraper/data/diagnostics/vote_issues.parquet`, along with names that match no member, which
+    /// do not flag the vote. Missing in files written before the check.
+    #[serde(default)]
+    pub tally_mismatch: bool,
+}
+
+impl Table for Vote {
+    const FILE_NAME: &'static str = "votes.parquet";
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[("date", DataType::Date32)];
+}
+
+/// How one member voted in a roll-call vote.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ballot {
+    pub session_id: String,
+    pub meeting_id: String,
+    pub vote_id: String,
+    /// The `member_id` in `members.parquet`, null when the name could not be matched to a member of
+    /// the session.
+    pub member_id: Option<String>,
+    /// The name as listed in the report, last name first: "Aerts Staf".
+    pub name: String,
+    /// "yes", "no" or "abstain".
+    pub choice: String,
+    /// The choice after the corrections the member made on the record (`vote_corrections.parquet`),
+    /// the same as `choice` when there are none.
+    pub corrected_choice: String,
+}
+
+impl Table for Ballot {
+    const FILE_NAME: &'static str = "vote_ballots.parquet";
+}
+
+/// An oral question, or a group of joined questions, in a plenary meeting. The debate on it is in
+/// `utterances.parquet`, one row per speech turn.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Question {
+    /// Number of the first joined question as given by the Chamber ("56001154P"), or a hash of its
+    /// heading when it has none.
+    pub question_id: String,
+    pub session_id: String,
+    pub meeting_id: String,
+    pub questioners: Vec<String>,
+    pub respondents: Vec<String>,
+    /// One topic per joined question.
+    pub topics_nl: Vec<String>,
+    pub topics_fr: Vec<String>,
+    pub dossier_ids: Vec<String>,
+}
+
+impl Table for Question {
+    const FILE_NAME: &'static str = "questions.parquet";
+}
+
+/// A bill or resolution taken into consideration in a plenary meeting.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Proposition {
+    /// Hash of the heading of the proposition.
+    pub proposition_id: String,
+    pub session_id: String,
+    pub meeting_id: String,
+    pub title_nl: String,
+    pub title_fr: String,
+    /// Null when the proposition does not link to a dossier.
+    pub dossier_id: Option<String>,
+    pub document_id: Option<String>,
+}
+
+impl Table for Proposition {
+    const FILE_NAME: &'static str = "propositions.parquet";
+}
+
+/// An item on the agenda of a plenary meeting, with its speech turns in `utterances.parquet`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgendaItem {
+    pub session_id: String,
+    pub meeting_id: String,
+    /// Number of the item in the report: "07", also the start of the markers of its speech turns
+    /// ("07.01").
+    pub item_id: String,
+    /// "question", "interpellation", "bill" (bills, resolutions and budgets),
+    /// "government_statement", "point_of_order" (the order of business, motions of order and
+    /// personal statements), "vote", "communication" or "other".
+    pub item_type: String,
+    /// The heading of the item, followed by the headings of its joined questions or texts on new
+    /// lines.
+    pub title_nl: String,
+    pub title_fr: String,
+    /// Numbers of the dossiers ("558") and of the questions and interpellations ("56000182I") in
+    /// the headings.
+    pub dossier_ids: Vec<String>,
+    /// The `question_id` in `questions.parquet` when the item is a question, null otherwise.
+    pub question_id: Option<String>,
+}
+
+impl Table for AgendaItem {
+    const FILE_NAME: &'static str = "agenda_items.parquet";
+}
+
+/// Whether a member attended a plenary meeting.
+///
+/// The excused members come from the list in the report ("Berichten van verhindering", "Hebben zich
+/// verontschuldigd"). The other members in office count as present when they cast a ballot in a
+/// roll-call vote of the meeting, so a meeting without a roll call only has rows for its excused
+/// members.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attendance {
+    pub session_id: String,
+    pub meeting_id: String,
+    /// The `member_id` in `members.parquet`, null when an excused name could not be matched to a
+    /// member of the session.
+    pub member_id: Option<String>,
+    /// The name as listed in the report for excused members, the member's name otherwise: "Staf
+    /// Aerts".
+    pub name: String,
+    /// "excused", "voted" or "absent" (in office but neither excused nor on any ballot). A member
+    /// on the excused list is excused even when they voted later in the meeting.
+    pub status: String,
+    /// The reason given for an excused member: "ziek", "buitenslands". Null otherwise or when the
+    /// report gives none.
+    pub reason_nl: Option<String>,
+    pub reason_fr: Option<String>,
+}
+
+impl Table for Attendance {
+    const FILE_NAME: &'static str = "attendance.parquet";
+}
+
+/// The ID a vote, question or proposition had before IDs were derived from the report, when they
+/// were numbered by their position in the meeting. Used to redirect old links.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdMapping {
+    pub session_id: String,
+    pub meeting_id: String,
+    /// "vote", "question" or "proposition".
+    pub kind: String,
+    /// Position of the row in the meeting: "3". Decisions without a roll call never had one.
+    pub old_id: String,
+    pub new_id: String,
+}
+
+impl Table for IdMapping {
+    const FILE_NAME: &'static str = "id_mappings.parquet";
+}
+
+/// What a member said to explain their vote: before the vote, or after it when asked why they
+/// abstained.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteExplanation {
+    pub session_id: String,
+    pub meeting_id: String,
+    /// The `vote_id` in `votes.parquet` of the roll-call vote that is explained. Null when the
+    /// explanation precedes a decision without a roll call.
+    pub vote_id: Option<String>,
+    /// "explanation" (asked for before the vote) or "abstention" (the reason for abstaining, asked
+    /// for after it).
+    pub kind: String,
+    /// The speaker as labelled in the report: "Marijke Dillen (VB)".
+    pub speaker: String,
+    /// The `member_id` in `members.parquet`, null when the speaker could not be matched.
+    pub member_id: Option<String>,
+    /// The party in the label: "VB". Null when the label has none.
+    pub party: Option<String>,
+    pub paragraphs: Vec<String>,
+    /// Number of the turn in the report, "04.01", also its `marker` in `utterances.parquet`.
+    pub marker: Option<String>,
+}
+
+impl Table for VoteExplanation {
+    const FILE_NAME: &'static str = "vote_explanations.parquet";
+}
+
+/// A correction of a ballot stated after the result of a roll-call vote: "(De heer Steven Vandeput
+/// heeft voorgestemd.)".
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteCorrection {
+    pub session_id: String,
+    pub meeting_id: String,
+    pub vote_id: String,
+    /// The `member_id` in `members.parquet`, null when the name could not be matched to a member of
+    /// the session.
+    pub member_id: Option<String>,
+    /// The name as written in the correction, without title: "Steven Vandeput".
+    pub name: String,
+    /// The choice on the member's ballot: "yes", "no" or "abstain". Null when they have no ballot
+    /// in the vote.
+    pub recorded_choice: Option<String>,
+    /// The choice the member meant to make: "yes", "no" or "abstain". Null when they voted like
+    /// their group and their party is unknown, no other member of it voted or its votes are split
+    /// evenly.
+    pub intended_choice: Option<String>,
+    /// Whether the member voted "like their group", whose most common choice is then the intended
+    /// one.
+    pub like_group: bool,
+    /// The correction as written in the report.
+    pub text: String,
+}
+
+impl Table for VoteCorrection {
+    const FILE_NAME: &'static str = "vote_corrections.parquet";
+}
+
+/// A disagreement between the tally of a roll-call vote and the names the report lists for it,
+/// found when checking the votes after scraping. Not published: the vote is flagged with
+/// `tally_mismatch` in `votes.parquet` unless its only issues are unknown names, and these rows tell
+/// what to look at in the report.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteIssue {
+    pub session_id: String,
+    pub meeting_id: String,
+    pub vote_id: String,
+    /// "count_mismatch" (the tally of a choice differs from the number of names listed for it),
+    /// "duplicate_name" (a name is listed more than once in the vote) or "unknown_name" (a name
+    /// matches no member of the session).
+    pub kind: String,
+    /// "yes", "no" or "abstain": the choice whose tally differs, or the list the name is on. For a
+    /// duplicate name, the list of its second occurrence.
+    pub choice: String,
+    /// The tally of the choice in the report. Null for name issues.
+    pub counted: Option<i32>,
+    /// The number of names listed for the choice. Null for name issues.
+    pub listed: Option<i32>,
+    /// The name as listed in the report. Null for count mismatches.
+    pub name: Option<String>,
+}
+
+impl Table for VoteIssue {
+    const FILE_NAME: &'static str = "vote_issues.parquet";
+}
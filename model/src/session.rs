@@ -0,0 +1,16 @@
+use crate::Table;
+use serde::{Deserialize, Serialize};
+
+/// A legislature, e.g. session 56 from 2024.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    pub session_id: String,
+    /// Year the session started.
+    pub start_date: i32,
+    /// Year the session ended, null for the current one.
+    pub end_date: Option<i32>,
+}
+
+impl Table for Session {
+    const FILE_NAME: &'static str = "sessions.parquet";
+}
@@ -0,0 +1,17 @@
+use crate::Table;
+use serde::{Deserialize, Serialize};
+
+/// A generated summary of a question topic list or a dossier title.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Summary {
+    /// SHA-256 of `original`, used to look the summary up.
+    pub input_hash: String,
+    pub original: String,
+    pub summary: String,
+    /// The model that wrote the summary.
+    pub model: String,
+}
+
+impl Table for Summary {
+    const FILE_NAME: &'static str = "summaries.parquet";
+}
@@ -0,0 +1,127 @@
+use crate::Error;
+use arrow::datatypes::{DataType, FieldRef, Schema};
+use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::WriterProperties;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_arrow::schema::{SchemaLike, TracingOptions};
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Version of the column types, stored under `SCHEMA_VERSION_KEY` in the key-value metadata of
+/// every file.
+///
+/// Version 1 stored every column as a required string. Version 2 stores counts as Int32, dates as
+/// Date32, times as Time64, flags as Boolean and multi-valued columns as List<Utf8>, with nulls for
+/// missing values.
+pub const SCHEMA_VERSION: u32 = 2;
+
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// The schema version of the Parquet file at `path`, 1 for files written before the version was
+/// stored.
+pub fn schema_version(path: &Path) -> Result<u32, Error> {
+    let reader = SerializedFileReader::new(File::open(path)?)?;
+    let version = reader
+        .metadata()
+        .file_metadata()
+        .key_value_metadata()
+        .and_then(|entries| entries.iter().find(|entry| entry.key == SCHEMA_VERSION_KEY))
+        .and_then(|entry| entry.value.as_deref())
+        .map(|value| value.parse().unwrap_or_default())
+        .unwrap_or(1);
+    Ok(version)
+}
+
+/// A Parquet file of the dataset, stored as one row per value of the implementing struct.
+///
+/// The columns are the fields of the struct, in order and under their serde names.
+pub trait Table: Serialize + DeserializeOwned {
+    /// Name of the file in the data directory (`web/src/data`), e.g. "votes.parquet".
+    const FILE_NAME: &'static str;
+
+    /// Columns whose type does not follow from the field, such as dates (`DataType::Date32`) and
+    /// times (`DataType::Time64`), which serialize as integers.
+    const COLUMN_TYPES: &'static [(&'static str, DataType)] = &[];
+
+    /// The columns of the table.
+    fn fields() -> Result<Vec<FieldRef>, Error> {
+        let options = TracingOptions::default()
+            .strings_as_large_utf8(false)
+            .sequence_as_large_list(false);
+        let fields = Vec::<FieldRef>::from_type::<Self>(options)?
+            .into_iter()
+            .map(|field| {
+                match Self::COLUMN_TYPES
+                    .iter()
+                    .find(|(name, _)| name == field.name())
+                {
+                    Some((_, data_type)) => field
+                        .as_ref()
+                        .clone()
+                        .with_data_type(data_type.clone())
+                        .into(),
+                    None => field,
+                }
+            })
+            .collect();
+        Ok(fields)
+    }
+
+    /// Reads every row of the file at `path`.
+    ///
+    /// Fails with `Error::SchemaVersion` for files of another schema version.
+    fn read(path: &Path) -> Result<Vec<Self>, Error> {
+        let version = schema_version(path)?;
+        if version != SCHEMA_VERSION {
+            return Err(Error::SchemaVersion(version));
+        }
+
+        let file = File::open(path)?;
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
+
+        let mut rows = Vec::new();
+        for batch in reader {
+            let batch = batch.map_err(parquet::errors::ParquetError::from)?;
+            rows.extend(serde_arrow::from_record_batch::<Vec<Self>>(&batch)?);
+        }
+        Ok(rows)
+    }
+
+    /// Replaces the file at `path` with `rows`.
+    ///
+    /// The rows are written to a temporary file next to `path` that is then renamed over it, so
+    /// readers and later runs see either the old or the new table, never a partially written one.
+    fn write(path: &Path, rows: &[Self]) -> Result<(), Error> {
+        let fields = Self::fields()?;
+        let batch = serde_arrow::to_record_batch(&fields, &rows)?;
+
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = path.with_file_name(temp_name);
+
+        let written = (|| {
+            let file = File::create(&temp_path)?;
+            let properties = WriterProperties::builder()
+                .set_key_value_metadata(Some(vec![KeyValue::new(
+                    SCHEMA_VERSION_KEY.to_string(),
+                    SCHEMA_VERSION.to_string(),
+                )]))
+                .build();
+            let mut writer =
+                ArrowWriter::try_new(file, Arc::new(Schema::new(fields)), Some(properties))?;
+            writer.write(&batch)?;
+            writer.into_inner()?.sync_all()?;
+            fs::rename(&temp_path, path)?;
+            Ok(())
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        written
+    }
+}
@@ -0,0 +1,225 @@
+use arrow::datatypes::{DataType, TimeUnit};
+use chrono::{NaiveDate, NaiveTime};
+use model::{
+    AgendaItem, Attendance, Ballot, Commission, DocumentStatus, DocumentType, Dossier,
+    DossierEvent, DossierKeyword, IdMapping, LobbyEntry, Meeting, Member, Proposition, Question,
+    Remuneration, Subdocument, Summary, Table, Utterance, Vote, VoteCorrection, VoteExplanation,
+    VoteIssue,
+};
+use std::path::{Path, PathBuf};
+
+fn data_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../web/src/data")
+}
+
+fn column_types<T: Table>() -> Vec<(String, DataType, bool)> {
+    T::fields()
+        .unwrap()
+        .iter()
+        .map(|field| {
+            (
+                field.name().clone(),
+                field.data_type().clone(),
+                field.is_nullable(),
+            )
+        })
+        .collect()
+}
+
+/// Reads a published data file, so that a struct that no longer matches the files fails here.
+fn read_published<T: Table>() -> Vec<T> {
+    let path = data_dir().join(T::FILE_NAME);
+    T::read(&path).unwrap_or_else(|error| panic!("{}: {}", path.display(), error))
+}
+
+#[test]
+fn writes_and_reads_back_rows() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(Vote::FILE_NAME);
+    let votes = vec![
+        Vote {
+            vote_id: "1".to_string(),
+            session_id: "56".to_string(),
+            meeting_id: "40".to_string(),
+            date: NaiveDate::from_ymd_opt(2025, 4, 24).unwrap(),
+            title_nl: "Wetsontwerp houdende diverse bepalingen".to_string(),
+            title_fr: "Projet de loi portant des dispositions diverses".to_string(),
+            yes: Some(80),
+            no: Some(50),
+            abstain: Some(2),
+            members_yes: vec!["Staf Aerts".to_string(), "Ismaël Nuino".to_string()],
+            dossier_id: Some("0095".to_string()),
+            vote_kind: "roll_call".to_string(),
+            outcome: Some("adopted".to_string()),
+            majority: "simple".to_string(),
+            ..Vote::default()
+        },
+        Vote {
+            vote_id: "d0".to_string(),
+            vote_kind: "show_of_hands".to_string(),
+            ..Vote::default()
+        },
+    ];
+
+    Vote::write(&path, &votes).unwrap();
+    assert_eq!(Vote::read(&path).unwrap(), votes);
+
+    Vote::write(&path, &[]).unwrap();
+    assert_eq!(Vote::read(&path).unwrap(), vec![]);
+}
+
+#[test]
+fn columns_are_typed_and_named_like_the_fields() {
+    let utf8 = || DataType::Utf8;
+    let time = || DataType::Time64(TimeUnit::Microsecond);
+    assert_eq!(
+        column_types::<Meeting>(),
+        [
+            ("session_id".to_string(), utf8(), false),
+            ("meeting_id".to_string(), utf8(), false),
+            ("date".to_string(), DataType::Date32, false),
+            ("time_of_day".to_string(), utf8(), false),
+            ("start_time".to_string(), time(), true),
+            ("end_time".to_string(), time(), true),
+        ]
+    );
+
+    let vote = column_types::<Vote>();
+    assert_eq!(vote[6], ("yes".to_string(), DataType::Int32, true));
+    assert!(matches!(&vote[9].1, DataType::List(item) if item.data_type() == &DataType::Utf8));
+    assert_eq!(vote[12], ("dossier_id".to_string(), utf8(), true));
+    assert_eq!(vote[15], ("vote_kind".to_string(), utf8(), false));
+    assert_eq!(vote[16], ("outcome".to_string(), utf8(), true));
+
+    let utterance = column_types::<Utterance>();
+    assert_eq!(utterance[3], ("question_id".to_string(), utf8(), true));
+    assert_eq!(
+        utterance[4],
+        ("sequence".to_string(), DataType::Int32, false)
+    );
+    assert!(
+        matches!(&utterance[10].1, DataType::List(item) if item.data_type() == &DataType::Utf8)
+    );
+
+    let member = column_types::<Member>();
+    assert!(member.contains(&("active".to_string(), DataType::Boolean, false)));
+    assert!(member.contains(&("start".to_string(), DataType::Date32, true)));
+}
+
+#[test]
+fn stores_the_schema_version() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(Meeting::FILE_NAME);
+    let meeting = Meeting {
+        date: NaiveDate::from_ymd_opt(2025, 6, 12).unwrap(),
+        start_time: NaiveTime::from_hms_opt(14, 15, 0),
+        ..Meeting::default()
+    };
+
+    Meeting::write(&path, std::slice::from_ref(&meeting)).unwrap();
+    assert_eq!(model::schema_version(&path).unwrap(), model::SCHEMA_VERSION);
+    assert_eq!(Meeting::read(&path).unwrap(), [meeting]);
+}
+
+#[test]
+fn reads_the_published_data_files() {
+    assert!(!read_published::<Meeting>().is_empty());
+    assert!(!read_published::<Vote>().is_empty());
+    assert!(!read_published::<Ballot>().is_empty());
+    assert!(!read_published::<Proposition>().is_empty());
+    assert!(!read_published::<IdMapping>().is_empty());
+    assert!(!read_published::<AgendaItem>().is_empty());
+    assert!(!read_published::<Attendance>().is_empty());
+    assert!(!read_published::<VoteExplanation>().is_empty());
+    assert!(!read_published::<VoteCorrection>().is_empty());
+    assert!(!read_published::<Dossier>().is_empty());
+    assert!(!read_published::<DossierEvent>().is_empty());
+    assert!(!read_published::<DossierKeyword>().is_empty());
+    assert!(!read_published::<Subdocument>().is_empty());
+    assert!(!read_published::<Commission>().is_empty());
+    assert!(!read_published::<Member>().is_empty());
+    assert!(!read_published::<Remuneration>().is_empty());
+    assert!(!read_published::<LobbyEntry>().is_empty());
+    assert!(!read_published::<Summary>().is_empty());
+
+    // The issues found in the tallies are kept with the scraper, empty when every tally matches
+    // its names.
+    let issues = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../scraper/data/diagnostics")
+        .join(VoteIssue::FILE_NAME);
+    VoteIssue::read(&issues).unwrap();
+
+    // Questions and their utterances are not published in the repository; the scrapers write them
+    // before the site is built.
+    let questions = data_dir().join(Question::FILE_NAME);
+    if questions.exists() {
+        Question::read(&questions).unwrap();
+    }
+    let utterances = data_dir().join(Utterance::FILE_NAME);
+    if utterances.exists() {
+        Utterance::read(&utterances).unwrap();
+    }
+}
+
+#[test]
+fn replaces_the_file_without_leaving_a_temporary_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(Meeting::FILE_NAME);
+    let meeting = |meeting_id: &str| Meeting {
+        session_id: "56".to_string(),
+        meeting_id: meeting_id.to_string(),
+        ..Meeting::default()
+    };
+
+    Meeting::write(&path, &[meeting("1"), meeting("2")]).unwrap();
+    Meeting::write(&path, &[meeting("3")]).unwrap();
+
+    assert_eq!(Meeting::read(&path).unwrap(), vec![meeting("3")]);
+    let files: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(files, [Meeting::FILE_NAME]);
+}
+
+#[test]
+fn parses_document_types_and_statuses_by_code_then_label() {
+    assert_eq!(
+        DocumentType::parse("02 WETSONTWERP"),
+        Some(DocumentType::WetsOntwerp)
+    );
+    assert_eq!(
+        DocumentType::parse("68 niet-geevoceerd   ontwerp"),
+        Some(DocumentType::NietGeevoceerdOntwerp)
+    );
+    assert_eq!(
+        DocumentType::parse("AVIS DU CONSEIL D'ÉTAT"),
+        Some(DocumentType::AdviesVanDeRaadVanState)
+    );
+    assert_eq!(
+        DocumentType::parse("VERSLAG (VERWIJZEND)"),
+        Some(DocumentType::VerwijzendVerslag)
+    );
+    assert_eq!(DocumentType::parse("99 NIEUW TYPE"), None);
+    assert_eq!(DocumentType::WetsVoorstel.code(), Some(5));
+    assert_eq!(DocumentType::WetsVoorstel.to_string(), "WetsVoorstel");
+
+    assert_eq!(
+        DocumentStatus::parse("16 AANGENOMEN"),
+        Some(DocumentStatus::Aangenomen)
+    );
+    assert_eq!(
+        DocumentStatus::parse("SANS OBJET"),
+        Some(DocumentStatus::ZonderVoorwerp)
+    );
+    assert_eq!(DocumentStatus::Verworpen.label_fr(), "REJETÉ");
+    // Without a known code, by the label.
+    assert_eq!(
+        DocumentStatus::parse("17 INGETROKKEN"),
+        Some(DocumentStatus::Ingetrokken)
+    );
+
+    // The same name when the page gives none.
+    assert_eq!(DocumentType::Onbekend.to_string(), "Onbekend");
+    assert_eq!(DocumentStatus::Onbekend.to_string(), "Onbekend");
+}
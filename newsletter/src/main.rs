@@ -1,30 +1,11 @@
-use arrow::array::Int32Array;
-use arrow_array::{Array, RecordBatch, StringArray};
 use chrono::Datelike;
-use chrono::NaiveDate;
-use chrono::NaiveTime;
-use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use crawl::utils::dates::{DayPart, dutch_month_name, format_time};
+use model::{Attendance, Meeting, Member, Question, Table, Vote};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
-use std::fs::File;
 use std::path::{Path, PathBuf};
 
-// Translate time of day to Dutch
-fn translate_time_of_day(s: &str) -> String {
-    match s {
-        "morning" | "voormiddag" => "Voormiddag".to_string(),
-        "afternoon" | "namiddag" => "Namiddag".to_string(),
-        "evening" | "avond" => "Avond".to_string(),
-        _ => s.to_string(),
-    }
-}
-
-// Parse time string (either "HH:MM" or "HHhMM")
-fn parse_time(s: &str) -> Result<NaiveTime, chrono::ParseError> {
-    NaiveTime::parse_from_str(s, "%H:%M").or_else(|_| NaiveTime::parse_from_str(s, "%Hh%M"))
-}
-
 // Paths
 fn data_dir() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -37,49 +18,6 @@ fn posts_dir() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR")).join("posts")
 }
 
-// Read Parquet batches
-fn read_batches(path: &Path) -> anyhow::Result<Vec<RecordBatch>> {
-    let file = File::open(path)?;
-    let reader = ParquetRecordBatchReaderBuilder::try_new(file)?.build()?;
-    let mut batches = Vec::new();
-    for batch in reader {
-        batches.push(batch?);
-    }
-    Ok(batches)
-}
-
-fn parse_names_opt(arr: &StringArray, i: usize) -> Vec<String> {
-    if arr.is_null(i) {
-        vec![]
-    } else {
-        arr.value(i)
-            .split(',')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect()
-    }
-}
-
-// Get a string column from a RecordBatch
-fn get_string_col<'a>(batch: &'a RecordBatch, name: &str) -> &'a StringArray {
-    batch
-        .column_by_name(name)
-        .unwrap()
-        .as_any()
-        .downcast_ref::<StringArray>()
-        .unwrap()
-}
-
-fn get_i32_col<'a>(batch: &'a RecordBatch, name: &str) -> &'a Int32Array {
-    batch
-        .column_by_name(name)
-        .unwrap()
-        .as_any()
-        .downcast_ref::<Int32Array>()
-        .unwrap()
-}
-
 #[derive(Debug)]
 struct QuestionInfo {
     topic: String,
@@ -89,18 +27,14 @@ struct QuestionInfo {
 #[derive(Debug)]
 struct MemberInfo {
     party: String,
-    active: bool,
 }
 
 #[derive(Debug)]
 struct VoteInfo {
     title: String,
-    yes_votes: Vec<String>,
-    no_votes: Vec<String>,
-    abstain_votes: Vec<String>,
-    yes: usize,
-    no: usize,
-    abstain: usize,
+    yes: i32,
+    no: i32,
+    abstain: i32,
 }
 
 #[tokio::main]
@@ -111,192 +45,115 @@ async fn main() -> Result<(), Box<dyn Error>> {
     fs::create_dir_all(&posts_dir)?;
 
     // Read members
-    let members_path = data_dir.join("members.parquet");
-    let member_batches = read_batches(&members_path)?;
+    let members = Member::read(&data_dir.join(Member::FILE_NAME))?;
     let mut member_infos: HashMap<String, MemberInfo> = HashMap::new();
-    for batch in member_batches {
-        let first_name_col = get_string_col(&batch, "first_name");
-        let last_name_col = get_string_col(&batch, "last_name");
-        let party_col = get_string_col(&batch, "party");
-        let active_col = get_string_col(&batch, "active"); // assume "true"/"false" as string
-
-        for i in 0..first_name_col.len() {
-            let name = format!("{} {}", first_name_col.value(i), last_name_col.value(i));
-            let party = party_col.value(i).to_string();
-            let active = active_col.value(i).to_lowercase() == "true";
-
-            member_infos.insert(name, MemberInfo { party, active });
-        }
+    for member in members {
+        let name = format!("{} {}", member.first_name, member.last_name);
+        member_infos.insert(
+            name,
+            MemberInfo {
+                party: member.party,
+            },
+        );
     }
 
+    // --- Read meetings ---
+    // Posts are only written for the latest session, whose members are the active ones.
+    let meetings = Meeting::read(&data_dir.join(Meeting::FILE_NAME))?;
+    let session_id = meetings
+        .iter()
+        .map(|meeting| meeting.session_id.parse::<u32>().unwrap_or_default())
+        .max()
+        .unwrap_or_default()
+        .to_string();
+    let meetings: HashMap<String, Meeting> = meetings
+        .into_iter()
+        .filter(|meeting| meeting.session_id == session_id)
+        .map(|meeting| (meeting.meeting_id.clone(), meeting))
+        .collect();
+
     // Read votes
     // --- Read votes ---
-    let votes_path = data_dir.join("votes.parquet");
-    let vote_batches = read_batches(&votes_path)?;
+    let votes = Vote::read(&data_dir.join(Vote::FILE_NAME))?;
     let mut meeting_votes: HashMap<String, Vec<VoteInfo>> = HashMap::new();
 
-    for batch in vote_batches {
-        let meeting_id_col = get_string_col(&batch, "meeting_id");
-        let title_col = get_string_col(&batch, "title_nl");
-        let yes_count_col = get_string_col(&batch, "yes");
-        let no_count_col = get_string_col(&batch, "no");
-        let abstain_count_col = get_string_col(&batch, "abstain");
-
-        let yes_voters_col = get_string_col(&batch, "members_yes");
-        let no_voterscol = get_string_col(&batch, "members_no");
-        let abstain_voters_col = get_string_col(&batch, "members_abstain");
-
-        for i in 0..meeting_id_col.len() {
-            let meeting_id = meeting_id_col.value(i).to_string();
-
-            let yes_count: usize = yes_count_col.value(i).parse().unwrap_or(0);
-            let no_count: usize = no_count_col.value(i).parse().unwrap_or(0);
-            let abstain_count: usize = abstain_count_col.value(i).parse().unwrap_or(0);
-
-            // Split comma-separated names, trim, filter out empty strings
-            let yes_voters: Vec<String> = yes_voters_col
-                .value(i)
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-
-            let no_voters: Vec<String> = no_voterscol
-                .value(i)
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-
-            let abstain_voters: Vec<String> = abstain_voters_col
-                .value(i)
-                .split(',')
-                .map(|s| s.trim().to_string())
-                .filter(|s| !s.is_empty())
-                .collect();
-
-            meeting_votes
-                .entry(meeting_id)
-                .or_insert_with(Vec::new)
-                .push(VoteInfo {
-                    title: title_col.value(i).to_string(),
-                    yes_votes: yes_voters,
-                    no_votes: no_voters,
-                    abstain_votes: abstain_voters,
-                    yes: yes_count,
-                    no: no_count,
-                    abstain: abstain_count,
-                });
-        }
+    // Decisions without a roll call are left out, the posts list the roll-call votes.
+    for vote in votes
+        .into_iter()
+        .filter(|vote| vote.session_id == session_id && vote.vote_kind == "roll_call")
+    {
+        meeting_votes
+            .entry(vote.meeting_id)
+            .or_default()
+            .push(VoteInfo {
+                title: vote.title_nl,
+                yes: vote.yes.unwrap_or_default(),
+                no: vote.no.unwrap_or_default(),
+                abstain: vote.abstain.unwrap_or_default(),
+            });
     }
 
-    // Compute absentees
-    let active_members: Vec<String> = member_infos
-        .iter()
-        .filter(|(_, info)| info.active)
-        .map(|(name, _)| name.clone())
-        .collect();
-
-    // --- Read meetings ---
-    let meetings_path = data_dir.join("meetings.parquet");
-    let meeting_batches = read_batches(&meetings_path)?;
-
-    #[derive(Debug)]
-    struct MeetingInfo {
-        session_id: String,
-        meeting_id: String,
-        date: String,
-        time_of_day: String,
-        start_time: String,
-        end_time: String,
-    }
-
-    let mut meetings: HashMap<String, MeetingInfo> = HashMap::new();
-
-    for batch in meeting_batches {
-        let session_id = get_string_col(&batch, "session_id");
-        let meeting_id = get_string_col(&batch, "meeting_id");
-        let date = get_string_col(&batch, "date");
-        let time_of_day = get_string_col(&batch, "time_of_day");
-        let start_time = get_string_col(&batch, "start_time");
-        let end_time = get_string_col(&batch, "end_time");
-
-        for i in 0..meeting_id.len() {
-            let id = meeting_id.value(i).to_string();
-            meetings.insert(
-                id.clone(),
-                MeetingInfo {
-                    session_id: session_id.value(i).to_string(),
-                    meeting_id: id,
-                    date: date.value(i).to_string(),
-                    time_of_day: time_of_day.value(i).to_string(),
-                    start_time: start_time.value(i).to_string(),
-                    end_time: end_time.value(i).to_string(),
-                },
-            );
-        }
+    // --- Read attendance ---
+    let mut meeting_attendance: HashMap<String, Vec<Attendance>> = HashMap::new();
+    let attendance = Attendance::read(&data_dir.join(Attendance::FILE_NAME))?;
+    for row in attendance
+        .into_iter()
+        .filter(|row| row.session_id == session_id)
+    {
+        meeting_attendance
+            .entry(row.meeting_id.clone())
+            .or_default()
+            .push(row);
     }
 
     // --- Read questions ---
-    let questions_path = data_dir.join("questions.parquet");
-    let question_batches = read_batches(&questions_path)?;
+    let questions = Question::read(&data_dir.join(Question::FILE_NAME))?;
 
     let mut meeting_questions: HashMap<String, Vec<QuestionInfo>> = HashMap::new();
 
-    for batch in question_batches {
-        let q_topics = get_string_col(&batch, "topics_nl");
-        let meeting_id = get_string_col(&batch, "meeting_id");
-        let questioners_col = get_string_col(&batch, "questioners"); // comma-separated full names
-        let respondents_col = get_string_col(&batch, "respondents"); // comma-separated full names
-
-        for i in 0..meeting_id.len() {
-            let m = meeting_id.value(i).to_string();
-
-            // Only take first topic (split by ';')
-            let full_topic = q_topics.value(i);
-            let first_topic = full_topic
-                .split(';')
-                .next()
-                .unwrap_or(full_topic)
-                .trim()
-                .to_string();
-
-            // Split comma-separated arrays
-            let questioners: Vec<String> = questioners_col
-                .value(i)
-                .split(',')
-                .map(|s| {
-                    let name = s.trim();
-                    let party = member_infos
-                        .get(name)
-                        .map(|info| format!(" ({})", info.party))
-                        .unwrap_or_default();
-                    format!("{}{}", name, party)
-                })
-                .collect();
-
-            let respondents: Vec<String> = respondents_col
-                .value(i)
-                .split(',')
-                .map(|s| {
-                    let name = s.trim();
-                    let party = member_infos
-                        .get(name)
-                        .map(|info| format!(" ({})", info.party))
-                        .unwrap_or_default();
-                    format!("{}{}", name, party)
-                })
-                .collect();
-
-            meeting_questions
-                .entry(m)
-                .or_insert_with(Vec::new)
-                .push(QuestionInfo {
-                    topic: first_topic,
-                    questioners,
-                    respondents,
-                });
-        }
+    for question in questions
+        .into_iter()
+        .filter(|question| question.session_id == session_id)
+    {
+        // Only take first topic
+        let first_topic = question
+            .topics_nl
+            .first()
+            .map(|topic| topic.trim().to_string())
+            .unwrap_or_default();
+
+        let questioners: Vec<String> = question
+            .questioners
+            .iter()
+            .map(|name| {
+                let party = member_infos
+                    .get(name)
+                    .map(|info| format!(" ({})", info.party))
+                    .unwrap_or_default();
+                format!("{}{}", name, party)
+            })
+            .collect();
+
+        let respondents: Vec<String> = question
+            .respondents
+            .iter()
+            .map(|name| {
+                let party = member_infos
+                    .get(name)
+                    .map(|info| format!(" ({})", info.party))
+                    .unwrap_or_default();
+                format!("{}{}", name, party)
+            })
+            .collect();
+
+        meeting_questions
+            .entry(question.meeting_id)
+            .or_default()
+            .push(QuestionInfo {
+                topic: first_topic,
+                questioners,
+                respondents,
+            });
     }
 
     // --- Generate Markdown files ---
@@ -310,19 +167,20 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .map(|v| v.as_slice())
             .unwrap_or(&[]);
 
-        let mut present_members: Vec<String> = Vec::new();
-        for vote in votes {
-            present_members.extend(vote.yes_votes.iter().cloned());
-            present_members.extend(vote.no_votes.iter().cloned());
-            present_members.extend(vote.abstain_votes.iter().cloned());
-        }
-        present_members.sort();
-        present_members.dedup();
-
-        let mut absentees: Vec<String> = active_members
+        let attendance = meeting_attendance
+            .get(meeting_id)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[]);
+        // Without a roll-call vote only the excused members are known.
+        let roll_call = attendance.iter().any(|row| row.status != "excused");
+        let excused: Vec<&Attendance> = attendance
+            .iter()
+            .filter(|row| row.status == "excused")
+            .collect();
+        let mut absentees: Vec<String> = attendance
             .iter()
-            .filter(|m| !present_members.contains(m))
-            .cloned()
+            .filter(|row| row.status == "absent")
+            .map(|row| row.name.clone())
             .collect();
         let empty = "".to_string();
 
@@ -332,36 +190,26 @@ async fn main() -> Result<(), Box<dyn Error>> {
             party_a.cmp(party_b)
         });
 
-        let dutch_time_of_day = translate_time_of_day(&info.time_of_day);
+        let dutch_time_of_day = info
+            .time_of_day
+            .parse::<DayPart>()
+            .map(|day_part| day_part.dutch_label().to_string())
+            .unwrap_or_else(|_| info.time_of_day.clone());
 
-        let start = parse_time(&info.start_time).unwrap();
-        let end = parse_time(&info.end_time).unwrap();
+        let start = info.start_time.unwrap();
+        let end = info.end_time.unwrap();
         let duration_minutes = (end - start).num_minutes();
 
         // Generate markdown.
         let mut md = String::new();
         // TITLE
-        let date = NaiveDate::parse_from_str(&info.date, "%Y-%m-%d")?;
-        let dutch_month = match date.month() {
-            1 => "januari",
-            2 => "februari",
-            3 => "maart",
-            4 => "april",
-            5 => "mei",
-            6 => "juni",
-            7 => "juli",
-            8 => "augustus",
-            9 => "september",
-            10 => "oktober",
-            11 => "november",
-            12 => "december",
-            _ => "onbekend",
-        };
+        let date = info.date;
+        let dutch_month = dutch_month_name(date.month()).unwrap_or("onbekend");
         let formatted_date2 = format!("{} {} {}", date.day(), dutch_month, date.year());
 
         md.push_str(&format!(
-            "Verslag van de plenaire vergadering van {}.\n\nHet volledig verslag (met vragen/antwoorden) is te vinden op https://zijwerkenvooru.be/sessions/56/meetings/plenary/{}/.\n\n\n",
-            formatted_date2, meeting_id
+            "Verslag van de plenaire vergadering van {}.\n\nHet volledig verslag (met vragen/antwoorden) is te vinden op https://zijwerkenvooru.be/sessions/{}/meetings/plenary/{}/.\n\n\n",
+            formatted_date2, info.session_id, info.meeting_id
         ));
 
         // TIME AND DATE
@@ -371,25 +219,45 @@ async fn main() -> Result<(), Box<dyn Error>> {
         ));
         md.push_str(&format!(
             "⏱ **Duur:** {}–{} ({} minuten)\n\n",
-            info.start_time, info.end_time, duration_minutes
+            format_time(start),
+            format_time(end),
+            duration_minutes
         ));
 
         // Add absentees
         md.push_str("**Afwezigen**\n\n");
-        md.push_str("Dit is een schatting op basis van deelname aan de gevonden stemmingen. De Kamer publiceert geen aanwezigheden.\n\n");
-        if absentees.is_empty() {
-            md.push_str("_Geen afwezigen_\n\n");
-        } else {
-            for a in &absentees {
-                let party = member_infos
-                    .get(a)
-                    .map(|info| format!(" ({})", info.party))
-                    .unwrap_or_default();
-                let slug = a.to_lowercase().replace(' ', "-");
-                md.push_str(&format!("- {}{}\n", a, party));
-            }
-            md.push_str("\n");
+        md.push_str(
+            "De verontschuldigde leden staan in het verslag. De andere afwezigen namen aan geen \
+             enkele naamstemming deel.\n\n",
+        );
+        for row in &excused {
+            let party = member_infos
+                .get(&row.name)
+                .map(|info| format!(" ({})", info.party))
+                .unwrap_or_default();
+            let reason = row
+                .reason_nl
+                .as_ref()
+                .map(|reason| format!(": {}", reason))
+                .unwrap_or_default();
+            md.push_str(&format!(
+                "- {}{}, verontschuldigd{}\n",
+                row.name, party, reason
+            ));
+        }
+        for a in &absentees {
+            let party = member_infos
+                .get(a)
+                .map(|info| format!(" ({})", info.party))
+                .unwrap_or_default();
+            md.push_str(&format!("- {}{}\n", a, party));
+        }
+        if !roll_call {
+            md.push_str("_Geen naamstemmingen, de andere afwezigen zijn niet bekend_\n");
+        } else if excused.is_empty() && absentees.is_empty() {
+            md.push_str("_Geen afwezigen_\n");
         }
+        md.push('\n');
 
         // Questions
         md.push_str(&format!("❓Vragen ({} totaal)\n\n", qs.len()));
@@ -399,14 +267,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
             md.push_str("Gesteld door:\n\n");
             for a in &q.questioners {
-                let name_only = a.split(" (").next().unwrap_or(a);
-                let slug = name_only.to_lowercase().replace(' ', "-");
                 md.push_str(&format!("- *{}*\n\n", a));
             }
             md.push_str("\nAan: ");
             for r in &q.respondents {
-                let name_only = r.split(" (").next().unwrap_or(r);
-                let slug = name_only.to_lowercase().replace(' ', "-");
                 md.push_str(&format!("*{}* ", r));
             }
             md.push_str("\n\n\n");
@@ -455,7 +319,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
                     md.push_str(&format!("{}\n\n", bar));
                 } else {
-                    md.push_str("\n");
+                    md.push('\n');
                 }
             }
         }
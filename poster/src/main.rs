@@ -1,16 +1,14 @@
-use arrow::array::Array;
-use arrow::array::StringArray;
 use atrium_api::app::bsky::feed::post::{RecordData, RecordEmbedRefs, ReplyRefData};
 use atrium_api::types::string::Datetime;
 use bsky_sdk::rich_text::RichText;
 use bsky_sdk::BskyAgent;
-use chrono::Datelike;
-use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use chrono::{Datelike, NaiveDate};
+use model::{Meeting, Question, Summary, Table, Vote};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use std::{
-    fs::{self, File},
+    fs,
     path::Path,
 };
 use std::error::Error;
@@ -24,7 +22,6 @@ use dotenv::dotenv;
 use unicode_segmentation::UnicodeSegmentation;
 use headless_chrome::{Browser, LaunchOptionsBuilder};
 use headless_chrome::protocol::cdp::Page::Viewport;
-use parquet::data_type::AsBytes;
 
 fn truncate_to_graphemes(s: &str, count: usize) -> String {
     let graphemes: Vec<&str> = UnicodeSegmentation::graphemes(s, true).collect();
@@ -43,7 +40,7 @@ fn hash_text(input: &str) -> String {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "type")]
-pub enum BskyReply {
+enum BskyReply {
     Vote(BskyReplyVote),
     Question(BskyReplyQuestion),
 }
@@ -72,53 +69,8 @@ struct BskyReplyQuestion  {
     pub respondents: Vec<String>,
 }
 
-fn parse_dutch_date(date_str: &str) -> Option<chrono::NaiveDate> {
-    let months = [
-        "", "januari", "februari", "maart", "april", "mei", "juni",
-        "juli", "augustus", "september", "oktober", "november", "december",
-    ];
-
-    let parts: Vec<&str> = date_str.split_whitespace().collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    let day = parts[0].parse::<u32>().ok()?;
-    let month_str = parts[1];
-    let year = parts[2].parse::<i32>().ok()?;
-
-    let month = months.iter().position(|&m| m == month_str)?;
-    chrono::NaiveDate::from_ymd_opt(year, month as u32, day)
-}
-
-
-fn format_dutch_date(date_str: &str) -> Option<String> {
-    if let Ok(date) = chrono::NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-        Some(format!("{:02}/{:02}/{}", date.day(), date.month(), date.year()))
-    } else {
-        None
-    }
-}
-
-fn read_string_column(file_path: &PathBuf, col_name: &str) -> anyhow::Result<Vec<String>> {
-    let file = File::open(file_path)?;
-    let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
-    let reader = builder.build()?;
-    let mut result = Vec::new();
-
-    for batch in reader {
-        let batch = batch?;
-        let column = batch
-            .column_by_name(col_name)
-            .expect(&format!("Missing '{}'", col_name))
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .unwrap();
-        for i in 0..column.len() {
-            result.push(column.value(i).to_string());
-        }
-    }
-    Ok(result)
+fn format_dutch_date(date: NaiveDate) -> String {
+    format!("{:02}/{:02}/{}", date.day(), date.month(), date.year())
 }
 
 fn get_data_dir() -> PathBuf {
@@ -141,10 +93,21 @@ async fn main() -> Result<(), Box<dyn Error>> {
     dotenv().ok();
     let username = std::env::var("BSKY_USERNAME").expect("Missing BSKY_USERNAME");
     let password = std::env::var("BSKY_PASSWORD").expect("Missing BSKY_PASSWORD");
-    let meeting_id_path = Path::new(env!("CARGO_MANIFEST_DIR"))
-        .join("../scraper/current_plenary_id.txt");
-    let last_meeting_id = fs::read_to_string(&meeting_id_path)?;
-    let target_meeting_id = last_meeting_id.trim();
+    let data_dir = get_data_dir();
+
+    // Post about the last meeting that was scraped.
+    let meetings = Meeting::read(&data_dir.join(Meeting::FILE_NAME))?;
+    let last_meeting = meetings
+        .iter()
+        .max_by_key(|meeting| {
+            (
+                meeting.session_id.parse::<u32>().unwrap_or_default(),
+                meeting.meeting_id.parse::<u32>().unwrap_or_default(),
+            )
+        })
+        .ok_or("No meetings to post about")?;
+    let target_session_id = last_meeting.session_id.as_str();
+    let target_meeting_id = last_meeting.meeting_id.as_str();
 
     // Hardcoded name to handle mapping.
     let name_to_handle: HashMap<&str, &str> = HashMap::from([
@@ -186,7 +149,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Create bsky session.
     let agent = BskyAgent::builder().build().await?;
-    let session = agent.login(&username, &password).await?;
+    agent.login(&username, &password).await?;
 
     // Create headless Chrome browser.
     let launch_options = LaunchOptionsBuilder::default()
@@ -198,7 +161,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 "--user-agent=Mozilla/5.0 (Windows NT 10.0; Win64; x64)...",
             ]
                 .iter()
-                .map(|s| OsStr::new(s))
+                .map(OsStr::new)
                 .collect::<Vec<&OsStr>>(),
         )
         .build()
@@ -206,7 +169,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let browser = Browser::new(launch_options)?;
 
     // Posts.
-    let data_dir = get_data_dir();
     let posts_log_path = get_log_file_path("posts.json");
     let mut posts: Vec<BskyPost> = if posts_log_path.exists() {
         let contents = fs::read_to_string(&posts_log_path)?;
@@ -216,55 +178,34 @@ async fn main() -> Result<(), Box<dyn Error>> {
     };
 
     // Data readers.
-    let votes_path = data_dir.join("votes.parquet");
-    let vote_file = File::open(&votes_path)?;
-    let mut vote_reader = ParquetRecordBatchReaderBuilder::try_new(vote_file)?.build()?;
-    let questions_path = data_dir.join("questions.parquet");
-    let questions_file = File::open(&questions_path)?;
-    let mut questions_reader = ParquetRecordBatchReaderBuilder::try_new(questions_file)?.build()?;
+    // Only roll-call votes have a vote page to take a screenshot of.
+    let votes: Vec<Vote> = Vote::read(&data_dir.join(Vote::FILE_NAME))?
+        .into_iter()
+        .filter(|vote| vote.vote_kind == "roll_call")
+        .collect();
+    let questions = Question::read(&data_dir.join(Question::FILE_NAME))?;
 
     // Collect data.
     let mut vote_count = 0;
-    let mut date = String::new();
+    let mut date = None;
     let mut meeting_id = String::new();
-    for batch in &mut vote_reader {
-        let batch = batch?;
-        let vote_titles = batch
-            .column_by_name("title_nl").unwrap()
-            .as_any().downcast_ref::<StringArray>().unwrap();
-        let meeting_id_col = batch
-            .column_by_name("meeting_id").unwrap()
-            .as_any().downcast_ref::<StringArray>().unwrap();
-        let date_col = batch
-            .column_by_name("date").unwrap()
-            .as_any().downcast_ref::<StringArray>().unwrap();
-
-        for i in 0..vote_titles.len() {
-            if meeting_id_col.value(i) == target_meeting_id {
-                vote_count += 1;
-
-                if date.is_empty() {
-                    date = date_col.value(i).to_string();
-                    meeting_id = meeting_id_col.value(i).to_string();
-                }
+    for vote in &votes {
+        if vote.session_id == target_session_id && vote.meeting_id == target_meeting_id {
+            vote_count += 1;
+
+            if date.is_none() {
+                date = Some(vote.date);
+                meeting_id = vote.meeting_id.clone();
             }
         }
     }
 
-    let mut question_count = 0;
-    for batch in &mut questions_reader {
-        let batch = batch?;
-        let question_titles = batch
-            .column_by_name("topics_nl").unwrap()
-            .as_any().downcast_ref::<StringArray>().unwrap();
-        let meeting_id_col = batch
-            .column_by_name("meeting_id").unwrap()
-            .as_any().downcast_ref::<StringArray>().unwrap();
-
-        question_count += (0..question_titles.len())
-            .filter(|&i| meeting_id_col.value(i) == target_meeting_id)
-            .count();
-    }
+    let question_count = questions
+        .iter()
+        .filter(|question| {
+            question.session_id == target_session_id && question.meeting_id == target_meeting_id
+        })
+        .count();
 
     let mut main_post_uri: Option<String> = None;
 
@@ -274,7 +215,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
         if let Some(existing) = posts.iter().find(|p| p.id == target_meeting_id) {
             main_post_uri = Some(existing.uri.clone());
         } else {
-            let date_str = format_dutch_date(date.as_str()).unwrap();
+            let date_str = format_dutch_date(date.unwrap());
             let main_post_text = format!("🇧🇪 Vandaag in het Parlement ({})\n- {} stemmingen\n- {} vragen", date_str, vote_count, question_count);
             let main_post = agent.create_record(RecordData {
                 created_at: Datetime::now(),
@@ -300,281 +241,261 @@ async fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    // Re-open file for second pass (votes).
-    let vote_file = File::open(&votes_path)?;
-    let vote_reader = ParquetRecordBatchReaderBuilder::try_new(vote_file)?.build()?;
-
-    for batch in vote_reader {
-        let batch = batch?;
-        let vote_titles = batch.column_by_name("title_nl").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let yes_col = batch.column_by_name("yes").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let no_col = batch.column_by_name("no").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let abstain_col = batch.column_by_name("abstain").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let session_id_col = batch.column_by_name("session_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let meeting_id_col = batch.column_by_name("meeting_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let vote_id_col = batch.column_by_name("vote_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-
-        // Loop through votes.
-        for i in 0..vote_titles.len() {
-            // Only process votes for the target meeting.
-            let meeting_id = meeting_id_col.value(i);
-            if meeting_id != target_meeting_id {
-                continue;
-            }
+    // Loop through votes.
+    for vote in &votes {
+        // Only process votes for the target meeting.
+        let meeting_id = vote.meeting_id.as_str();
+        if vote.session_id != target_session_id || meeting_id != target_meeting_id {
+            continue;
+        }
 
-            // Add vote.
-            let raw_vote_title = vote_titles.value(i);
-            let vote_title_with_handles = replace_names_with_handles(raw_vote_title, &name_to_handle);
-            let vote_hash = hash_text(raw_vote_title);
+        // Add vote.
+        let raw_vote_title = vote.title_nl.as_str();
+        let vote_title_with_handles = replace_names_with_handles(raw_vote_title, &name_to_handle);
+        let vote_hash = hash_text(raw_vote_title);
 
-            if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
-                if parent.replies.iter().any(|r| match r {
-                    BskyReply::Vote(v) => v.hash == vote_hash,
-                    _ => false,
-                }) {
-                    continue;
-                }
+        if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
+            if parent.replies.iter().any(|r| match r {
+                BskyReply::Vote(v) => v.hash == vote_hash,
+                _ => false,
+            }) {
+                continue;
+            }
 
-                let yes = yes_col.value(i).parse::<u32>().unwrap_or(0);
-                let no = no_col.value(i).parse::<u32>().unwrap_or(0);
-                let abstain = abstain_col.value(i).parse::<u32>().unwrap_or(0);
-
-                let session_id = session_id_col.value(i);
-                let vote_id = vote_id_col.value(i);
-
-                // Take screenshot of vote and upload.
-                let vote_url = format!("https://zijwerkenvooru.pages.dev/sessions/{}/meetings/plenary/{}/votes/{}", session_id, meeting_id, vote_id);
-                let ScreenshotResult { png_data, viewport } = take_screenshot_of_element(&browser, &vote_url, "#screenshot-target")?;
-                let output = agent
-                    .api
-                    .com
-                    .atproto
-                    .repo
-                    .upload_blob(png_data.as_bytes().to_vec())
-                    .await?;
-                let width = viewport.width.round() as u64;
-                let height = viewport.height.round() as u64;
-                let aspect_ratio = Some(atrium_api::app::bsky::embed::defs::AspectRatioData {
-                    width: NonZeroU64::new(width).unwrap_or(NonZeroU64::new(1).unwrap()),
-                    height: NonZeroU64::new(height).unwrap_or(NonZeroU64::new(1).unwrap()),
-                }.into());
-                let image = ImageData {
-                    alt: "A screenshot of the vote results.".to_string(),
-                    image: output.data.blob,
-                    aspect_ratio,
-
-                }.into();
-                let embed = Some(Union::Refs(
-                    RecordEmbedRefs::AppBskyEmbedImagesMain(Box::new(
-                        atrium_api::app::bsky::embed::images::MainData {
-                            images: vec![image],
-                        }.into(),
-                    )),
-                ));
-
-                // Create vote post text.
-                let result = if yes > (no + abstain) { "Aangenomen" } else { "Verworpen" };
-                let total = yes + no + abstain;
-                let bar_len: usize = 14;
-                let scale = |count: u32| ((count as f64 / total as f64) * bar_len as f64).round() as usize;
-                let yes_blocks = scale(yes);
-                let no_blocks = scale(no);
-                let abstain_blocks = bar_len.saturating_sub(yes_blocks + no_blocks);
-
-                let bar = format!(
-                    "{}\n{}{}{}",
-                    result,
-                    "🟩".repeat(yes_blocks),
-                    "🟥".repeat(no_blocks),
-                    "🟧".repeat(abstain_blocks),
-                );
-
-                let vote_post_text = format!(
-                    "🗳️ Gestemd\n\"{}\"\n\n{}\n\nhttps://zijwerkenvooru.be/sessions/{}/meetings/plenary/{}/votes/{}",
-                    vote_title_with_handles,
-                    bar,
-                    session_id, meeting_id, vote_id
-                );
-                let truncated_post_text = truncate_to_graphemes(&vote_post_text, 300);
-                let rich_text = RichText::new_with_detect_facets(&truncated_post_text).await?;
-
-                let reply_to_uri = if let Some(parent) = posts.iter().find(|p| p.id == target_meeting_id) {
-                    if let Some(last_reply) = parent.replies.last() {
-                        match last_reply {
-                            BskyReply::Vote(v) => Some(v.uri.clone()),
-                            BskyReply::Question(q) => Some(q.uri.clone()),
-                        }
-                    } else {
-                        // No replies yet, reply to main post
-                        main_post_uri.clone()
+            let (yes, no, abstain) = (
+                vote.yes.unwrap_or_default(),
+                vote.no.unwrap_or_default(),
+                vote.abstain.unwrap_or_default(),
+            );
+
+            let session_id = &vote.session_id;
+            let vote_id = &vote.vote_id;
+
+            // Take screenshot of vote and upload.
+            let vote_url = format!("https://zijwerkenvooru.pages.dev/sessions/{}/meetings/plenary/{}/votes/stemming-{}", session_id, meeting_id, vote_id);
+            let ScreenshotResult { png_data, viewport } = take_screenshot_of_element(&browser, &vote_url, "#screenshot-target")?;
+            let output = agent
+                .api
+                .com
+                .atproto
+                .repo
+                .upload_blob(png_data)
+                .await?;
+            let width = viewport.width.round() as u64;
+            let height = viewport.height.round() as u64;
+            let aspect_ratio = Some(atrium_api::app::bsky::embed::defs::AspectRatioData {
+                width: NonZeroU64::new(width).unwrap_or(NonZeroU64::new(1).unwrap()),
+                height: NonZeroU64::new(height).unwrap_or(NonZeroU64::new(1).unwrap()),
+            }.into());
+            let image = ImageData {
+                alt: "A screenshot of the vote results.".to_string(),
+                image: output.data.blob,
+                aspect_ratio,
+
+            }.into();
+            let embed = Some(Union::Refs(
+                RecordEmbedRefs::AppBskyEmbedImagesMain(Box::new(
+                    atrium_api::app::bsky::embed::images::MainData {
+                        images: vec![image],
+                    }.into(),
+                )),
+            ));
+
+            // Create vote post text.
+            // The outcome stated in the report, abstentions and special majorities make the counts misleading.
+            let result = match vote.outcome.as_deref() {
+                Some("adopted") => "Aangenomen",
+                Some("rejected") => "Verworpen",
+                _ => "Uitslag onbekend",
+            };
+            let total = yes + no + abstain;
+            let bar_len: usize = 14;
+            let scale = |count: i32| ((count as f64 / total as f64) * bar_len as f64).round() as usize;
+            let yes_blocks = scale(yes);
+            let no_blocks = scale(no);
+            let abstain_blocks = bar_len.saturating_sub(yes_blocks + no_blocks);
+
+            let bar = format!(
+                "{}\n{}{}{}",
+                result,
+                "🟩".repeat(yes_blocks),
+                "🟥".repeat(no_blocks),
+                "🟧".repeat(abstain_blocks),
+            );
+
+            let vote_post_text = format!(
+                "🗳️ Gestemd\n\"{}\"\n\n{}\n\nhttps://zijwerkenvooru.be/sessions/{}/meetings/plenary/{}/votes/stemming-{}",
+                vote_title_with_handles,
+                bar,
+                session_id, meeting_id, vote_id
+            );
+            let truncated_post_text = truncate_to_graphemes(&vote_post_text, 300);
+            let rich_text = RichText::new_with_detect_facets(&truncated_post_text).await?;
+
+            let reply_to_uri = if let Some(parent) = posts.iter().find(|p| p.id == target_meeting_id) {
+                if let Some(last_reply) = parent.replies.last() {
+                    match last_reply {
+                        BskyReply::Vote(v) => Some(v.uri.clone()),
+                        BskyReply::Question(q) => Some(q.uri.clone()),
                     }
                 } else {
-                    // No parent found, fallback to main post URI
+                    // No replies yet, reply to main post
                     main_post_uri.clone()
-                };
+                }
+            } else {
+                // No parent found, fallback to main post URI
+                main_post_uri.clone()
+            };
 
-                let root_uri = main_post_uri.clone();
+            let root_uri = main_post_uri.clone();
 
-                let vote_post_uri = create_post(&agent, rich_text.text, rich_text.facets, root_uri, reply_to_uri, embed).await?;
+            let vote_post_uri = create_post(&agent, rich_text.text, rich_text.facets, root_uri, reply_to_uri, embed).await?;
 
-                let vote_reply = BskyReply::Vote(BskyReplyVote {
-                    hash: vote_hash.clone(),
-                    topic: vote_title_with_handles.to_string(),
-                    uri: vote_post_uri.clone(),
-                });
+            let vote_reply = BskyReply::Vote(BskyReplyVote {
+                hash: vote_hash.clone(),
+                topic: vote_title_with_handles.to_string(),
+                uri: vote_post_uri.clone(),
+            });
 
-                // Find parent post by meeting id and add the reply
-                if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
-                    parent.replies.push(vote_reply);
-                }
-
-                // Save updated posts to disk
-                fs::write(&posts_log_path, serde_json::to_string_pretty(&posts)?)?;
+            // Find parent post by meeting id and add the reply
+            if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
+                parent.replies.push(vote_reply);
             }
+
+            // Save updated posts to disk
+            fs::write(&posts_log_path, serde_json::to_string_pretty(&posts)?)?;
         }
     }
 
     // Load summaries
-    let summaries_path = data_dir.join("summaries.parquet");
-    let hashes = read_string_column(&summaries_path, "input_hash")?;
-    let summaries = read_string_column(&summaries_path, "summary")?;
-    let summary_map: HashMap<String, String> = hashes.into_iter().zip(summaries.into_iter()).collect();
-
-
-    // Re-open file for second pass (questions).
-    let questions_file = File::open(&questions_path)?;
-    let questions_reader = ParquetRecordBatchReaderBuilder::try_new(questions_file)?.build()?;
-
-    for batch in questions_reader {
-        let batch = batch?;
-        let question_titles = batch.column_by_name("topics_nl").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let questioners_col = batch.column_by_name("questioners").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let respondents_col = batch.column_by_name("respondents").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let session_ids_col = batch.column_by_name("session_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let meeting_ids_col = batch.column_by_name("meeting_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-        let question_ids_col = batch.column_by_name("question_id").unwrap().as_any().downcast_ref::<StringArray>().unwrap();
-
-        // Loop through questions.
-        for i in 0..question_titles.len() {
-            // Only process questions for the target meeting.
-            let meeting_id = meeting_ids_col.value(i);
-            if meeting_id != target_meeting_id {
+    let summaries = Summary::read(&data_dir.join(Summary::FILE_NAME))?;
+    let summary_map: HashMap<String, String> = summaries
+        .into_iter()
+        .map(|summary| (summary.input_hash, summary.summary))
+        .collect();
+
+
+    // Loop through questions.
+    for question in &questions {
+        // Only process questions for the target meeting.
+        let meeting_id = question.meeting_id.as_str();
+        if question.session_id != target_session_id || meeting_id != target_meeting_id {
+            continue;
+        }
+
+        // Get question title.
+        // Summaries are keyed by the topics joined with ';'.
+        let question_title = question.topics_nl.join(";");
+        let question_hash = hash_text(&question_title);
+        let topics = &question.topics_nl;
+        let summary = if topics.len() > 1 {
+            summary_map.get(&question_hash).cloned().unwrap_or_else(|| topics.join(", "))
+        } else {
+            topics.concat()
+        };
+
+        if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
+            if parent.replies.iter().any(|r| match r {
+                BskyReply::Question(q) => q.hash == question_hash,
+                _ => false,
+            }) {
                 continue;
             }
 
-            // Get question title.
-            let question_title = question_titles.value(i);
-            let question_hash = hash_text(question_title);
-            let topics: Vec<&str> = question_title.split(';').collect();
-            let summary = if topics.len() > 1 {
-                summary_map.get(&question_hash).cloned().unwrap_or_else(|| topics.join(", "))
-            } else {
-                topics[0].to_string()
+            let format_name = |name: &str| -> String {
+                match name_to_handle.get(name) {
+                    Some(handle) => {
+                        handle.to_string()
+                    }
+                    None =>  name.to_string()
+                }
             };
 
-            if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
-                if parent.replies.iter().any(|r| match r {
-                    BskyReply::Question(q) => q.hash == question_hash,
-                    _ => false,
-                }) {
-                    continue;
-                }
+            let session_id = &question.session_id;
+            let question_id = &question.question_id;
+            let questioners: Vec<String> = question.questioners
+                .iter()
+                .map(|name| format_name(name))
+                .collect();
 
-                let format_name = |name: &str| -> String {
-                    match name_to_handle.get(name) {
-                        Some(handle) => {
-                            format!("{}", handle)
-                        }
-                        None =>  format!("{}", name)
-                    }
-                };
-
-                let session_id = session_ids_col.value(i);
-                let question_id = question_ids_col.value(i);
-                let questioners: Vec<String> = questioners_col.value(i)
-                    .split(',')
-                    .map(|s| format_name(s.trim())) // <-- trim here!
-                    .collect();
-
-                let respondents: Vec<String> = respondents_col.value(i)
-                    .split(',')
-                    .map(|s| format_name(s.trim()))
-                    .collect();
-
-                // Take screenshot of question and upload.
-                let vote_url = format!("https://zijwerkenvooru.pages.dev/sessions/{}/meetings/plenary/{}/questions/{}", session_id, meeting_id, question_id);
-                let ScreenshotResult { png_data, viewport } = take_screenshot_of_element(&browser, &vote_url, "#screenshot-target")?;
-                let output = agent
-                    .api
-                    .com
-                    .atproto
-                    .repo
-                    .upload_blob(png_data.as_bytes().to_vec())
-                    .await?;
-                let width = viewport.width.round() as u64;
-                let height = viewport.height.round() as u64;
-                let aspect_ratio = Some(atrium_api::app::bsky::embed::defs::AspectRatioData {
-                    width: NonZeroU64::new(width).unwrap_or(NonZeroU64::new(1).unwrap()),
-                    height: NonZeroU64::new(height).unwrap_or(NonZeroU64::new(1).unwrap()),
-                }.into());
-                let image = ImageData {
-                    alt: "A screenshot of the question.".to_string(),
-                    image: output.data.blob,
-                    aspect_ratio,
-                }.into();
-                let embed = Some(Union::Refs(
-                    RecordEmbedRefs::AppBskyEmbedImagesMain(Box::new(
-                        atrium_api::app::bsky::embed::images::MainData {
-                            images: vec![image],
-                        }.into(),
-                    )),
-                ));
-
-                // Create question post text.
-                let question_post_text = format!(
-                    "❓Vraag\n\"{}\"\n\n{}\n\nDetails: https://zijwerkenvooru.be/sessions/{}/meetings/plenary/{}/questions/{}",
-                    summary,
-                    questioners.join(", "),
-                    session_id, meeting_id, question_id
-                );
-                let truncated_post_text = truncate_to_graphemes(&question_post_text, 300);
-                let rich_text = RichText::new_with_detect_facets(&truncated_post_text).await?;
-
-                let reply_to_uri = if let Some(parent) = posts.iter().find(|p| p.id == target_meeting_id) {
-                    if let Some(last_reply) = parent.replies.last() {
-                        match last_reply {
-                            BskyReply::Vote(v) => Some(v.uri.clone()),
-                            BskyReply::Question(q) => Some(q.uri.clone()),
-                        }
-                    } else {
-                        // No replies yet, reply to main post
-                        main_post_uri.clone()
+            let respondents: Vec<String> = question.respondents
+                .iter()
+                .map(|name| format_name(name))
+                .collect();
+
+            // Take screenshot of question and upload.
+            let vote_url = format!("https://zijwerkenvooru.pages.dev/sessions/{}/meetings/plenary/{}/questions/{}", session_id, meeting_id, question_id);
+            let ScreenshotResult { png_data, viewport } = take_screenshot_of_element(&browser, &vote_url, "#screenshot-target")?;
+            let output = agent
+                .api
+                .com
+                .atproto
+                .repo
+                .upload_blob(png_data)
+                .await?;
+            let width = viewport.width.round() as u64;
+            let height = viewport.height.round() as u64;
+            let aspect_ratio = Some(atrium_api::app::bsky::embed::defs::AspectRatioData {
+                width: NonZeroU64::new(width).unwrap_or(NonZeroU64::new(1).unwrap()),
+                height: NonZeroU64::new(height).unwrap_or(NonZeroU64::new(1).unwrap()),
+            }.into());
+            let image = ImageData {
+                alt: "A screenshot of the question.".to_string(),
+                image: output.data.blob,
+                aspect_ratio,
+            }.into();
+            let embed = Some(Union::Refs(
+                RecordEmbedRefs::AppBskyEmbedImagesMain(Box::new(
+                    atrium_api::app::bsky::embed::images::MainData {
+                        images: vec![image],
+                    }.into(),
+                )),
+            ));
+
+            // Create question post text.
+            let question_post_text = format!(
+                "❓Vraag\n\"{}\"\n\n{}\n\nDetails: https://zijwerkenvooru.be/sessions/{}/meetings/plenary/{}/questions/{}",
+                summary,
+                questioners.join(", "),
+                session_id, meeting_id, question_id
+            );
+            let truncated_post_text = truncate_to_graphemes(&question_post_text, 300);
+            let rich_text = RichText::new_with_detect_facets(&truncated_post_text).await?;
+
+            let reply_to_uri = if let Some(parent) = posts.iter().find(|p| p.id == target_meeting_id) {
+                if let Some(last_reply) = parent.replies.last() {
+                    match last_reply {
+                        BskyReply::Vote(v) => Some(v.uri.clone()),
+                        BskyReply::Question(q) => Some(q.uri.clone()),
                     }
                 } else {
-                    // No parent found, fallback to main post URI
+                    // No replies yet, reply to main post
                     main_post_uri.clone()
-                };
-
-                let root_uri = main_post_uri.clone();
+                }
+            } else {
+                // No parent found, fallback to main post URI
+                main_post_uri.clone()
+            };
 
-                let question_post_uri = create_post(&agent, rich_text.text, rich_text.facets, root_uri, reply_to_uri, embed).await?;
+            let root_uri = main_post_uri.clone();
 
-                let question_reply = BskyReply::Question(BskyReplyQuestion {
-                    hash: question_hash.clone(),
-                    topic: summary.to_string(),
-                    uri: question_post_uri.clone(),
-                    questioners,
-                    respondents,
-                });
+            let question_post_uri = create_post(&agent, rich_text.text, rich_text.facets, root_uri, reply_to_uri, embed).await?;
 
-                // Find parent post by meeting id and add the reply
-                if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
-                    parent.replies.push(question_reply);
-                }
+            let question_reply = BskyReply::Question(BskyReplyQuestion {
+                hash: question_hash.clone(),
+                topic: summary.to_string(),
+                uri: question_post_uri.clone(),
+                questioners,
+                respondents,
+            });
 
-                // Save updated posts to disk
-                fs::write(&posts_log_path, serde_json::to_string_pretty(&posts)?)?;
+            // Find parent post by meeting id and add the reply
+            if let Some(parent) = posts.iter_mut().find(|p| p.id == target_meeting_id) {
+                parent.replies.push(question_reply);
             }
+
+            // Save updated posts to disk
+            fs::write(&posts_log_path, serde_json::to_string_pretty(&posts)?)?;
         }
     }
 
@@ -664,11 +585,11 @@ pub async fn create_post(agent: &BskyAgent, text: String, facets: Option<Vec<atr
 
         record.reply = Some(ReplyRefData {
             root: atrium_api::com::atproto::repo::strong_ref::MainData {
-                uri: root.unwrap().clone().try_into()?,
+                uri: root.unwrap().clone(),
                 cid: parent_post.cid.clone(),
             }.into(),
             parent: atrium_api::com::atproto::repo::strong_ref::MainData {
-                uri: reply_uri.try_into()?,
+                uri: reply_uri,
                 cid: parent_post.cid.clone(),
             }.into(),
         }.into());
@@ -695,9 +616,9 @@ pub async fn get_post(agent: &BskyAgent, uri: &str) -> anyhow::Result<atrium_api
         }.into()
     ).await;
     if let Ok(post_data) = get_posts_result {
-        return Ok(post_data.data.posts[0].clone());
+        Ok(post_data.data.posts[0].clone())
     } else {
-        return Err(anyhow::anyhow!("Failed to get post"));
+        Err(anyhow::anyhow!("Failed to get post"))
     }
 }
 
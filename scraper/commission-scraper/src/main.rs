@@ -1,26 +1,69 @@
-use arrow::array::{ArrayRef, RecordBatch, StringArray};
-use arrow::datatypes::{DataType, Field, Schema};
+use chrono::{NaiveDate, NaiveTime};
+use clap::Parser;
+use crawl::cache::{CachePolicy, HttpCache};
 use crawl::client::ScrapingClient;
+use crawl::sessions::{SessionRange, read_progress, write_progress};
+use crawl::urls::Urls;
 use crawl::utils::clean_text;
-use encoding_rs::WINDOWS_1252;
+use crawl::utils::dates::{DayPart, parse_date, parse_time};
+use crawl::utils::debate::{resolve_speakers, speaker_name, split_turns};
+use crawl::utils::language::detect_language;
+use crawl::utils::names::{MemberIndex, normalize_person_name};
 use http::StatusCode;
-use parquet::arrow::ArrowWriter;
+use model::{CommissionQuestion, Member, Table, Utterance};
 use regex::Regex;
 use scraper::{Html, Selector};
-use serde_json::json;
 use std::error::Error;
 use std::fmt;
-use std::fs::{File, read_to_string};
 use std::path::Path;
-use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs;
 
+/// Reports are still corrected for a while after they are published, so cached copies are
+/// revalidated monthly.
+const REPORT_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Scrapes the commission meeting reports into the Parquet files under `web/src/data`.
+///
+/// The rows of the scraped sessions are replaced, those of the other sessions are kept.
+#[derive(Parser)]
+struct Args {
+    /// Sessions to scrape, e.g. "56" or "52..=56".
+    #[arg(long, default_value_t = SessionRange::current(), conflicts_with = "all_sessions")]
+    session: SessionRange,
+
+    /// Scrape every session with HTML reports.
+    #[arg(long)]
+    all_sessions: bool,
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    // Create scraping client.
-    let client = ScrapingClient::new();
+    let args = Args::parse();
+    let sessions = match args.all_sessions {
+        true => SessionRange::all(),
+        false => args.session,
+    };
+    let unverified = sessions.unverified_sessions();
+    if !unverified.is_empty() {
+        eprintln!(
+            "The reports of session(s) {} may use a layout this scraper does not handle yet, \
+             check their rows before publishing them.",
+            unverified
+                .iter()
+                .map(|session_id| session_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 
-    let session_id = 56;
+    // Create scraping client.
+    let cache_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("data/cache");
+    let client = ScrapingClient::new().with_cache(HttpCache::new(cache_dir));
+    let urls = Urls::from_env();
 
     // Create folders.
     let root = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -28,165 +71,132 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .unwrap()
         .join("../web/src/data");
 
-    let commissions_path = root.join("commissions.parquet");
-    let questions_path = root.join("commission_questions.parquet");
-
-    if let Some(parent) = commissions_path.parent() {
-        fs::create_dir_all(parent).await?;
-    }
-    if let Some(parent) = questions_path.parent() {
-        fs::create_dir_all(parent).await?;
-    }
-
-    // Create parquet files.
-    let commissions_file = File::create(commissions_path).unwrap();
-    let commissions_schema = Arc::new(Schema::new(vec![
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("commission_id", DataType::Utf8, false),
-        Field::new("date", DataType::Utf8, false),
-        Field::new("time_of_day", DataType::Utf8, false),
-        Field::new("start_time", DataType::Utf8, false),
-        Field::new("end_time", DataType::Utf8, false),
-        Field::new("commission", DataType::Utf8, false),
-        Field::new("chair", DataType::Utf8, false),
-    ]));
-
-    let questions_file = File::create(questions_path).unwrap();
-    let questions_schema = Arc::new(Schema::new(vec![
-        Field::new("question_id", DataType::Utf8, false),
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("meeting_id", DataType::Utf8, false),
-        Field::new("questioners", DataType::Utf8, false),
-        Field::new("respondents", DataType::Utf8, false),
-        Field::new("topics_nl", DataType::Utf8, false),
-        Field::new("topics_fr", DataType::Utf8, false),
-        Field::new("discussion", DataType::Utf8, false),
-        Field::new("dossier_ids", DataType::Utf8, false),
-    ]));
+    fs::create_dir_all(&root).await?;
+
+    // Keep the rows of the sessions that are not scraped.
+    let commissions_path = root.join(model::Commission::FILE_NAME);
+    let questions_path = root.join(CommissionQuestion::FILE_NAME);
+    let utterances_path = root.join(Utterance::FILE_NAME);
+    let mut commissions = match commissions_path.exists() {
+        true => model::Commission::read(&commissions_path)?,
+        false => vec![],
+    };
+    let mut questions = match questions_path.exists() {
+        true => CommissionQuestion::read(&questions_path)?,
+        false => vec![],
+    };
+    // The plenary scraper writes the utterances of plenary meetings to the same file.
+    let mut utterances = match utterances_path.exists() {
+        true => Utterance::read(&utterances_path)?,
+        false => vec![],
+    };
+    let scraped = |session_id: &str| session_id.parse().is_ok_and(|id| sessions.contains(id));
+    commissions.retain(|commission| !scraped(&commission.session_id));
+    questions.retain(|question| !scraped(&question.session_id));
+    utterances.retain(|utterance| {
+        utterance.meeting_type != "commission" || !scraped(&utterance.session_id)
+    });
 
     // Scrape data.
-    let mut commission_ids = vec![];
-    let mut commission_session_ids = vec![];
-    let mut dates = vec![];
-    let mut times_of_day = vec![];
-    let mut start_times = vec![];
-    let mut end_times = vec![];
-    let mut commissions = vec![];
-    let mut chairs = vec![];
-    let mut question_ids = vec![];
-    let mut question_session_ids = vec![];
-    let mut question_meeting_ids = vec![];
-    let mut question_questioners = vec![];
-    let mut question_respondents = vec![];
-    let mut question_topics_nl = vec![];
-    let mut question_topics_fr = vec![];
-    let mut question_discussions = vec![];
-    let mut question_dossier_ids = vec![];
-
-    let mut web_request_count = 0;
-
-    // Check for new commission meeting.
-    let commission_id_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+    let progress_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
         .parent()
         .unwrap()
-        .join("current_commission_id.txt");
-    let current_commission_id: u32 = std::fs::read_to_string(&commission_id_path)?
-        .trim()
-        .parse()?;
-
-    let mut last_commission_id = current_commission_id;
-
-    loop {
-        let probe = last_commission_id + 1;
-        let url = format!(
-            "https://www.dekamer.be/doc/CCRI/html/{}/ic{:03}x.html",
-            session_id, probe
-        );
-        let resp = client.get(&url).await?;
-        web_request_count += 1;
+        .join("progress");
+    for session_id in sessions.sessions() {
+        // Check for new commission meeting.
+        let current_commission_id = read_progress(&progress_dir, "commission", session_id)?;
+        let mut last_commission_id = current_commission_id;
+
+        loop {
+            let probe = last_commission_id + 1;
+            let url = urls.commission_report(session_id, probe);
+            // Through the cache, so that the reports are not downloaded again below.
+            let page = client
+                .fetch(&url, CachePolicy::MaxAge(REPORT_MAX_AGE))
+                .await?;
 
-        if resp.status() == StatusCode::NOT_FOUND {
-            break;
+            if page.status == StatusCode::NOT_FOUND {
+                break;
+            } else {
+                // found a new one, move forward
+                last_commission_id = probe;
+            }
+        }
+
+        if last_commission_id == current_commission_id {
+            println!("Session {}: no new meeting available.", session_id);
         } else {
-            // found a new one, move forward
-            last_commission_id = probe;
+            println!(
+                "Session {}: found new meetings up to {}",
+                session_id, last_commission_id
+            );
         }
-    }
 
-    if last_commission_id == current_commission_id {
-        println!("No new meeting available.");
-    } else {
-        println!("Found new meetings up to {}", last_commission_id);
+        for commission in 1..=last_commission_id {
+            // println!("SCRAPING: {}", commission);
+            scrape_commission(
+                &client,
+                &urls,
+                session_id,
+                commission,
+                &mut commissions,
+                &mut questions,
+                &mut utterances,
+            )
+            .await?;
+        }
+
+        write_progress(&progress_dir, "commission", session_id, last_commission_id)?;
     }
 
-    for commission in 1..=last_commission_id {
-        // println!("SCRAPING: {}", commission);
-        scrape_commission(
-            &client,
-            56,
-            commission,
-            &mut commission_ids,
-            &mut commission_session_ids,
-            &mut dates,
-            &mut times_of_day,
-            &mut start_times,
-            &mut end_times,
-            &mut commissions,
-            &mut chairs,
-            &mut question_ids,
-            &mut question_session_ids,
-            &mut question_meeting_ids,
-            &mut question_questioners,
-            &mut question_respondents,
-            &mut question_discussions,
-            &mut question_topics_nl,
-            &mut question_topics_fr,
-            &mut question_dossier_ids,
-            &mut web_request_count,
+    // Sessions scraped by this run follow the ones that were kept.
+    let session_of = |session_id: &str| session_id.parse::<u32>().unwrap_or_default();
+    commissions.sort_by_key(|commission| session_of(&commission.session_id));
+    questions.sort_by_key(|question| session_of(&question.session_id));
+    utterances.sort_by_key(|utterance| session_of(&utterance.session_id));
+
+    let members_path = root.join(Member::FILE_NAME);
+    let members = match members_path.exists() {
+        true => Member::read(&members_path)?,
+        false => vec![],
+    };
+    let members = MemberIndex::new(members.iter().map(|member| {
+        (
+            member.session_id.as_str(),
+            member.member_id.as_str(),
+            member.first_name.as_str(),
+            member.last_name.as_str(),
         )
-        .await?;
+    }));
+    // Only the utterances of commission meetings are matched.
+    let unmatched = resolve_speakers(
+        utterances
+            .iter_mut()
+            .filter(|utterance| {
+                utterance.meeting_type == "commission" && utterance.role == "member"
+            })
+            .map(|utterance| {
+                (
+                    utterance.session_id.as_str(),
+                    utterance.speaker.as_str(),
+                    &mut utterance.member_id,
+                )
+            }),
+        &members,
+    );
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} speaker(s) to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
     }
 
-    std::fs::write(&commission_id_path, last_commission_id.to_string())?;
-
-    let commissions_batch = RecordBatch::try_new(
-        commissions_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(commission_session_ids)) as ArrayRef,
-            Arc::new(StringArray::from(commission_ids)),
-            Arc::new(StringArray::from(dates)),
-            Arc::new(StringArray::from(times_of_day)),
-            Arc::new(StringArray::from(start_times)),
-            Arc::new(StringArray::from(end_times)),
-            Arc::new(StringArray::from(commissions)),
-            Arc::new(StringArray::from(chairs)),
-        ],
-    )?;
-
-    let mut commissions = ArrowWriter::try_new(commissions_file, commissions_schema, None)?;
-    commissions.write(&commissions_batch)?;
-    commissions.close().unwrap();
-
-    let questions_batch = RecordBatch::try_new(
-        questions_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(question_ids)) as ArrayRef,
-            Arc::new(StringArray::from(question_session_ids)),
-            Arc::new(StringArray::from(question_meeting_ids)),
-            Arc::new(StringArray::from(question_questioners)),
-            Arc::new(StringArray::from(question_respondents)),
-            Arc::new(StringArray::from(question_topics_nl)),
-            Arc::new(StringArray::from(question_topics_fr)),
-            Arc::new(StringArray::from(question_discussions)),
-            Arc::new(StringArray::from(question_dossier_ids)),
-        ],
-    )?;
-
-    let mut questions = ArrowWriter::try_new(questions_file, questions_schema, None)?;
-    questions.write(&questions_batch)?;
-    questions.close().unwrap();
+    model::Commission::write(&commissions_path, &commissions)?;
+    CommissionQuestion::write(&questions_path, &questions)?;
+    Utterance::write(&utterances_path, &utterances)?;
 
-    println!("Scraped data using {} web request(s).", web_request_count);
+    println!("Scraped data using {}.", client.stats());
 
     Ok(())
 }
@@ -195,10 +205,12 @@ struct QuestionData {
     questioners: Vec<String>,
     respondents: Vec<String>,
     topics: Vec<String>,
-    discussion: String,
     dossier_ids: Vec<String>,
 }
 
+/// Older sessions name some commissions differently, e.g. "Infrastructuur, het Verkeer en de
+/// Overheidsbedrijven" for mobility and "Bedrijfsleven, het Wetenschapsbeleid, ..." for the
+/// economy.
 fn parse_commission_type(raw: &str) -> Commission {
     let raw = raw.trim().to_lowercase();
     if raw.contains("binnenlandse") {
@@ -207,16 +219,14 @@ fn parse_commission_type(raw: &str) -> Commission {
         Commission::Justitie
     } else if raw.contains("gezondheid") {
         Commission::GezondheidEnGelijkeKansen
-    } else if raw.contains("economie") {
+    } else if raw.contains("economie") || raw.contains("bedrijfsleven") {
         Commission::EconomieConsumentenBeschermingEnDigitalisering
     } else if raw.contains("buitenlandse") {
         Commission::BuitenlandseBetrekkingen
-    } else if raw.contains("mobiliteit") {
+    } else if raw.contains("mobiliteit") || raw.contains("infrastructuur") {
         Commission::MobiliteitOverheidsbedrijvenEnFederaleInstellingen
     } else if raw.contains("landsverdediging") {
         Commission::Landsverdediging
-    } else if raw.contains("landsverdediging") {
-        Commission::Landsverdediging
     } else if raw.contains("energie") {
         Commission::EnergieLeefmilieuEnKlimaat
     } else if raw.contains("sociale") {
@@ -286,79 +296,31 @@ impl fmt::Display for Commission {
 
 async fn scrape_commission(
     client: &ScrapingClient,
+    urls: &Urls,
     session_id: u32,
     commission_id: u32,
-    commission_ids: &mut Vec<String>,
-    commission_session_ids: &mut Vec<String>,
-    dates: &mut Vec<String>,
-    times_of_day: &mut Vec<String>,
-    start_times: &mut Vec<String>,
-    end_times: &mut Vec<String>,
-    commissions: &mut Vec<String>,
-    chairs: &mut Vec<String>,
-    question_ids: &mut Vec<String>,
-    question_session_ids: &mut Vec<String>,
-    question_meeting_ids: &mut Vec<String>,
-    question_questioners: &mut Vec<String>,
-    question_respondents: &mut Vec<String>,
-    question_discussions: &mut Vec<String>,
-    question_topics_nl: &mut Vec<String>,
-    question_topics_fr: &mut Vec<String>,
-    question_dossier_ids: &mut Vec<String>,
-    web_request_count: &mut u32,
+    commissions: &mut Vec<model::Commission>,
+    questions: &mut Vec<CommissionQuestion>,
+    utterances: &mut Vec<Utterance>,
 ) -> Result<(), Box<dyn Error>> {
     println!("Scraping commission: {}", commission_id);
-    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
-    let filename = format!(
-        "data/sources/sessions/{}/meetings/commission/{}-{}.html",
-        session_id, session_id, commission_id
-    );
-    let filepath = root.join(filename);
+    let url = urls.commission_report(session_id, commission_id);
 
-    // Download commission html if it does not exist yet.
-    if !filepath.exists() {
-        let url = format!(
-            "https://www.dekamer.be/doc/CCRI/html/{}/ic{:03}x.html",
-            session_id, commission_id
-        );
-
-        // Fetch and parse the HTML content.
-        let response = client.get(&url).await?;
-        *web_request_count += 1;
-
-        if response.status().as_u16() == 404 {
-            commission_session_ids.push(session_id.to_string());
-            commission_ids.push(commission_id.to_string());
-            times_of_day.push("404".to_string());
-            dates.push("404".to_string());
-            start_times.push("404".to_string());
-            end_times.push("404".to_string());
-            commissions.push("404".to_string());
-            chairs.push("404".to_string());
-            question_ids.push("404".to_string());
-            question_session_ids.push("404".to_string());
-            question_meeting_ids.push("404".to_string());
-            question_questioners.push("404".to_string());
-            question_respondents.push("404".to_string());
-            question_discussions.push("404".to_string());
-            question_topics_nl.push("404".to_string());
-            question_topics_fr.push("404".to_string());
-            question_dossier_ids.push("404".to_string());
-            return Ok(());
-        }
+    // Fetch and parse the HTML content.
+    let page = client
+        .fetch(&url, CachePolicy::MaxAge(REPORT_MAX_AGE))
+        .await?;
 
-        let raw_bytes = response.bytes().await?;
-        let (decoded_str, _, _) = WINDOWS_1252.decode(&raw_bytes); // Decode data using windows_1252 (see meta tag of site <meta http-equiv="Content-Type" content="text/html; charset=windows-1252">)
-        let content = decoded_str.to_string();
-        if let Some(parent) = filepath.parent() {
-            std::fs::create_dir_all(parent).unwrap();
-        }
-        std::fs::write(&filepath, content).unwrap();
+    if page.status.as_u16() == 404 {
+        commissions.push(model::Commission {
+            session_id: session_id.to_string(),
+            commission_id: commission_id.to_string(),
+            ..Default::default()
+        });
+        return Ok(());
     }
 
-    // Fetch and parse the HTML content.
-    let content = read_to_string(filepath)?;
-    let document = Html::parse_document(&content);
+    let document = Html::parse_document(&page.decode_logged().text);
 
     // Extract meeting data.
     // println!("MEETING ID: {}", commission_id);
@@ -375,15 +337,11 @@ async fn scrape_commission(
 
     let mut previous_question_nl = String::new();
     let mut previous_question_fr = String::new();
-    let mut previous_discussion = String::new();
+    let mut previous_paragraphs: Vec<String> = Vec::new();
     let mut question_id: i32 = 0;
-    let mut found_questions = false;
-    let mut processing_questions = false;
+    let processing_questions = true;
     // let mut collecting_subquestions = false;
 
-    found_questions = true;
-    processing_questions = true;
-
     for element in document.select(&Selector::parse("h1, h2, p").unwrap()) {
         let tag_name = element.value().name();
 
@@ -429,11 +387,11 @@ async fn scrape_commission(
         //                 question_meeting_ids.push(commission_id.to_string());
         //                 question_session_ids.push(session_id.to_string());
         //                 question_discussions.push(question_nl.discussion.clone());
-        //                 question_questioners.push(question_nl.questioners.join(","));
-        //                 question_respondents.push(question_nl.respondents.join(","));
-        //                 question_topics_nl.push(question_nl.topics.join(";"));
-        //                 question_topics_fr.push(question_fr.topics.join(";"));
-        //                 question_dossier_ids.push(question_nl.dossier_ids.join(","));
+        //                 question_questioners.push(question_nl.questioners);
+        //                 question_respondents.push(question_nl.respondents);
+        //                 question_topics_nl.push(question_nl.topics);
+        //                 question_topics_fr.push(question_fr.topics);
+        //                 question_dossier_ids.push(question_nl.dossier_ids);
         //                 // question_id += 1;
         //                 previous_discussion.clear();
         //                 previous_question_nl.clear();
@@ -500,51 +458,46 @@ async fn scrape_commission(
 
             // Combine decision-making logic based on Dutch/French headers
             // NOTE: See https://www.dekamer.be/doc/CCRI/html/56/ic017x.html, here there is a grouped question that does not start with 'Samengevoegde' but has 'toegevoegde'.
-            let is_group_start = found_text_nl.as_deref().map_or(false, |t| {
+            let is_group_start = found_text_nl.as_deref().is_some_and(|t| {
                 t.starts_with("Samengevoegde") || t.contains("toegevoegde vragen")
             }) || found_text_fr
                 .as_deref()
-                .map_or(false, |t| t.contains("jointes"));
+                .is_some_and(|t| t.contains("jointes"));
 
-            let is_subquestion = found_text_nl
-                .as_deref()
-                .map_or(false, |t| t.starts_with("-"))
-                || found_text_fr
-                    .as_deref()
-                    .map_or(false, |t| t.starts_with("-"));
+            let is_subquestion = found_text_nl.as_deref().is_some_and(|t| t.starts_with("-"))
+                || found_text_fr.as_deref().is_some_and(|t| t.starts_with("-"));
 
             let is_single_question = found_text_nl
                 .as_deref()
-                .map_or(false, |t| t.starts_with("Vraag van"))
+                .is_some_and(|t| t.starts_with("Vraag van"))
                 || found_text_fr
                     .as_deref()
-                    .map_or(false, |t| t.starts_with("Question de"));
+                    .is_some_and(|t| t.starts_with("Question de"));
 
             if is_group_start || is_single_question {
                 if !previous_question_nl.is_empty() && !previous_question_fr.is_empty() {
-                    let question_nl = extract_question_data(
-                        previous_question_nl.clone(),
-                        previous_discussion.clone(),
-                    )
-                    .await?;
-                    let question_fr = extract_question_data(
-                        previous_question_fr.clone(),
-                        previous_discussion.clone(),
-                    )
-                    .await?;
-
-                    question_ids.push(question_id.to_string());
-                    question_meeting_ids.push(commission_id.to_string());
-                    question_session_ids.push(session_id.to_string());
-                    question_discussions.push(question_nl.discussion.clone());
-                    question_questioners.push(question_nl.questioners.join(",")); // NL used here
-                    question_respondents.push(question_nl.respondents.join(","));
-                    question_topics_nl.push(question_nl.topics.join(";"));
-                    question_topics_fr.push(question_fr.topics.join(";"));
-                    question_dossier_ids.push(question_nl.dossier_ids.join(","));
+                    let question_nl = extract_question_data(previous_question_nl.clone()).await?;
+                    let question_fr = extract_question_data(previous_question_fr.clone()).await?;
+
+                    utterances.extend(extract_utterances(
+                        session_id,
+                        commission_id,
+                        &question_id.to_string(),
+                        &previous_paragraphs,
+                    ));
+                    questions.push(CommissionQuestion {
+                        question_id: question_id.to_string(),
+                        session_id: session_id.to_string(),
+                        meeting_id: commission_id.to_string(),
+                        questioners: question_nl.questioners, // NL used here
+                        respondents: question_nl.respondents,
+                        topics_nl: question_nl.topics,
+                        topics_fr: question_fr.topics,
+                        dossier_ids: question_nl.dossier_ids,
+                    });
 
                     question_id += 1;
-                    previous_discussion.clear();
+                    previous_paragraphs.clear();
                     previous_question_nl.clear();
                     previous_question_fr.clear();
                 }
@@ -559,11 +512,11 @@ async fn scrape_commission(
                 // collecting_subquestions = true;
             } else if is_subquestion {
                 if let Some(text) = found_text_nl {
-                    previous_question_nl.push_str("\n");
+                    previous_question_nl.push('\n');
                     previous_question_nl.push_str(text.as_str());
                 }
                 if let Some(text) = found_text_fr {
-                    previous_question_fr.push_str("\n");
+                    previous_question_fr.push('\n');
                     previous_question_fr.push_str(text.as_str());
                 }
             }
@@ -577,53 +530,52 @@ async fn scrape_commission(
                 .trim()
                 .to_string();
 
-            let discussion_paragraph = clean_text(&paragraph_text);
-
             if !paragraph_text.is_empty() {
-                previous_discussion.push_str(discussion_paragraph.as_str());
-                previous_discussion.push_str("NEWPARAGRAPH");
+                previous_paragraphs.push(clean_text(&paragraph_text));
             }
         }
     }
 
     // Push last question if there is one
     if !previous_question_nl.is_empty() && !previous_question_fr.is_empty() {
-        let question_nl =
-            extract_question_data(previous_question_nl.clone(), previous_discussion.clone())
-                .await?;
-        let question_fr =
-            extract_question_data(previous_question_fr.clone(), previous_discussion.clone())
-                .await?;
-
-        question_ids.push(question_id.to_string());
-        question_meeting_ids.push(commission_id.to_string());
-        question_session_ids.push(session_id.to_string());
-        question_discussions.push(question_nl.discussion.clone());
-        question_questioners.push(question_nl.questioners.join(","));
-        question_respondents.push(question_nl.respondents.join(","));
-        question_topics_nl.push(question_nl.topics.join(";"));
-        question_topics_fr.push(question_fr.topics.join(";"));
-        question_dossier_ids.push(question_nl.dossier_ids.join(","));
-
-        question_id += 1;
+        let question_nl = extract_question_data(previous_question_nl.clone()).await?;
+        let question_fr = extract_question_data(previous_question_fr.clone()).await?;
+
+        utterances.extend(extract_utterances(
+            session_id,
+            commission_id,
+            &question_id.to_string(),
+            &previous_paragraphs,
+        ));
+        questions.push(CommissionQuestion {
+            question_id: question_id.to_string(),
+            session_id: session_id.to_string(),
+            meeting_id: commission_id.to_string(),
+            questioners: question_nl.questioners,
+            respondents: question_nl.respondents,
+            topics_nl: question_nl.topics,
+            topics_fr: question_fr.topics,
+            dossier_ids: question_nl.dossier_ids,
+        });
     }
 
-    commission_session_ids.push(session_id.to_string());
-    commission_ids.push(commission_id.to_string());
-    dates.push(date.to_string());
-    times_of_day.push(time_of_day.to_string());
-    start_times.push(start_time.to_string());
-    end_times.push(end_time.to_string());
-    commissions.push(commission.to_string());
-    chairs.push(chair.to_string());
+    commissions.push(model::Commission {
+        session_id: session_id.to_string(),
+        commission_id: commission_id.to_string(),
+        date: Some(date),
+        time_of_day: Some(time_of_day),
+        start_time: Some(start_time),
+        end_time: Some(end_time),
+        commission: Some(commission),
+        chair,
+    });
 
     Ok(())
 }
-fn extract_time_from_document(document: &Html, keywords: &[&str]) -> Option<String> {
+fn extract_time_from_document(document: &Html, keywords: &[&str]) -> Option<NaiveTime> {
     let span_selector = Selector::parse("span, p").unwrap();
-    let re = Regex::new(r"(\d{1,2})\.(\d{2})\s*?uur").unwrap();
 
-    let mut last_time: Option<String> = None;
+    let mut last_time: Option<NaiveTime> = None;
 
     // Iterate through all spans.
     for span in document.select(&span_selector) {
@@ -632,10 +584,9 @@ fn extract_time_from_document(document: &Html, keywords: &[&str]) -> Option<Stri
         // Check if any of the keywords are in the text
         if keywords.iter().any(|&keyword| text.contains(keyword)) {
             // println!("{}", text);
-            // Try to capture the time pattern.
-            if let Some(caps) = re.captures(&text) {
-                // Update last_time with the most recent time match.
-                last_time = Some(format!("{}h{}", &caps[1], &caps[2]));
+            // Update last_time with the most recent time match.
+            if let Some(time) = parse_time(&text) {
+                last_time = Some(time);
             }
         }
     }
@@ -643,7 +594,7 @@ fn extract_time_from_document(document: &Html, keywords: &[&str]) -> Option<Stri
     last_time
 }
 
-fn extract_start_time_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_start_time_from_document(document: &Html) -> Result<NaiveTime, Box<dyn Error>> {
     let keywords = [
         "De behandeling van de",
         "De behandeling van de vragen en de interpellatie vangt aan om",
@@ -662,25 +613,15 @@ fn extract_start_time_from_document(document: &Html) -> Result<String, Box<dyn E
 fn extract_time_of_day_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
     let span_selector = Selector::parse("span").unwrap();
     for span in document.select(&span_selector) {
-        let text = span
-            .text()
-            .collect::<Vec<_>>()
-            .join(" ")
-            .trim()
-            .to_lowercase();
-
-        if text == "namiddag" {
-            return Ok("afternoon".to_string());
-        } else if text == "voormiddag" {
-            return Ok("morning".to_string());
-        } else if text == "avond" {
-            return Ok("evening".to_string());
+        let text = span.text().collect::<Vec<_>>().join(" ");
+        if let Some(day_part) = DayPart::from_label(&text) {
+            return Ok(day_part.to_string());
         }
     }
     Err("Could not extract time of day from the document".into())
 }
 
-fn extract_end_time_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_end_time_from_document(document: &Html) -> Result<NaiveTime, Box<dyn Error>> {
     let keywords = [
         "De openbare commissievergadering wordt gesloten",
         "De gedachtewisseling met de ministers eindigt",
@@ -694,7 +635,7 @@ fn extract_end_time_from_document(document: &Html) -> Result<String, Box<dyn Err
         .ok_or_else(|| "No end time found matching the expected patterns".into())
 }
 
-fn extract_date_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_date_from_document(document: &Html) -> Result<NaiveDate, Box<dyn Error>> {
     let table_selector = Selector::parse("table").unwrap();
     if let Some(first_table) = document.select(&table_selector).next() {
         let span_selector = Selector::parse("span").unwrap();
@@ -706,32 +647,14 @@ fn extract_date_from_document(document: &Html) -> Result<String, Box<dyn Error>>
             extracted_text.push(' ');
         }
 
-        let re = Regex::new(r"(\d{1,2})\s+([a-zA-Z]+)\s+(\d{4})").unwrap();
-        if let Some(caps) = re.captures(&extracted_text) {
-            let day = format!("{:02}", caps[1].parse::<u8>()?);
-            let month = match &caps[2].to_lowercase()[..] {
-                "januari" => "01",
-                "februari" => "02",
-                "maart" => "03",
-                "april" => "04",
-                "mei" => "05",
-                "juni" => "06",
-                "juli" => "07",
-                "augustus" => "08",
-                "september" => "09",
-                "oktober" => "10",
-                "november" => "11",
-                "december" => "12",
-                _ => return Err("Invalid month name".into()),
-            };
-            let year = &caps[3];
-            return Ok(format!("{}-{}-{}", year, month, day));
+        if let Some(date) = parse_date(&extracted_text) {
+            return Ok(date);
         }
     }
     Err("Could not extract date from the document".into())
 }
 
-pub fn extract_chair_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+pub fn extract_chair_from_document(document: &Html) -> Result<Vec<String>, Box<dyn Error>> {
     let node_selector = Selector::parse("span, p").unwrap();
 
     // 1.  Grab everything that follows “voorgezeten door …” up to the dot (or EOL)
@@ -757,10 +680,8 @@ pub fn extract_chair_from_document(document: &Html) -> Result<String, Box<dyn Er
             // Split on “ en ” and strip titles from each part
             let mut names: Vec<String> = Vec::new();
             for part in chunk.split(" en ") {
-                let clean = re_titles
-                    .replace_all(part.trim(), "") // remove title words
-                    .trim() // trim leftover spaces
-                    .to_string();
+                // Remove title words.
+                let clean = normalize_person_name(&re_titles.replace_all(part, ""));
                 if !clean.is_empty() {
                     names.push(clean);
                 }
@@ -768,7 +689,7 @@ pub fn extract_chair_from_document(document: &Html) -> Result<String, Box<dyn Er
 
             if !names.is_empty() {
                 // println!("{}", names.join(","));
-                return Ok(names.join(", "));
+                return Ok(names);
             }
         }
     }
@@ -797,10 +718,7 @@ fn extract_commission_from_document(document: &Html) -> Result<String, Box<dyn E
     Err("Could not extract commission".into())
 }
 
-async fn extract_question_data(
-    question_text: String,
-    discussion_text: String,
-) -> Result<QuestionData, Box<dyn Error>> {
+async fn extract_question_data(question_text: String) -> Result<QuestionData, Box<dyn Error>> {
     //  println!("TOPIC: {}", question_text);
     // FIXME: French issue, single ticks (fixed now?)
     //let regex = Regex::new(r#"(?m)(?:(?:Vraag van|Question de)\s)?([^\n]+?)\s+(?:aan|à)\s+([^\n]+?)\s*\(.*?\)\s*(?:over|sur)\s*'([^"']+?)'\s*\((\d{8}[A-Z])\)"#)?;
@@ -828,8 +746,8 @@ async fn extract_question_data(
             .as_str()
             .trim()
             .replace("- ", "")
-            .replace("de heer ", "") // remove titles
-            .to_string();
+            .replace("de heer ", ""); // remove titles
+        let questioner = normalize_person_name(&questioner);
 
         let respondent = capture.get(2).unwrap().as_str().trim().to_string();
 
@@ -852,89 +770,35 @@ async fn extract_question_data(
         questioners,
         respondents,
         topics,
-        discussion: get_discussion_json(&discussion_text),
         dossier_ids,
     })
 }
 
-fn get_discussion_json(input: &str) -> String {
-    let cleaned_input = clean_text(input);
-    // println!("DISC: {}", cleaned_input);
-
-    // Regex to capture timestamps and speakers (including Le président and De voorzitter)
-    // FIX: it looks for numbers like 07.01 to find new sections, but it also fell over 20.00 which could just be an hour within the text!
-    // SO: new regex which checks that either no characters should be before it
-    // (?m)(?:^|(?:NEWPARAGRAPH))[\n\r\s ]*(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:
-    // instead of
-    // (?m)(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:
-    let speaker_re = Regex::new(
-        r"(?m)(?:^|(?:NEWPARAGRAPH))[\n\r\s ]*(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:"
-    ).unwrap();
-
-    let speaker_name_re = Regex::new(r"^[^(,:\n\r]+").unwrap();
-    let title_re = Regex::new(r"^(Minister|De heer|Mevrouw|Le ministre|La ministre|Monsieur|Madame|Eerste minister|Staatssecretaris)\s+").unwrap();
-
-    let mut discussion = Vec::new();
-    let mut current_speaker = String::new();
-    let mut last_end = 0;
-
-    for cap in speaker_re.captures_iter(&cleaned_input) {
-        let match_start = cap.get(0).unwrap().start();
-        let text_segment = cleaned_input[last_end..match_start].trim();
-
-        if !current_speaker.is_empty() && !text_segment.is_empty() {
-            let clean_text_segment = text_segment
-                .replace("Het incident is gesloten.", "")
-                .replace("L'incident est clos.", "")
-                //.replace("NEWPARAGRAPH", "\n")
-                .trim()
-                .to_string();
-
-            if !clean_text_segment.is_empty() {
-                // println!("SPEAKER: {}", current_speaker);
-
-                discussion.push(json!({
-                    "speaker": current_speaker.trim(),
-                    "text": clean_text_segment
-                }));
-            }
-        }
-
-        // Determine speaker
-        current_speaker = if let Some(full_speaker) = cap.get(2) {
-            let speaker_raw = full_speaker.as_str().trim();
-            let speaker_stripped = title_re.replace(speaker_raw, "").to_string();
-            speaker_name_re
-                .captures(&speaker_stripped)
-                .and_then(|c| c.get(0))
-                .map_or("Onbekend".to_string(), |m| m.as_str().to_string())
-        } else {
-            // It's the chairperson
-            "Voorzitter".to_string()
-        };
-        // println!("{}", current_speaker);
-
-        last_end = cap.get(0).unwrap().end();
-    }
-
-    // Handle last speaker's segment
-    if !current_speaker.is_empty() && last_end < cleaned_input.len() {
-        let text_segment = &cleaned_input[last_end..];
-        let clean_text_segment = text_segment
-            .replace("Het incident is gesloten.", "")
-            .replace("L'incident est clos.", "")
-            .replace("NEWPARAGRAPH", "\n")
-            .trim()
-            .to_string();
-
-        if !clean_text_segment.is_empty() {
-            // println!("{}", current_speaker);
-            discussion.push(json!({
-                "speaker": current_speaker.trim(),
-                "text": clean_text_segment
-            }));
-        }
-    }
-
-    serde_json::to_string_pretty(&discussion).unwrap()
+/// The speech turns of the debate on a question, from its paragraphs. The `member_id`s are set by
+/// `resolve_speakers`.
+fn extract_utterances(
+    session_id: u32,
+    commission_id: u32,
+    question_id: &str,
+    paragraphs: &[String],
+) -> Vec<Utterance> {
+    split_turns(paragraphs.iter().map(String::as_str))
+        .into_iter()
+        .enumerate()
+        .map(|(sequence, turn)| Utterance {
+            session_id: session_id.to_string(),
+            meeting_id: commission_id.to_string(),
+            meeting_type: "commission".to_string(),
+            question_id: Some(question_id.to_string()),
+            sequence: sequence as i32,
+            minister: (turn.role == "minister").then(|| speaker_name(&turn.label)),
+            speaker: turn.label,
+            member_id: None,
+            role: turn.role.to_string(),
+            language: detect_language(&turn.paragraphs).map(str::to_string),
+            paragraphs: turn.paragraphs,
+            marker: turn.marker,
+            item_id: None,
+        })
+        .collect()
 }
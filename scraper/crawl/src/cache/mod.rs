@@ -0,0 +1,167 @@
+mod policy;
+
+pub use policy::CachePolicy;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Content-addressed HTTP cache on disk.
+///
+/// Bodies are stored once per SHA-256 under `objects/`, the metadata of every URL (status, headers,
+/// body hash and timestamps) as JSON under `entries/`, keyed by the SHA-256 of the URL.
+#[derive(Debug, Clone)]
+pub struct HttpCache {
+    root: PathBuf,
+}
+
+/// Metadata of a cached response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub url: String,
+    pub status: u16,
+    /// Response headers as (lowercase name, value) pairs.
+    pub headers: Vec<(String, String)>,
+    /// SHA-256 of the body, which is also its name under `objects/`.
+    pub body_hash: String,
+    /// When the body was downloaded.
+    pub fetched_at: SystemTime,
+    /// When the server last confirmed the body, either by sending it or by answering 304 Not
+    /// Modified.
+    pub validated_at: SystemTime,
+}
+
+/// How a page was obtained by `ScrapingClient::fetch`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// Served from the cache without contacting the server.
+    Fresh,
+    /// Revalidated with the server, which confirmed the cached copy is still current.
+    NotModified,
+    /// Revalidated with the server, which returned different content.
+    Modified,
+    /// Not cached before; downloaded and stored.
+    Miss,
+    /// Downloaded but not stored, because there is no cache or the response was not a success.
+    Uncached,
+}
+
+impl CacheEntry {
+    /// Value of the response header `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn is_fresh(&self, policy: CachePolicy) -> bool {
+        policy.is_fresh(self.validated_at)
+    }
+}
+
+impl HttpCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        HttpCache { root: root.into() }
+    }
+
+    /// Looks up the entry for `url`. Entries whose body went missing are treated as not cached.
+    pub fn lookup(&self, url: &str) -> io::Result<Option<CacheEntry>> {
+        let path = self.entry_path(url);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let entry: CacheEntry = serde_json::from_slice(&fs::read(path)?)?;
+        if entry.url != url || !self.object_path(&entry.body_hash).exists() {
+            return Ok(None);
+        }
+
+        Ok(Some(entry))
+    }
+
+    pub fn body(&self, entry: &CacheEntry) -> io::Result<Vec<u8>> {
+        fs::read(self.object_path(&entry.body_hash))
+    }
+
+    /// Stores a freshly downloaded response for `url`, replacing any previous entry.
+    pub fn store(
+        &self,
+        url: &str,
+        status: u16,
+        headers: Vec<(String, String)>,
+        body: &[u8],
+    ) -> io::Result<CacheEntry> {
+        let body_hash = sha256_hex(body);
+        let object_path = self.object_path(&body_hash);
+        if !object_path.exists() {
+            write_atomic(&object_path, body)?;
+        }
+
+        let now = SystemTime::now();
+        let entry = CacheEntry {
+            url: url.to_string(),
+            status,
+            headers,
+            body_hash,
+            fetched_at: now,
+            validated_at: now,
+        };
+        self.write_entry(&entry)?;
+
+        Ok(entry)
+    }
+
+    /// Records that the server confirmed `entry` is current, taking over any headers it sent along
+    /// (e.g. a new ETag).
+    pub fn revalidated(
+        &self,
+        entry: &mut CacheEntry,
+        headers: Vec<(String, String)>,
+    ) -> io::Result<()> {
+        for (name, value) in headers {
+            match entry.headers.iter_mut().find(|(key, _)| *key == name) {
+                Some(existing) => existing.1 = value,
+                None => entry.headers.push((name, value)),
+            }
+        }
+        entry.validated_at = SystemTime::now();
+
+        self.write_entry(entry)
+    }
+
+    fn write_entry(&self, entry: &CacheEntry) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(entry)?;
+        write_atomic(&self.entry_path(&entry.url), &json)
+    }
+
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let hash = sha256_hex(url.as_bytes());
+        self.root
+            .join("entries")
+            .join(&hash[..2])
+            .join(format!("{}.json", hash))
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.root.join("objects").join(&hash[..2]).join(hash)
+    }
+}
+
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Writes to a temporary file next to `path` first, so readers never see a half-written file.
+pub(crate) fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = path.with_extension(format!("{:016x}.tmp", rand::random::<u64>()));
+    fs::write(&temp_path, contents)?;
+    fs::rename(temp_path, path)
+}
@@ -0,0 +1,30 @@
+use std::time::{Duration, SystemTime};
+
+/// Decides whether a cached page can be used as is or has to be revalidated with the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// Use the cached copy whenever there is one; only missing pages are fetched.
+    Forever,
+    /// Use the cached copy for this long after it was last validated, then revalidate.
+    MaxAge(Duration),
+    /// Revalidate when the cached copy was last validated before this moment.
+    RefreshIfOlderThan(SystemTime),
+    /// Always revalidate with the server.
+    Revalidate,
+}
+
+impl CachePolicy {
+    /// Whether a copy that was last validated at `validated_at` can be used without contacting the
+    /// server.
+    pub fn is_fresh(&self, validated_at: SystemTime) -> bool {
+        match self {
+            CachePolicy::Forever => true,
+            CachePolicy::MaxAge(max_age) => SystemTime::now()
+                .duration_since(validated_at)
+                .map(|age| age <= *max_age)
+                .unwrap_or(true),
+            CachePolicy::RefreshIfOlderThan(moment) => validated_at >= *moment,
+            CachePolicy::Revalidate => false,
+        }
+    }
+}
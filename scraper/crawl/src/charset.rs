@@ -0,0 +1,119 @@
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_8, UTF_16BE, UTF_16LE};
+use regex::bytes::Regex;
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Only the start of a document is searched for a `<meta>` charset, like browsers do.
+const META_PRESCAN_BYTES: usize = 4096;
+
+static META_CHARSET: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)<meta\b[^>]*?charset\s*=\s*["']?\s*([a-z0-9_\-:.]+)"#).unwrap()
+});
+
+/// Where the encoding used to decode a page came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharsetSource {
+    /// The `charset` parameter of the `Content-Type` header.
+    ContentType,
+    /// A `<meta charset>` or `<meta http-equiv="Content-Type">` tag in the document.
+    MetaTag,
+    /// A byte order mark at the start of the body.
+    ByteOrderMark,
+    /// Guessed from the bytes, because nothing declared an encoding.
+    Sniffed,
+}
+
+/// Text decoded from a page, together with the encoding that was used.
+#[derive(Debug, Clone)]
+pub struct Decoded {
+    pub text: String,
+    pub encoding: &'static Encoding,
+    pub source: CharsetSource,
+    /// Whether malformed bytes were replaced with U+FFFD, which usually means the encoding is
+    /// wrong.
+    pub had_errors: bool,
+}
+
+impl fmt::Display for CharsetSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let source = match self {
+            CharsetSource::ContentType => "Content-Type header",
+            CharsetSource::MetaTag => "meta tag",
+            CharsetSource::ByteOrderMark => "byte order mark",
+            CharsetSource::Sniffed => "sniffed",
+        };
+        write!(f, "{}", source)
+    }
+}
+
+/// Decodes `body` with the charset from the `Content-Type` header, then the `<meta>` tag, and
+/// otherwise the byte order mark or a guess based on the content. `tld` (e.g. "be") helps the guess
+/// for legacy encodings.
+pub fn decode(body: &[u8], content_type: Option<&str>, tld: Option<&str>) -> Decoded {
+    let declared = content_type
+        .and_then(charset_from_content_type)
+        .map(|encoding| (encoding, CharsetSource::ContentType))
+        .or_else(|| charset_from_meta(body).map(|encoding| (encoding, CharsetSource::MetaTag)));
+
+    let (encoding, source) = match declared {
+        Some(declared) => declared,
+        None => match Encoding::for_bom(body) {
+            Some((encoding, _)) => (encoding, CharsetSource::ByteOrderMark),
+            None => {
+                let mut detector = EncodingDetector::new();
+                detector.feed(body, true);
+                (
+                    detector.guess(tld.map(str::as_bytes), true),
+                    CharsetSource::Sniffed,
+                )
+            }
+        },
+    };
+
+    // A byte order mark always wins, even over a declared charset.
+    let (text, used_encoding, had_errors) = encoding.decode(body);
+    let source = if used_encoding != encoding {
+        CharsetSource::ByteOrderMark
+    } else {
+        source
+    };
+
+    Decoded {
+        text: text.into_owned(),
+        encoding: used_encoding,
+        source,
+        had_errors,
+    }
+}
+
+/// Reads the `charset` parameter of a `Content-Type` header value.
+pub fn charset_from_content_type(content_type: &str) -> Option<&'static Encoding> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (key, label) = param.split_once('=')?;
+        if !key.trim().eq_ignore_ascii_case("charset") {
+            return None;
+        }
+        Encoding::for_label(
+            label
+                .trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .as_bytes(),
+        )
+    })
+}
+
+/// Finds the charset declared by a `<meta>` tag near the start of the document.
+pub fn charset_from_meta(body: &[u8]) -> Option<&'static Encoding> {
+    let head = &body[..body.len().min(META_PRESCAN_BYTES)];
+    let label = META_CHARSET.captures(head)?.get(1)?.as_bytes();
+    let encoding = Encoding::for_label(label)?;
+
+    // A document that could be parsed as ASCII up to its meta tag cannot be UTF-16, so browsers
+    // read it as UTF-8.
+    if encoding == UTF_16LE || encoding == UTF_16BE {
+        Some(UTF_8)
+    } else {
+        Some(encoding)
+    }
+}
@@ -0,0 +1,131 @@
+use super::Error;
+use super::page::{header_map, header_pairs};
+use crate::cache::{sha256_hex, write_atomic};
+use reqwest::Response;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Environment variable that turns on a cassette: "record" or "replay".
+pub const CASSETTE_MODE_VAR: &str = "CRAWL_CASSETTE_MODE";
+/// Environment variable with the directory the cassette is stored in.
+pub const CASSETTE_DIR_VAR: &str = "CRAWL_CASSETTE_DIR";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Send requests to the network and save every response.
+    Record,
+    /// Answer requests from the saved responses, without any network access.
+    Replay,
+}
+
+/// A directory of recorded responses, one pair of files per URL, so scrapers can run against a
+/// frozen snapshot.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    pub mode: CassetteMode,
+    pub dir: PathBuf,
+}
+
+/// Status and headers of a recorded response; the body is stored next to it.
+#[derive(Serialize, Deserialize)]
+struct Recording {
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+}
+
+impl Cassette {
+    pub fn record(dir: impl Into<PathBuf>) -> Self {
+        Cassette {
+            mode: CassetteMode::Record,
+            dir: dir.into(),
+        }
+    }
+
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Cassette {
+            mode: CassetteMode::Replay,
+            dir: dir.into(),
+        }
+    }
+
+    /// Reads the cassette from `CRAWL_CASSETTE_MODE` and `CRAWL_CASSETTE_DIR`, if the mode is set.
+    ///
+    /// Panics on an unknown mode or a missing directory, so a misconfigured run never silently goes
+    /// online.
+    pub fn from_env() -> Option<Self> {
+        let mode = env::var(CASSETTE_MODE_VAR).ok()?;
+        let dir = env::var(CASSETTE_DIR_VAR).unwrap_or_else(|_| {
+            panic!(
+                "{} is set but {} is not",
+                CASSETTE_MODE_VAR, CASSETTE_DIR_VAR
+            )
+        });
+
+        match mode.to_ascii_lowercase().as_str() {
+            "record" => Some(Cassette::record(dir)),
+            "replay" => Some(Cassette::replay(dir)),
+            _ => panic!(
+                "{} must be \"record\" or \"replay\", not {:?}",
+                CASSETTE_MODE_VAR, mode
+            ),
+        }
+    }
+
+    /// Saves `response` for `url` and returns an equivalent response to the caller.
+    pub(crate) async fn save(&self, url: &str, response: Response) -> Result<Response, Error> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+
+        let recording = Recording {
+            url: url.to_string(),
+            status: status.as_u16(),
+            headers: header_pairs(&headers),
+        };
+        let (recording_path, body_path) = self.paths(url);
+        write_atomic(&body_path, &body)?;
+        write_atomic(
+            &recording_path,
+            &serde_json::to_vec_pretty(&recording).map_err(io::Error::from)?,
+        )?;
+
+        Ok(build_response(status.as_u16(), headers, body))
+    }
+
+    /// Loads the recorded response for `url`.
+    pub(crate) fn load(&self, url: &str) -> Result<Response, Error> {
+        let (recording_path, body_path) = self.paths(url);
+        if !recording_path.exists() {
+            return Err(Error::NotRecorded {
+                url: url.to_string(),
+            });
+        }
+
+        let recording: Recording =
+            serde_json::from_slice(&fs::read(recording_path)?).map_err(io::Error::from)?;
+        let body = fs::read(body_path)?;
+
+        Ok(build_response(
+            recording.status,
+            header_map(&recording.headers),
+            body,
+        ))
+    }
+
+    fn paths(&self, url: &str) -> (PathBuf, PathBuf) {
+        let base = self.dir.join(sha256_hex(url.as_bytes()));
+        (base.with_extension("json"), base.with_extension("body"))
+    }
+}
+
+fn build_response(status: u16, headers: HeaderMap, body: Vec<u8>) -> Response {
+    let mut response = http::Response::new(body);
+    *response.status_mut() = http::StatusCode::from_u16(status).unwrap_or(http::StatusCode::OK);
+    *response.headers_mut() = headers;
+    Response::from(response)
+}
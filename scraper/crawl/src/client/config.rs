@@ -0,0 +1,73 @@
+use super::cassette::Cassette;
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub const DEFAULT_USER_AGENT: &str = "zijwerkenvooru/1.0 (+https://www.zijwerkenvooru.be)";
+pub const DEFAULT_FROM: &str = "info@zijwerkenvooru.be";
+
+/// Settings that control how politely and how persistently the client fetches pages.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    /// Minimum time between two requests to the same host.
+    pub min_interval: Duration,
+    /// Per-host overrides for `min_interval`, keyed by host name (e.g. "www.dekamer.be").
+    pub host_intervals: HashMap<String, Duration>,
+    /// Timeout for a single request, including reading the response headers.
+    pub timeout: Duration,
+    /// Timeout for establishing the connection.
+    pub connect_timeout: Duration,
+    /// Number of additional attempts after a failed request.
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled on every following retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for the backoff. A request the server asks to retry later than this (with
+    /// `Retry-After`) fails instead of being retried sooner than asked.
+    pub max_backoff: Duration,
+    /// Identifies the scraper to the sites it reads, with a link to the project.
+    pub user_agent: String,
+    /// Contact address sent in the `From` header, so site operators can reach us.
+    pub from: Option<String>,
+    /// Fetches each site's robots.txt and refuses URLs it disallows. Its `Crawl-delay` raises
+    /// `min_interval`.
+    pub respect_robots_txt: bool,
+    /// Number of requests `fetch_all` keeps in flight at once. Requests to one host still respect
+    /// its interval.
+    pub max_concurrent_requests: usize,
+    /// Records responses to, or replays them from, a cassette instead of only using the network.
+    /// Defaults to the cassette configured in the environment (see `Cassette::from_env`).
+    pub cassette: Option<Cassette>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            min_interval: Duration::from_millis(500),
+            host_intervals: HashMap::new(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_retries: 4,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_concurrent_requests: 4,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            from: Some(DEFAULT_FROM.to_string()),
+            respect_robots_txt: true,
+            cassette: Cassette::from_env(),
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Overrides the minimum interval between requests for a single host.
+    pub fn with_host_interval(mut self, host: &str, interval: Duration) -> Self {
+        self.host_intervals.insert(host.to_string(), interval);
+        self
+    }
+
+    pub(crate) fn interval_for(&self, host: &str) -> Duration {
+        self.host_intervals
+            .get(host)
+            .copied()
+            .unwrap_or(self.min_interval)
+    }
+}
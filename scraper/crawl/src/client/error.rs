@@ -0,0 +1,53 @@
+use reqwest::StatusCode;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read.
+    Request(reqwest::Error),
+    /// The server kept answering with a retryable error status.
+    Status { url: String, status: StatusCode },
+    /// Reading from or writing to the HTTP cache or the cassette failed.
+    Cache(io::Error),
+    /// The cassette being replayed has no response for this URL.
+    NotRecorded { url: String },
+    /// The site's robots.txt does not allow our user agent to fetch this URL.
+    Disallowed { url: String, user_agent: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Request(error) => write!(f, "request failed: {}", error),
+            Error::Status { url, status } => write!(f, "{} returned {}", url, status),
+            Error::Cache(error) => write!(f, "cache error: {}", error),
+            Error::NotRecorded { url } => write!(f, "{} is not in the cassette", url),
+            Error::Disallowed { url, user_agent } => {
+                write!(f, "robots.txt disallows {} for {}", url, user_agent)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Status { .. } | Error::NotRecorded { .. } | Error::Disallowed { .. } => None,
+            Error::Cache(error) => Some(error),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Cache(error)
+    }
+}
@@ -1,40 +1,385 @@
-use reqwest::{header, Client, Error, Response};
+mod cassette;
+mod config;
+mod error;
+mod page;
+mod rate_limit;
+mod retry;
+mod robots;
+mod stats;
+
+pub use cassette::{CASSETTE_DIR_VAR, CASSETTE_MODE_VAR, Cassette, CassetteMode};
+pub use config::{ClientConfig, DEFAULT_FROM, DEFAULT_USER_AGENT};
+pub use error::Error;
+pub use page::Page;
+pub use robots::Robots;
+pub use stats::RequestStats;
+
+use crate::cache::{CachePolicy, CacheStatus, HttpCache};
+use futures::stream::{self, Stream, StreamExt};
+use page::header_pairs;
+use rate_limit::RateLimiter;
+use reqwest::{Client, Response, StatusCode, Url, header};
+use stats::Counters;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// How long a cached robots.txt is used before it is downloaded again, as RFC 9309 recommends.
+const ROBOTS_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
 
 pub struct ScrapingClient {
-    client: Client
+    client: Client,
+    config: ClientConfig,
+    rate_limiter: RateLimiter,
+    counters: Counters,
+    cache: Option<HttpCache>,
+    /// Parsed robots.txt per origin ("https://www.dekamer.be").
+    robots: Mutex<HashMap<String, Arc<Robots>>>,
+}
+
+impl Default for ScrapingClient {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ScrapingClient {
     pub fn new() -> Self {
+        Self::with_config(ClientConfig::default())
+    }
+
+    pub fn with_config(config: ClientConfig) -> Self {
         let client = Client::builder()
-            .build().unwrap();
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            .unwrap();
 
         ScrapingClient {
-            client
+            client,
+            config,
+            rate_limiter: RateLimiter::new(),
+            counters: Counters::default(),
+            cache: None,
+            robots: Mutex::new(HashMap::new()),
         }
     }
 
+    /// Serves `fetch` from `cache` where the cache policy allows it and stores new pages in it.
+    pub fn with_cache(mut self, cache: HttpCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fetches `url`, waiting for the host's rate limit and retrying transient failures.
+    ///
+    /// Non-retryable statuses such as 404 are returned as a normal response so callers can inspect
+    /// them. URLs that the site's robots.txt disallows fail with `Error::Disallowed` without being
+    /// requested.
     pub async fn get(&self, url: &str) -> Result<Response, Error> {
+        self.send(url, header::HeaderMap::new()).await
+    }
+
+    /// Fetches `url` through the cache.
+    ///
+    /// A cached copy that is fresh according to `policy` is returned without a request. A stale
+    /// copy is revalidated with a conditional GET (`If-None-Match`/`If-Modified-Since`). Only
+    /// successful responses are stored; other statuses such as 404 are returned uncached. Without a
+    /// cache, every call downloads the page. The cache is skipped while a cassette is used, so that
+    /// recordings contain every page a run needs.
+    pub async fn fetch(&self, url: &str, policy: CachePolicy) -> Result<Page, Error> {
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| self.config.cassette.is_none());
+        let Some(cache) = cache else {
+            let response = self.send(url, header::HeaderMap::new()).await?;
+            return Ok(Page::from_response(url, response, CacheStatus::Uncached).await?);
+        };
+
+        let cached = cache.lookup(url)?;
+        let mut conditional = header::HeaderMap::new();
+        if let Some(entry) = &cached {
+            if entry.is_fresh(policy) {
+                self.counters.cache_hit();
+                let body = cache.body(entry)?;
+                return Ok(Page::from_entry(entry.clone(), body, CacheStatus::Fresh));
+            }
+
+            let validators = [
+                (header::IF_NONE_MATCH, entry.header("etag")),
+                (header::IF_MODIFIED_SINCE, entry.header("last-modified")),
+            ];
+            for (name, value) in validators {
+                if let Some(Ok(value)) = value.map(header::HeaderValue::from_str) {
+                    conditional.insert(name, value);
+                }
+            }
+        }
+
+        let response = self.send(url, conditional).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = cached {
+                cache.revalidated(&mut entry, header_pairs(response.headers()))?;
+                let body = cache.body(&entry)?;
+                return Ok(Page::from_entry(entry, body, CacheStatus::NotModified));
+            }
+        }
+
+        let mut page = Page::from_response(url, response, CacheStatus::Uncached).await?;
+        if page.status.is_success() {
+            let entry = cache.store(
+                url,
+                page.status.as_u16(),
+                header_pairs(&page.headers),
+                &page.body,
+            )?;
+            page.cache_status = match cached {
+                None => CacheStatus::Miss,
+                Some(previous) if previous.body_hash == entry.body_hash => CacheStatus::NotModified,
+                Some(_) => CacheStatus::Modified,
+            };
+        }
+
+        Ok(page)
+    }
+
+    /// Fetches every `(url, policy)` pair through the cache, keeping up to
+    /// `max_concurrent_requests` in flight.
+    ///
+    /// The pages are yielded in the order of `requests`, each with its URL. Every request still
+    /// waits for its host's rate limit, so concurrency mainly overlaps slow responses, cache reads
+    /// and requests to different hosts.
+    pub fn fetch_all<'a, I>(
+        &'a self,
+        requests: I,
+    ) -> impl Stream<Item = (String, Result<Page, Error>)> + 'a
+    where
+        I: IntoIterator<Item = (String, CachePolicy)>,
+        I::IntoIter: 'a,
+    {
+        stream::iter(requests)
+            .map(move |(url, policy)| async move {
+                let page = self.fetch(&url, policy).await;
+                (url, page)
+            })
+            .buffered(self.config.max_concurrent_requests.max(1))
+    }
+
+    /// Sends a GET request with the default headers plus `extra_headers`, or answers it from the
+    /// cassette.
+    async fn send(&self, url: &str, extra_headers: header::HeaderMap) -> Result<Response, Error> {
+        match &self.config.cassette {
+            Some(cassette) if cassette.mode == CassetteMode::Replay => {
+                self.counters.request();
+                cassette.load(url)
+            }
+            Some(cassette) => {
+                let response = self.send_online(url, extra_headers).await?;
+                cassette.save(url, response).await
+            }
+            None => self.send_online(url, extra_headers).await,
+        }
+    }
+
+    /// Sends a GET request over the network, after checking it against the site's robots.txt.
+    async fn send_online(
+        &self,
+        url: &str,
+        extra_headers: header::HeaderMap,
+    ) -> Result<Response, Error> {
+        let parsed = Url::parse(url).ok();
+        let host = parsed
+            .as_ref()
+            .and_then(|url| url.host_str().map(|host| host.to_string()))
+            .unwrap_or_default();
+        let mut interval = self.config.interval_for(&host);
+
+        if let Some(parsed) = parsed.filter(|_| self.config.respect_robots_txt) {
+            let robots = self.robots_for(&parsed, &host, interval).await?;
+            let path = match parsed.query() {
+                Some(query) => format!("{}?{}", parsed.path(), query),
+                None => parsed.path().to_string(),
+            };
+            if !robots.is_allowed(&path) {
+                return Err(Error::Disallowed {
+                    url: url.to_string(),
+                    user_agent: self.config.user_agent.clone(),
+                });
+            }
+            if let Some(crawl_delay) = robots.crawl_delay() {
+                interval = interval.max(crawl_delay);
+            }
+        }
+
+        self.send_with_retries(url, &host, interval, extra_headers)
+            .await
+    }
+
+    /// The robots.txt rules of the site serving `url`, downloaded once per client and cached for a
+    /// day.
+    async fn robots_for(
+        &self,
+        url: &Url,
+        host: &str,
+        interval: Duration,
+    ) -> Result<Arc<Robots>, Error> {
+        let origin = url.origin().ascii_serialization();
+        // Held while downloading, so concurrent requests to a new site wait for a single robots.txt
+        // request.
+        let mut known = self.robots.lock().await;
+        if let Some(robots) = known.get(&origin) {
+            return Ok(robots.clone());
+        }
+
+        let robots_url = format!("{}/robots.txt", origin);
+        let robots = Arc::new(self.download_robots(&robots_url, host, interval).await?);
+        known.insert(origin, robots.clone());
+        Ok(robots)
+    }
+
+    async fn download_robots(
+        &self,
+        robots_url: &str,
+        host: &str,
+        interval: Duration,
+    ) -> Result<Robots, Error> {
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| self.config.cassette.is_none());
+        if let Some(cache) = cache {
+            if let Some(entry) = cache.lookup(robots_url)? {
+                if entry.is_fresh(CachePolicy::MaxAge(ROBOTS_MAX_AGE)) {
+                    self.counters.cache_hit();
+                    let body = cache.body(&entry)?;
+                    return Ok(Robots::parse(
+                        &String::from_utf8_lossy(&body),
+                        &self.config.user_agent,
+                    ));
+                }
+            }
+        }
+
         let response = self
-            .client
-            .get(url)
-            .headers(self.headers())
-            .send()
-            .await;
+            .send_with_retries(robots_url, host, interval, header::HeaderMap::new())
+            .await?;
+        let status = response.status();
+        if status.is_client_error() {
+            // A missing or forbidden robots.txt places no restrictions on crawling.
+            return Ok(Robots::allow_all());
+        }
+        if !status.is_success() {
+            return Err(Error::Status {
+                url: robots_url.to_string(),
+                status,
+            });
+        }
 
-        response
+        let headers = header_pairs(response.headers());
+        let body = response.bytes().await?;
+        if let Some(cache) = cache {
+            cache.store(robots_url, status.as_u16(), headers, &body)?;
+        }
+        Ok(Robots::parse(
+            &String::from_utf8_lossy(&body),
+            &self.config.user_agent,
+        ))
+    }
+
+    async fn send_with_retries(
+        &self,
+        url: &str,
+        host: &str,
+        interval: Duration,
+        extra_headers: header::HeaderMap,
+    ) -> Result<Response, Error> {
+        self.counters.request();
+
+        let mut attempt = 0;
+        loop {
+            self.rate_limiter.acquire(host, interval).await;
+
+            let result = self
+                .client
+                .get(url)
+                .headers(self.headers())
+                .headers(extra_headers.clone())
+                .send()
+                .await;
+
+            let backoff = retry::backoff(
+                attempt,
+                self.config.initial_backoff,
+                self.config.max_backoff,
+            );
+            // None when the server asks to wait longer than we are willing to.
+            let delay = match &result {
+                Ok(response) if !retry::is_retryable_status(response.status()) => {
+                    return result.map_err(Error::from);
+                }
+                Ok(response) => match retry::retry_after(response.headers()) {
+                    Some(retry_after) if retry_after > self.config.max_backoff => {
+                        // Other requests to the host still wait as long as the server asked.
+                        self.rate_limiter.defer(host, retry_after).await;
+                        None
+                    }
+                    Some(retry_after) => Some(retry_after.max(backoff)),
+                    None => Some(backoff),
+                },
+                Err(error) if retry::is_retryable_error(error) => Some(backoff),
+                Err(_) => {
+                    self.counters.failure();
+                    return result.map_err(Error::from);
+                }
+            };
+
+            let Some(delay) = delay.filter(|_| attempt < self.config.max_retries) else {
+                self.counters.failure();
+                return match result {
+                    Ok(response) => Err(Error::Status {
+                        url: url.to_string(),
+                        status: response.status(),
+                    }),
+                    Err(error) => Err(Error::from(error)),
+                };
+            };
+
+            attempt += 1;
+            self.counters.retry();
+            // Delays the whole host, so concurrent requests back off as well.
+            self.rate_limiter.defer(host, delay).await;
+        }
+    }
+
+    /// Request counters for this client since it was created.
+    pub fn stats(&self) -> RequestStats {
+        self.counters.snapshot()
     }
 
     fn headers(&self) -> header::HeaderMap {
         let mut headers = header::HeaderMap::new();
-        headers.insert(header::USER_AGENT, header::HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/133.0.0.0 Safari/537.36 Edg/133.0.0.0"));
-        headers.insert(header::ACCEPT, header::HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.7"));
-        headers.insert(header::ACCEPT_LANGUAGE, header::HeaderValue::from_static("en-US,en;q=0.9,nl;q=0.8"));
-        headers.insert(header::CONNECTION, header::HeaderValue::from_static("keep-alive"));
-        headers.insert(header::UPGRADE_INSECURE_REQUESTS, header::HeaderValue::from_static("1"));
-        headers.insert(header::REFERER, header::HeaderValue::from_static("https://www.dekamer.be"));
-        // headers.insert(header::FROM, header::HeaderValue::from_static("alexanderameye@gmail.com"));
+        if let Ok(user_agent) = header::HeaderValue::from_str(&self.config.user_agent) {
+            headers.insert(header::USER_AGENT, user_agent);
+        }
+        headers.insert(
+            header::ACCEPT_LANGUAGE,
+            header::HeaderValue::from_static("en-US,en;q=0.9,nl;q=0.8"),
+        );
+        headers.insert(
+            header::CONNECTION,
+            header::HeaderValue::from_static("keep-alive"),
+        );
+        if let Some(Ok(from)) = self
+            .config
+            .from
+            .as_deref()
+            .map(header::HeaderValue::from_str)
+        {
+            headers.insert(header::FROM, from);
+        }
 
         headers
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,111 @@
+use crate::cache::{CacheEntry, CacheStatus};
+use crate::charset::{self, Decoded};
+use reqwest::header::{self, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Response, StatusCode, Url};
+
+/// A downloaded or cached page.
+#[derive(Debug, Clone)]
+pub struct Page {
+    pub url: String,
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+    pub cache_status: CacheStatus,
+}
+
+impl Page {
+    pub(crate) async fn from_response(
+        url: &str,
+        response: Response,
+        cache_status: CacheStatus,
+    ) -> Result<Self, reqwest::Error> {
+        let url = url.to_string();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?.to_vec();
+
+        Ok(Page {
+            url,
+            status,
+            headers,
+            body,
+            cache_status,
+        })
+    }
+
+    pub(crate) fn from_entry(entry: CacheEntry, body: Vec<u8>, cache_status: CacheStatus) -> Self {
+        Page {
+            status: StatusCode::from_u16(entry.status).unwrap_or(StatusCode::OK),
+            headers: header_map(&entry.headers),
+            url: entry.url,
+            body,
+            cache_status,
+        }
+    }
+
+    /// Whether the content differs from what was cached before (or nothing was cached).
+    pub fn is_changed(&self) -> bool {
+        !matches!(
+            self.cache_status,
+            CacheStatus::Fresh | CacheStatus::NotModified
+        )
+    }
+
+    /// Decodes the body, see `charset::decode` for how the encoding is picked.
+    pub fn decode(&self) -> Decoded {
+        let content_type = self
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok());
+        let host = Url::parse(&self.url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| host.to_string()));
+        let tld = host.as_deref().and_then(|host| host.rsplit('.').next());
+
+        charset::decode(&self.body, content_type, tld)
+    }
+
+    /// Decodes the body like `decode`, and warns on stderr when malformed bytes had to be replaced,
+    /// which usually means the page was decoded with the wrong encoding.
+    pub fn decode_logged(&self) -> Decoded {
+        let decoded = self.decode();
+        if decoded.had_errors {
+            eprintln!(
+                "{}: malformed bytes when decoding as {} ({})",
+                self.url,
+                decoded.encoding.name(),
+                decoded.source
+            );
+        }
+        decoded
+    }
+
+    /// The decoded body.
+    pub fn text(&self) -> String {
+        self.decode().text
+    }
+}
+
+/// Response headers as (lowercase name, value) pairs, as stored in the cache.
+pub(crate) fn header_pairs(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| {
+            Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+        })
+        .collect()
+}
+
+/// Builds a header map from stored (name, value) pairs, skipping any that are no longer valid.
+pub(crate) fn header_map(pairs: &[(String, String)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in pairs {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            headers.append(name, value);
+        }
+    }
+    headers
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, sleep_until};
+
+/// Spaces out requests per host so that no host receives more than one request per interval.
+pub(crate) struct RateLimiter {
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new() -> Self {
+        RateLimiter {
+            next_slot: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Waits until the next request to `host` is allowed and reserves that slot.
+    pub(crate) async fn acquire(&self, host: &str, interval: Duration) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let now = Instant::now();
+            let slot = next_slot
+                .get(host)
+                .copied()
+                .filter(|slot| *slot > now)
+                .unwrap_or(now);
+            next_slot.insert(host.to_string(), slot + interval);
+            slot
+        };
+
+        sleep_until(slot).await;
+    }
+
+    /// Pushes the next slot for `host` back, e.g. when the server asked us to slow down.
+    pub(crate) async fn defer(&self, host: &str, delay: Duration) {
+        let mut next_slot = self.next_slot.lock().await;
+        let deferred = Instant::now() + delay;
+        let slot = next_slot.entry(host.to_string()).or_insert(deferred);
+        if *slot < deferred {
+            *slot = deferred;
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+/// The rules of a robots.txt file (RFC 9309) that apply to one user agent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Robots {
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Default)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+impl Robots {
+    /// Allows every path, as when a site has no robots.txt.
+    pub fn allow_all() -> Self {
+        Robots::default()
+    }
+
+    /// Reads the groups of `text` that apply to `user_agent`.
+    ///
+    /// The groups naming the product token of `user_agent` ("zijwerkenvooru" in "zijwerkenvooru/1.0
+    /// (...)") are used if there are any, otherwise the `*` groups. Unknown lines and lines outside
+    /// a group are ignored.
+    pub fn parse(text: &str, user_agent: &str) -> Self {
+        let token = product_token(user_agent);
+
+        let mut groups: Vec<Group> = vec![];
+        let mut in_agent_lines = false;
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            if key == "user-agent" {
+                if !in_agent_lines {
+                    groups.push(Group::default());
+                }
+                in_agent_lines = true;
+                groups.last_mut().unwrap().agents.push(value.to_lowercase());
+                continue;
+            }
+            in_agent_lines = false;
+
+            let Some(group) = groups.last_mut() else {
+                continue;
+            };
+            match key.as_str() {
+                "allow" | "disallow" if !value.is_empty() => group.rules.push(Rule {
+                    allow: key == "allow",
+                    pattern: value.to_string(),
+                }),
+                "crawl-delay" => {
+                    group.crawl_delay = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+                        .map(Duration::from_secs_f64);
+                }
+                _ => {}
+            }
+        }
+
+        let named: Vec<&Group> = groups
+            .iter()
+            .filter(|group| group.agents.contains(&token))
+            .collect();
+        let applicable = if named.is_empty() {
+            groups
+                .iter()
+                .filter(|group| group.agents.iter().any(|agent| agent == "*"))
+                .collect()
+        } else {
+            named
+        };
+
+        Robots {
+            rules: applicable
+                .iter()
+                .flat_map(|group| group.rules.iter().cloned())
+                .collect(),
+            crawl_delay: applicable
+                .iter()
+                .filter_map(|group| group.crawl_delay)
+                .max(),
+        }
+    }
+
+    /// Whether `path` (including the query string) may be fetched.
+    ///
+    /// The longest matching rule decides; on a tie `Allow` wins. "/robots.txt" itself is always
+    /// allowed.
+    pub fn is_allowed(&self, path: &str) -> bool {
+        if path == "/robots.txt" {
+            return true;
+        }
+
+        self.rules
+            .iter()
+            .filter(|rule| matches(rule.pattern.as_bytes(), path.as_bytes()))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    /// The `Crawl-delay` the site asks for, if any.
+    pub fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+/// The name in a user agent string, lowercased: "zijwerkenvooru" for "zijwerkenvooru/1.0
+/// (+https://...)".
+fn product_token(user_agent: &str) -> String {
+    user_agent
+        .split(|c: char| c == '/' || c.is_whitespace())
+        .next()
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Matches a path prefix pattern in which `*` matches any characters and a trailing `$` anchors the
+/// end.
+fn matches(pattern: &[u8], path: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => true,
+        Some((b'$', [])) => path.is_empty(),
+        Some((b'*', rest)) => (0..=path.len()).any(|skip| matches(rest, &path[skip..])),
+        Some((c, rest)) => path.first() == Some(c) && matches(rest, &path[1..]),
+    }
+}
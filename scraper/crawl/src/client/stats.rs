@@ -0,0 +1,61 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Counters for a single scraper run.
+#[derive(Default)]
+pub(crate) struct Counters {
+    requests: AtomicU32,
+    cache_hits: AtomicU32,
+    retries: AtomicU32,
+    failures: AtomicU32,
+}
+
+impl Counters {
+    pub(crate) fn request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn failure(&self) {
+        self.failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> RequestStats {
+        RequestStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Snapshot of the request counters of a `ScrapingClient`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestStats {
+    /// Requests issued by the scraper (retries not included).
+    pub requests: u32,
+    /// Pages served from the cache without contacting the server.
+    pub cache_hits: u32,
+    /// Extra attempts made after a failed request.
+    pub retries: u32,
+    /// Requests that still failed after all retries.
+    pub failures: u32,
+}
+
+impl fmt::Display for RequestStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} web request(s), {} cache hit(s), {} retry(s), {} failure(s)",
+            self.requests, self.cache_hits, self.retries, self.failures
+        )
+    }
+}
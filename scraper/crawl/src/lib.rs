@@ -1,3 +1,7 @@
-pub mod utils;
+pub mod cache;
 pub mod characters;
-pub mod client;
\ No newline at end of file
+pub mod charset;
+pub mod client;
+pub mod sessions;
+pub mod urls;
+pub mod utils;
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The first session (legislature) whose reports dekamer.be publishes as HTML.
+pub const FIRST_SESSION: u32 = 52;
+
+/// The current session, 2024-2029.
+pub const CURRENT_SESSION: u32 = 56;
+
+/// The first session whose reports the meeting scrapers were checked against. Older reports are
+/// parsed the same way, but differences in their layout are not handled yet.
+pub const FIRST_VERIFIED_SESSION: u32 = 56;
+
+/// A range of sessions to scrape, parsed from "56", "52..=56" or "52..57".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionRange {
+    first: u32,
+    last: u32,
+}
+
+impl SessionRange {
+    /// Only the current session.
+    pub fn current() -> Self {
+        SessionRange {
+            first: CURRENT_SESSION,
+            last: CURRENT_SESSION,
+        }
+    }
+
+    /// Every session from `FIRST_SESSION` up to the current one.
+    pub fn all() -> Self {
+        SessionRange {
+            first: FIRST_SESSION,
+            last: CURRENT_SESSION,
+        }
+    }
+
+    pub fn contains(&self, session_id: u32) -> bool {
+        self.sessions().contains(&session_id)
+    }
+
+    pub fn sessions(&self) -> RangeInclusive<u32> {
+        self.first..=self.last
+    }
+
+    /// The sessions in the range from before `FIRST_VERIFIED_SESSION`.
+    pub fn unverified_sessions(&self) -> Vec<u32> {
+        self.sessions()
+            .filter(|&session_id| session_id < FIRST_VERIFIED_SESSION)
+            .collect()
+    }
+}
+
+impl Default for SessionRange {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+impl FromStr for SessionRange {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let number = |text: &str| {
+            text.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("'{}' is not a session number", text.trim()))
+        };
+
+        let (first, last) = if let Some((first, last)) = text.split_once("..=") {
+            (number(first)?, number(last)?)
+        } else if let Some((first, end)) = text.split_once("..") {
+            let end = number(end)?;
+            if end == 0 {
+                return Err(format!("'{}' contains no sessions", text));
+            }
+            (number(first)?, end - 1)
+        } else {
+            let session = number(text)?;
+            (session, session)
+        };
+
+        if first > last {
+            return Err(format!("'{}' contains no sessions", text));
+        }
+        if first < FIRST_SESSION || last > CURRENT_SESSION {
+            return Err(format!(
+                "sessions must be between {} and {}",
+                FIRST_SESSION, CURRENT_SESSION
+            ));
+        }
+        Ok(SessionRange { first, last })
+    }
+}
+
+impl fmt::Display for SessionRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.first == self.last {
+            true => write!(f, "{}", self.first),
+            false => write!(f, "{}..={}", self.first, self.last),
+        }
+    }
+}
+
+/// The file in `progress_dir` with the last ID a scraper reached in a session, e.g.
+/// "plenary_56.txt".
+fn progress_path(progress_dir: &Path, prefix: &str, session_id: u32) -> PathBuf {
+    progress_dir.join(format!("{}_{}.txt", prefix, session_id))
+}
+
+/// Reads the last ID the scraper `prefix` reached in a session, or 0 when it has not started it.
+pub fn read_progress(progress_dir: &Path, prefix: &str, session_id: u32) -> io::Result<u32> {
+    let path = progress_path(progress_dir, prefix, session_id);
+    match path.exists() {
+        true => std::fs::read_to_string(&path)?
+            .trim()
+            .parse()
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error)),
+        false => Ok(0),
+    }
+}
+
+/// Stores the last ID the scraper `prefix` reached in a session, for `read_progress`.
+pub fn write_progress(
+    progress_dir: &Path,
+    prefix: &str,
+    session_id: u32,
+    id: u32,
+) -> io::Result<()> {
+    std::fs::create_dir_all(progress_dir)?;
+    std::fs::write(
+        progress_path(progress_dir, prefix, session_id),
+        id.to_string(),
+    )
+}
@@ -0,0 +1,124 @@
+use std::env;
+
+/// A website the scrapers read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// www.dekamer.be: reports, dossiers and member pages.
+    Dekamer,
+    /// public.regimand.be: declared mandates and remunerations.
+    Regimand,
+}
+
+impl Source {
+    pub fn default_base_url(self) -> &'static str {
+        match self {
+            Source::Dekamer => "https://www.dekamer.be",
+            Source::Regimand => "https://public.regimand.be",
+        }
+    }
+
+    /// Environment variable that overrides the base URL, e.g. to point at a local fixture server.
+    pub fn env_var(self) -> &'static str {
+        match self {
+            Source::Dekamer => "CRAWL_DEKAMER_BASE_URL",
+            Source::Regimand => "CRAWL_REGIMAND_BASE_URL",
+        }
+    }
+
+    /// The base URL from the environment, or the real website if it is not overridden.
+    pub fn base_url(self) -> String {
+        env::var(self.env_var())
+            .ok()
+            .filter(|base| !base.trim().is_empty())
+            .map(|base| base.trim().trim_end_matches('/').to_string())
+            .unwrap_or_else(|| self.default_base_url().to_string())
+    }
+}
+
+/// Builds the URLs of every page the scrapers fetch.
+#[derive(Debug, Clone)]
+pub struct Urls {
+    dekamer: String,
+    regimand: String,
+}
+
+impl Default for Urls {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+impl Urls {
+    /// Uses the base URL of every source from the environment (see `Source::base_url`).
+    pub fn from_env() -> Self {
+        Urls {
+            dekamer: Source::Dekamer.base_url(),
+            regimand: Source::Regimand.base_url(),
+        }
+    }
+
+    /// Overrides the base URL of a single source.
+    pub fn with_base_url(mut self, source: Source, base_url: &str) -> Self {
+        let base_url = base_url.trim_end_matches('/').to_string();
+        match source {
+            Source::Dekamer => self.dekamer = base_url,
+            Source::Regimand => self.regimand = base_url,
+        }
+        self
+    }
+
+    /// Full report (integraal verslag) of a plenary meeting.
+    pub fn plenary_report(&self, session_id: u32, meeting_id: u32) -> String {
+        format!(
+            "{}/doc/PCRI/html/{}/ip{:03}x.html",
+            self.dekamer, session_id, meeting_id
+        )
+    }
+
+    /// Full report of a commission meeting.
+    pub fn commission_report(&self, session_id: u32, commission_id: u32) -> String {
+        format!(
+            "{}/doc/CCRI/html/{}/ic{:03}x.html",
+            self.dekamer, session_id, commission_id
+        )
+    }
+
+    /// Overview page of a dossier with its documents.
+    pub fn dossier(&self, session_id: u32, dossier_id: &str) -> String {
+        format!(
+            "{}/kvvcr/showpage.cfm?section=/flwb&language=nl&cfm=/site/wwwcfm/flwb/flwbn.cfm?lang=N&legislat={}&dossierID={}",
+            self.dekamer, session_id, dossier_id
+        )
+    }
+
+    /// List of the current members.
+    pub fn member_list(&self) -> String {
+        format!(
+            "{}/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=/site/wwwcfm/depute/cvlist54.cfm",
+            self.dekamer
+        )
+    }
+
+    /// List of everyone who was a member during a session, including those who left.
+    pub fn session_member_list(&self, session_id: u32) -> String {
+        format!(
+            "{}/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=cvlist54.cfm?legis={:02}&today=n",
+            self.dekamer, session_id
+        )
+    }
+
+    /// Details page of a member, from the relative link on a member list (e.g. "showpage.cfm?...").
+    pub fn member_page(&self, link: &str) -> String {
+        format!("{}/kvvcr/{}", self.dekamer, link)
+    }
+
+    /// Any other page on dekamer.be, from an absolute path such as an image source.
+    pub fn dekamer_path(&self, path: &str) -> String {
+        format!("{}{}", self.dekamer, path)
+    }
+
+    /// Declared mandates and remunerations of a mandatary ("First Last") for a year.
+    pub fn remuneration(&self, mandatary: &str, year: u32) -> String {
+        format!("{}/?mandatary={}&year={}", self.regimand, mandatary, year)
+    }
+}
@@ -0,0 +1,186 @@
+use chrono::{NaiveDate, NaiveTime};
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+const DUTCH_MONTHS: [&str; 12] = [
+    "januari",
+    "februari",
+    "maart",
+    "april",
+    "mei",
+    "juni",
+    "juli",
+    "augustus",
+    "september",
+    "oktober",
+    "november",
+    "december",
+];
+
+const FRENCH_MONTHS: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+/// A day, month name and year, optionally preceded by a weekday: "donderdag 12 juni 2025", "jeudi
+/// 1er mai 2025".
+static DATE_PHRASE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(\d{1,2})(?:er)?\s+(\p{L}+)\s+(\d{4})\b").unwrap());
+
+/// "14.15 uur", "14.15 heures", "14h15" or "14:15". A dotted time needs a unit, so "art. 1.23" is
+/// not a time.
+static TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(\d{1,2})(?:[.:](\d{2})\s*0?(?:uur|heures?|u|h)\b|[h:](\d{2})\b)").unwrap()
+});
+
+/// The part of the day a meeting takes place in, as printed on the reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayPart {
+    Morning,
+    Afternoon,
+    Evening,
+}
+
+impl DayPart {
+    /// Reads a Dutch or French label: "voormiddag"/"matin", "namiddag"/"après-midi",
+    /// "avond"/"soir".
+    pub fn from_label(label: &str) -> Option<Self> {
+        match normalize(label).as_str() {
+            "voormiddag" | "ochtend" | "matin" => Some(DayPart::Morning),
+            "namiddag" | "middag" | "apres-midi" | "apres midi" => Some(DayPart::Afternoon),
+            "avond" | "soir" | "soiree" => Some(DayPart::Evening),
+            _ => None,
+        }
+    }
+
+    /// The value stored in the data files.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DayPart::Morning => "morning",
+            DayPart::Afternoon => "afternoon",
+            DayPart::Evening => "evening",
+        }
+    }
+
+    pub fn dutch_label(self) -> &'static str {
+        match self {
+            DayPart::Morning => "Voormiddag",
+            DayPart::Afternoon => "Namiddag",
+            DayPart::Evening => "Avond",
+        }
+    }
+
+    pub fn french_label(self) -> &'static str {
+        match self {
+            DayPart::Morning => "Matin",
+            DayPart::Afternoon => "Après-midi",
+            DayPart::Evening => "Soir",
+        }
+    }
+}
+
+impl fmt::Display for DayPart {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Parses the stored value ("afternoon") as well as a Dutch or French label.
+impl FromStr for DayPart {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match normalize(s).as_str() {
+            "morning" => Ok(DayPart::Morning),
+            "afternoon" => Ok(DayPart::Afternoon),
+            "evening" => Ok(DayPart::Evening),
+            _ => DayPart::from_label(s).ok_or_else(|| format!("Unknown part of the day: {:?}", s)),
+        }
+    }
+}
+
+pub fn dutch_month_to_number(month: &str) -> Option<u32> {
+    let month = month.trim().to_lowercase();
+    month_number(&DUTCH_MONTHS, |name| *name == month)
+}
+
+/// Accepts the month names with or without accents ("février", "fevrier").
+pub fn french_month_to_number(month: &str) -> Option<u32> {
+    let month = normalize(month);
+    month_number(&FRENCH_MONTHS, |name| normalize(name) == month)
+}
+
+/// Dutch name of a month number (1 to 12).
+pub fn dutch_month_name(month: u32) -> Option<&'static str> {
+    DUTCH_MONTHS.get(month.checked_sub(1)? as usize).copied()
+}
+
+/// French name of a month number (1 to 12).
+pub fn french_month_name(month: u32) -> Option<&'static str> {
+    FRENCH_MONTHS.get(month.checked_sub(1)? as usize).copied()
+}
+
+/// Month number of a Dutch or French month name.
+pub fn month_to_number(month: &str) -> Option<u32> {
+    dutch_month_to_number(month).or_else(|| french_month_to_number(month))
+}
+
+/// Finds the first valid Dutch or French date phrase in `text`, e.g. "donderdag 12 juni 2025" or
+/// "jeudi 12 juin 2025".
+///
+/// Numbers followed by a word that is not a month ("12 leden 2025") and impossible dates ("31 juni
+/// 2025") are skipped.
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    DATE_PHRASE.captures_iter(text).find_map(|caps| {
+        let day = caps[1].parse().ok()?;
+        let month = month_to_number(&caps[2])?;
+        let year = caps[3].parse().ok()?;
+        NaiveDate::from_ymd_opt(year, month, day)
+    })
+}
+
+/// Finds the first valid time in `text`: "14.15 uur", "14.15 heures", "14h15" or "14:15".
+pub fn parse_time(text: &str) -> Option<NaiveTime> {
+    TIME.captures_iter(text).find_map(|caps| {
+        let hour = caps[1].parse().ok()?;
+        let minute = caps.get(2).or_else(|| caps.get(3))?.as_str().parse().ok()?;
+        NaiveTime::from_hms_opt(hour, minute, 0)
+    })
+}
+
+/// Formats a time as "09h05", "14h15".
+pub fn format_time(time: NaiveTime) -> String {
+    time.format("%Hh%M").to_string()
+}
+
+fn month_number(names: &[&str; 12], matches: impl Fn(&&str) -> bool) -> Option<u32> {
+    names.iter().position(matches).map(|index| index as u32 + 1)
+}
+
+/// Lowercases and strips the accents used in French month and day-part names.
+fn normalize(text: &str) -> String {
+    text.trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'à' | 'â' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'î' | 'ï' => 'i',
+            'ô' => 'o',
+            'û' | 'ù' => 'u',
+            _ => c,
+        })
+        .collect()
+}
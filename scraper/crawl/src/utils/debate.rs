@@ -0,0 +1,128 @@
+use crate::utils::names::MemberIndex;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::sync::LazyLock;
+
+/// A numbered turn starts with its marker and the speaker: "07.01 Alexia Bertrand (Open Vld): ...".
+/// The marker must start the paragraph, as numbers like "20.00" also occur in the text.
+static NUMBERED_TURN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{2}\.\d{2})\s+([^:]{1,150}?)\s*:\s*(.*)$").unwrap());
+
+/// The chair's turns are usually not numbered: "De voorzitter: ...", "Le président : ...".
+static CHAIR_TURN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(De voorzitter|De voorzitster|Le président|La présidente)\s*:\s*(.*)$").unwrap()
+});
+
+/// The chair, also when their turn is numbered.
+static CHAIR: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(De voorzitter|De voorzitster|Le président|La présidente)\b").unwrap()
+});
+
+/// Titles and functions around a speaker's name: "Minister Jan Jambon", "Jan Jambon, ministre".
+static TITLE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(Vice-eersteminister|Eerste minister|Minister|Staatssecretaris|De heer|Mevrouw|Le ministre|La ministre|Monsieur|Madame)\s+",
+    )
+    .unwrap()
+});
+
+/// Paragraphs that close a question rather than belong to the last speaker.
+const CLOSING_PARAGRAPHS: &[&str] = &["Het incident is gesloten.", "L'incident est clos."];
+
+/// A speech turn in the debate on a question.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    /// The number of the turn in the report, "07.01" for the first turn of item 7. `None` for most
+    /// turns of the chair, which are not numbered.
+    pub marker: Option<String>,
+    /// The speaker as labelled in the report: "Alexia Bertrand (Open Vld)", "Minister Jan Jambon",
+    /// "De voorzitter".
+    pub label: String,
+    /// "chair", "minister" (ministers and secretaries of state) or "member".
+    pub role: &'static str,
+    pub paragraphs: Vec<String>,
+}
+
+/// Splits the paragraphs of a debate into speech turns. Paragraphs before the first speaker are
+/// dropped.
+pub fn split_turns<'a>(paragraphs: impl IntoIterator<Item = &'a str>) -> Vec<Turn> {
+    let mut turns: Vec<Turn> = Vec::new();
+    for paragraph in paragraphs {
+        let paragraph = paragraph.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        let (turn, text) = if let Some(captures) = NUMBERED_TURN.captures(&paragraph) {
+            let label = captures[2].to_string();
+            let role = if CHAIR.is_match(&label) {
+                "chair"
+            } else if is_minister(&label) {
+                "minister"
+            } else {
+                "member"
+            };
+            (
+                Some((Some(captures[1].to_string()), label, role)),
+                captures[3].to_string(),
+            )
+        } else if let Some(captures) = CHAIR_TURN.captures(&paragraph) {
+            (
+                Some((None, captures[1].to_string(), "chair")),
+                captures[2].to_string(),
+            )
+        } else {
+            (None, paragraph)
+        };
+
+        if let Some((marker, label, role)) = turn {
+            turns.push(Turn {
+                marker,
+                label,
+                role,
+                paragraphs: Vec::new(),
+            });
+        }
+        if text.is_empty() || CLOSING_PARAGRAPHS.contains(&text.as_str()) {
+            continue;
+        }
+        if let Some(turn) = turns.last_mut() {
+            turn.paragraphs.push(text);
+        }
+    }
+    turns
+}
+
+fn is_minister(label: &str) -> bool {
+    let label = label.to_lowercase();
+    [
+        "minister",
+        "ministre",
+        "staatssecretaris",
+        "secrétaire d'état",
+    ]
+    .iter()
+    .any(|function| label.contains(function))
+}
+
+/// The name of the speaker of a turn, without title, party or function: "Minister Jan Jambon",
+/// "Jan Jambon, ministre" and "Jan Jambon (N-VA)" are all "Jan Jambon".
+pub fn speaker_name(label: &str) -> String {
+    let name = label.split(['(', ',']).next().unwrap_or_default().trim();
+    TITLE.replace(name, "").trim().to_string()
+}
+
+/// Sets the member ID of every speaker, given as (session ID, label, member ID), to the member of
+/// that session with the speaker's name. Returns the labels that match no member, sorted.
+pub fn resolve_speakers<'a>(
+    speakers: impl IntoIterator<Item = (&'a str, &'a str, &'a mut Option<String>)>,
+    members: &MemberIndex,
+) -> Vec<String> {
+    let mut unmatched = BTreeSet::new();
+    for (session_id, label, member_id) in speakers {
+        *member_id = members
+            .find(session_id, &speaker_name(label))
+            .map(str::to_string);
+        if member_id.is_none() {
+            unmatched.insert(label.to_string());
+        }
+    }
+    unmatched.into_iter().collect()
+}
@@ -0,0 +1,108 @@
+/// Common French words, including the words of agenda item headings ("Projet de loi portant...").
+const FRENCH: &[&str] = &[
+    "le",
+    "la",
+    "les",
+    "l",
+    "du",
+    "des",
+    "et",
+    "à",
+    "au",
+    "aux",
+    "sur",
+    "pour",
+    "dans",
+    "une",
+    "est",
+    "que",
+    "qui",
+    "pas",
+    "nous",
+    "vous",
+    "je",
+    "ce",
+    "cette",
+    "sont",
+    "portant",
+    "relatif",
+    "relative",
+    "ordre",
+    "jour",
+    "projet",
+    "proposition",
+    "demande",
+    "demandes",
+    "prises",
+    "considération",
+    "adoption",
+];
+
+/// Common Dutch words, including the words of agenda item headings ("Wetsontwerp houdende...").
+const DUTCH: &[&str] = &[
+    "het",
+    "van",
+    "en",
+    "een",
+    "op",
+    "met",
+    "voor",
+    "over",
+    "tot",
+    "is",
+    "dat",
+    "die",
+    "niet",
+    "ik",
+    "wij",
+    "we",
+    "u",
+    "zijn",
+    "ook",
+    "er",
+    "maar",
+    "wordt",
+    "worden",
+    "houdende",
+    "betreffende",
+    "inzake",
+    "wetsontwerp",
+    "wetsvoorstel",
+    "voorstel",
+    "agenda",
+    "goedkeuring",
+    "urgentieverzoek",
+    "urgentieverzoeken",
+    "inoverwegingnemingen",
+];
+
+/// How much more French than Dutch `text` reads: the number of common French words less the number
+/// of common Dutch ones. Reports mark languages with `lang` attributes, but not reliably.
+pub fn french_score(text: &str) -> i32 {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|word| {
+            if FRENCH.contains(&word) {
+                1
+            } else if DUTCH.contains(&word) {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// The language of some paragraphs, "nl" or "fr", by their `french_score`. `None` when there are
+/// as many common words of both.
+pub fn detect_language(paragraphs: &[String]) -> Option<&'static str> {
+    let score: i32 = paragraphs
+        .iter()
+        .map(|paragraph| french_score(paragraph))
+        .sum();
+    match score {
+        0 => None,
+        score if score < 0 => Some("nl"),
+        _ => Some("fr"),
+    }
+}
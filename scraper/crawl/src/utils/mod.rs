@@ -0,0 +1,24 @@
+pub mod dates;
+pub mod debate;
+pub mod language;
+pub mod names;
+
+use crate::characters::CHARACTERS;
+
+pub use dates::dutch_month_to_number;
+
+pub fn dutch_language_to_language_code(language: &str) -> Option<&str> {
+    match language.to_ascii_lowercase().as_str() {
+        "nederlands" => Some("NL"),
+        "frans" => Some("FR"),
+        _ => None,
+    }
+}
+
+pub fn clean_text(raw: &str) -> String {
+    raw.replace(CHARACTERS::NEWLINE, " ")
+        .replace(CHARACTERS::SOFT_HYPHEN, "")
+        .replace(CHARACTERS::NON_BREAKING_SPACE, " ")
+        .trim()
+        .to_string()
+}
@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Turns `text` into a URL slug: "Benoît Lutgen" becomes "benoit-lutgen", "Christoph D'Haese"
+/// "christoph-d-haese".
+///
+/// Follows the website's `slugify` filter, so slugs made here match the member URLs and image names
+/// it builds: German umlauts become "ae", "oe" and "ue", other accents are dropped by decomposing
+/// the characters, a lowercase letter followed by a capital starts a new word, and every run of
+/// other characters becomes a single hyphen.
+pub fn slugify(text: &str) -> String {
+    let mut transliterated = String::with_capacity(text.len());
+    for c in text.nfc() {
+        match c {
+            'ä' => transliterated.push_str("ae"),
+            'Ä' => transliterated.push_str("Ae"),
+            'ö' => transliterated.push_str("oe"),
+            'Ö' => transliterated.push_str("Oe"),
+            'ü' => transliterated.push_str("ue"),
+            'Ü' => transliterated.push_str("Ue"),
+            'ß' => transliterated.push_str("ss"),
+            'æ' => transliterated.push_str("ae"),
+            'Æ' => transliterated.push_str("Ae"),
+            'œ' => transliterated.push_str("oe"),
+            'Œ' => transliterated.push_str("Oe"),
+            'ø' => transliterated.push('o'),
+            'Ø' => transliterated.push('O'),
+            'ł' => transliterated.push('l'),
+            'Ł' => transliterated.push('L'),
+            'đ' | 'ð' => transliterated.push('d'),
+            'Đ' | 'Ð' => transliterated.push('D'),
+            'ı' => transliterated.push('i'),
+            _ => transliterated.extend(c.nfkd().filter(|c| !is_combining_mark(*c))),
+        }
+    }
+
+    let mut slug = String::with_capacity(transliterated.len());
+    let mut previous: Option<char> = None;
+    for c in transliterated.chars() {
+        if c.is_ascii_alphanumeric() {
+            let starts_word = c.is_ascii_uppercase()
+                && previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit());
+            if starts_word || (previous.is_none() && !slug.is_empty()) {
+                slug.push('-');
+            }
+            slug.push(c.to_ascii_lowercase());
+            previous = Some(c);
+        } else {
+            previous = None;
+        }
+    }
+
+    slug
+}
+
+/// Cleans up a person's name as written on the website: collapses whitespace (including
+/// non-breaking spaces), drops the comma the dossiers put between first and last name ("Staf,
+/// Aerts") and composes accents.
+pub fn normalize_person_name(name: &str) -> String {
+    name.nfc()
+        .collect::<String>()
+        .replace(',', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The slug of a person, used for member URLs and image names.
+pub fn person_slug(name: &str) -> String {
+    slugify(&normalize_person_name(name))
+}
+
+/// Finds members by the name a report uses for them, within their session.
+///
+/// Names are compared to both orders of the member's names ("Van Hecke Stefaan", "Stefaan Van
+/// Hecke") after slugifying, so differences in accents, case and hyphens do not matter. Some
+/// members are named by their last name only ("Mutyebele Ngoi"), which is found when no other
+/// member of the session has that last name.
+pub struct MemberIndex {
+    member_ids: HashMap<(String, String), String>,
+}
+
+impl MemberIndex {
+    /// Indexes members given as (session_id, member_id, first name, last name).
+    pub fn new<'a>(
+        members: impl IntoIterator<Item = (&'a str, &'a str, &'a str, &'a str)>,
+    ) -> MemberIndex {
+        let members: Vec<_> = members.into_iter().collect();
+        let mut member_ids = HashMap::new();
+        for &(session_id, member_id, first_name, last_name) in &members {
+            for name in [
+                format!("{last_name} {first_name}"),
+                format!("{first_name} {last_name}"),
+            ] {
+                member_ids
+                    .entry((session_id.to_string(), slugify(&name)))
+                    .or_insert_with(|| member_id.to_string());
+            }
+        }
+        let mut last_names: HashMap<(String, String), HashSet<&str>> = HashMap::new();
+        for &(session_id, member_id, _, last_name) in &members {
+            last_names
+                .entry((session_id.to_string(), slugify(last_name)))
+                .or_default()
+                .insert(member_id);
+        }
+        for (key, ids) in last_names {
+            if ids.len() == 1 {
+                member_ids
+                    .entry(key)
+                    .or_insert_with(|| ids.into_iter().next().unwrap().to_string());
+            }
+        }
+        MemberIndex { member_ids }
+    }
+
+    /// The `member_id` of the member of `session_id` called `name`.
+    pub fn find(&self, session_id: &str, name: &str) -> Option<&str> {
+        self.member_ids
+            .get(&(session_id.to_string(), slugify(name)))
+            .map(String::as_str)
+    }
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::FixtureServer;
+use crawl::cache::{CachePolicy, HttpCache};
+use crawl::client::{ClientConfig, ScrapingClient};
+use futures::StreamExt;
+use reqwest::StatusCode;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn fetches_a_batch_in_order_within_the_host_rate_limit() {
+    let server = FixtureServer::start(&[
+        ("/ip001x.html", "<p>1</p>"),
+        ("/ip002x.html", "<p>2</p>"),
+        ("/ip004x.html", "<p>4</p>"),
+        ("/ip005x.html", "<p>5</p>"),
+    ]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::from_millis(50),
+        max_concurrent_requests: 3,
+        cassette: None,
+        ..ClientConfig::default()
+    })
+    .with_cache(HttpCache::new(cache_dir.path()));
+    let urls: Vec<String> = (1..=5)
+        .map(|id| format!("{}/ip00{}x.html", server.base_url, id))
+        .collect();
+
+    let started = Instant::now();
+    let pages: Vec<_> = client
+        .fetch_all(urls.iter().map(|url| (url.clone(), CachePolicy::Forever)))
+        .collect()
+        .await;
+
+    // Five requests to one host are spaced by four intervals, however many are in flight. The
+    // robots.txt is requested once, before the first page.
+    assert!(started.elapsed() >= Duration::from_millis(200));
+    assert_eq!(server.requests(), 6);
+    assert_eq!(
+        pages.iter().map(|(url, _)| url).collect::<Vec<_>>(),
+        urls.iter().collect::<Vec<_>>()
+    );
+    let bodies: Vec<_> = pages
+        .iter()
+        .map(|(_, page)| {
+            let page = page.as_ref().unwrap();
+            (page.status, page.text())
+        })
+        .collect();
+    assert_eq!(
+        bodies,
+        [
+            (StatusCode::OK, "<p>1</p>".to_string()),
+            (StatusCode::OK, "<p>2</p>".to_string()),
+            (StatusCode::NOT_FOUND, "not found".to_string()),
+            (StatusCode::OK, "<p>4</p>".to_string()),
+            (StatusCode::OK, "<p>5</p>".to_string()),
+        ]
+    );
+
+    // Cached pages are served without waiting for the rate limit; only the uncached 404 is
+    // requested again.
+    let pages: Vec<_> = client
+        .fetch_all(urls.iter().map(|url| (url.clone(), CachePolicy::Forever)))
+        .collect()
+        .await;
+    assert_eq!(pages.len(), 5);
+    assert!(pages.iter().all(|(_, page)| page.is_ok()));
+    assert_eq!(server.requests(), 7);
+    assert_eq!(client.stats().cache_hits, 4);
+}
@@ -0,0 +1,213 @@
+mod common;
+
+use common::{FixtureServer, Reply};
+use crawl::cache::{CachePolicy, CacheStatus, HttpCache};
+use crawl::client::{ClientConfig, ScrapingClient};
+use std::time::{Duration, SystemTime};
+
+fn client(cache: HttpCache) -> ScrapingClient {
+    ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        respect_robots_txt: false,
+        cassette: None,
+        ..ClientConfig::default()
+    })
+    .with_cache(cache)
+}
+
+fn headers(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn stores_and_looks_up_pages() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = HttpCache::new(cache_dir.path());
+
+    assert!(cache.lookup("https://example.org/a").unwrap().is_none());
+    cache
+        .store(
+            "https://example.org/a",
+            200,
+            headers(&[("etag", "\"a1\"")]),
+            b"<p>a</p>",
+        )
+        .unwrap();
+
+    let entry = cache.lookup("https://example.org/a").unwrap().unwrap();
+    assert_eq!(entry.status, 200);
+    assert_eq!(entry.header("ETag"), Some("\"a1\""));
+    assert_eq!(cache.body(&entry).unwrap(), b"<p>a</p>");
+    assert!(cache.lookup("https://example.org/b").unwrap().is_none());
+}
+
+#[test]
+fn stores_identical_bodies_once() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = HttpCache::new(cache_dir.path());
+
+    let a = cache
+        .store("https://example.org/a", 200, vec![], b"<p>same</p>")
+        .unwrap();
+    let b = cache
+        .store("https://example.org/b", 200, vec![], b"<p>same</p>")
+        .unwrap();
+
+    assert_eq!(a.body_hash, b.body_hash);
+    let objects = walk_files(&cache_dir.path().join("objects"));
+    assert_eq!(objects, 1);
+}
+
+#[test]
+fn revalidating_updates_the_headers_and_validation_time() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = HttpCache::new(cache_dir.path());
+    let mut entry = cache
+        .store(
+            "https://example.org/a",
+            200,
+            headers(&[("etag", "\"a1\""), ("content-type", "text/html")]),
+            b"<p>a</p>",
+        )
+        .unwrap();
+    let fetched_at = entry.fetched_at;
+
+    cache
+        .revalidated(&mut entry, headers(&[("etag", "\"a2\"")]))
+        .unwrap();
+
+    let entry = cache.lookup("https://example.org/a").unwrap().unwrap();
+    assert_eq!(entry.header("etag"), Some("\"a2\""));
+    assert_eq!(entry.header("content-type"), Some("text/html"));
+    assert_eq!(entry.fetched_at, fetched_at);
+    assert!(entry.validated_at >= fetched_at);
+}
+
+#[test]
+fn policies_decide_when_to_revalidate() {
+    let now = SystemTime::now();
+    let an_hour_ago = now - Duration::from_secs(3600);
+
+    assert!(CachePolicy::Forever.is_fresh(an_hour_ago));
+    assert!(CachePolicy::MaxAge(Duration::from_secs(7200)).is_fresh(an_hour_ago));
+    assert!(!CachePolicy::MaxAge(Duration::from_secs(60)).is_fresh(an_hour_ago));
+    assert!(CachePolicy::RefreshIfOlderThan(an_hour_ago).is_fresh(now));
+    assert!(!CachePolicy::RefreshIfOlderThan(now).is_fresh(an_hour_ago));
+    assert!(!CachePolicy::Revalidate.is_fresh(now));
+}
+
+#[tokio::test]
+async fn serves_fresh_pages_without_a_request() {
+    let server = FixtureServer::start(&[("/page", "<p>page</p>")]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = client(HttpCache::new(cache_dir.path()));
+    let url = format!("{}/page", server.base_url);
+
+    let first = client.fetch(&url, CachePolicy::Forever).await.unwrap();
+    let second = client.fetch(&url, CachePolicy::Forever).await.unwrap();
+
+    assert_eq!(first.cache_status, CacheStatus::Miss);
+    assert_eq!(second.cache_status, CacheStatus::Fresh);
+    assert_eq!(second.body, b"<p>page</p>");
+    assert_eq!(server.requests(), 1);
+    assert_eq!(client.stats().cache_hits, 1);
+}
+
+#[tokio::test]
+async fn revalidates_stale_pages_with_their_etag() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::ok("<p>page</p>").header("etag", "\"v1\""),
+            Reply::status("304 Not Modified").header("etag", "\"v1\""),
+        ],
+    )]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = client(HttpCache::new(cache_dir.path()));
+    let url = format!("{}/page", server.base_url);
+
+    client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+    let page = client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+
+    assert_eq!(page.cache_status, CacheStatus::NotModified);
+    assert!(!page.is_changed());
+    assert_eq!(page.body, b"<p>page</p>");
+    let received = server.received();
+    assert_eq!(received[0].header("if-none-match"), None);
+    assert_eq!(received[1].header("if-none-match"), Some("\"v1\""));
+}
+
+#[tokio::test]
+async fn revalidates_stale_pages_with_their_last_modified_date() {
+    let last_modified = "Tue, 14 Oct 2025 08:00:00 GMT";
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::ok("<p>page</p>").header("last-modified", last_modified),
+            Reply::status("304 Not Modified"),
+        ],
+    )]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = client(HttpCache::new(cache_dir.path()));
+    let url = format!("{}/page", server.base_url);
+
+    client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+    let page = client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+
+    assert_eq!(page.cache_status, CacheStatus::NotModified);
+    assert_eq!(
+        server.received()[1].header("if-modified-since"),
+        Some(last_modified)
+    );
+}
+
+#[tokio::test]
+async fn replaces_pages_that_changed() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::ok("<p>old</p>").header("etag", "\"v1\""),
+            Reply::ok("<p>new</p>").header("etag", "\"v2\""),
+            Reply::status("304 Not Modified"),
+        ],
+    )]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = client(HttpCache::new(cache_dir.path()));
+    let url = format!("{}/page", server.base_url);
+
+    client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+    let changed = client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+    let confirmed = client.fetch(&url, CachePolicy::Revalidate).await.unwrap();
+
+    assert_eq!(changed.cache_status, CacheStatus::Modified);
+    assert!(changed.is_changed());
+    assert_eq!(changed.body, b"<p>new</p>");
+    assert_eq!(server.received()[2].header("if-none-match"), Some("\"v2\""));
+    assert_eq!(confirmed.body, b"<p>new</p>");
+}
+
+#[tokio::test]
+async fn does_not_cache_errors() {
+    let server = FixtureServer::start(&[]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let cache = HttpCache::new(cache_dir.path());
+    let client = client(cache.clone());
+    let url = format!("{}/missing", server.base_url);
+
+    let page = client.fetch(&url, CachePolicy::Forever).await.unwrap();
+
+    assert_eq!(page.status.as_u16(), 404);
+    assert_eq!(page.cache_status, CacheStatus::Uncached);
+    assert!(cache.lookup(&url).unwrap().is_none());
+}
+
+fn walk_files(dir: &std::path::Path) -> usize {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .map(|path| if path.is_dir() { walk_files(&path) } else { 1 })
+        .sum()
+}
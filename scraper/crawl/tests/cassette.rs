@@ -0,0 +1,57 @@
+mod common;
+
+use common::FixtureServer;
+use crawl::cache::{CachePolicy, HttpCache};
+use crawl::client::{Cassette, ClientConfig, Error, ScrapingClient};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+fn client(cassette: Cassette) -> ScrapingClient {
+    ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        cassette: Some(cassette),
+        ..ClientConfig::default()
+    })
+}
+
+#[tokio::test]
+async fn replays_recorded_responses_without_the_network() {
+    let server = FixtureServer::start(&[("/ip001x.html", "<p>Plenaire vergadering</p>")]);
+    let found = format!("{}/ip001x.html", server.base_url);
+    let missing = format!("{}/ip002x.html", server.base_url);
+    let cassette_dir = tempfile::tempdir().unwrap();
+    let cache_dir = tempfile::tempdir().unwrap();
+
+    let recorder =
+        client(Cassette::record(cassette_dir.path())).with_cache(HttpCache::new(cache_dir.path()));
+    let page = recorder.fetch(&found, CachePolicy::Forever).await.unwrap();
+    assert_eq!(page.status, StatusCode::OK);
+    assert_eq!(page.text(), "<p>Plenaire vergadering</p>");
+    let response = recorder.get(&missing).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    // Both pages and the robots.txt, which is checked while recording but not stored in the
+    // cassette.
+    assert_eq!(server.requests(), 3);
+
+    // The cache is bypassed while recording, so the cassette holds every page.
+    assert!(
+        HttpCache::new(cache_dir.path())
+            .lookup(&found)
+            .unwrap()
+            .is_none()
+    );
+
+    let player = client(Cassette::replay(cassette_dir.path()));
+    let page = player.fetch(&found, CachePolicy::Forever).await.unwrap();
+    assert_eq!(page.status, StatusCode::OK);
+    assert_eq!(page.text(), "<p>Plenaire vergadering</p>");
+    let response = player.get(&missing).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(server.requests(), 3);
+
+    let unknown = format!("{}/ip003x.html", server.base_url);
+    assert!(matches!(
+        player.get(&unknown).await,
+        Err(Error::NotRecorded { url }) if url == unknown
+    ));
+}
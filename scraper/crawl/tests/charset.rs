@@ -0,0 +1,134 @@
+mod common;
+
+use common::{FixtureServer, Reply};
+use crawl::cache::CachePolicy;
+use crawl::charset::{CharsetSource, charset_from_content_type, charset_from_meta, decode};
+use crawl::client::{ClientConfig, ScrapingClient};
+use encoding_rs::{UTF_8, WINDOWS_1252};
+use std::time::Duration;
+
+/// "Jean-Marc Delizée" in windows-1252, which is not valid UTF-8.
+const DELIZEE_1252: &[u8] = b"Jean-Marc Deliz\xe9e";
+
+fn page(head: &str, body: &[u8]) -> Vec<u8> {
+    let mut page = format!("<html><head>{}</head><body><p>", head).into_bytes();
+    page.extend_from_slice(body);
+    page.extend_from_slice(b"</p></body></html>");
+    page
+}
+
+#[test]
+fn prefers_the_content_type_charset() {
+    let body = page(r#"<meta charset="utf-8">"#, DELIZEE_1252);
+
+    let decoded = decode(&body, Some("text/html; charset=windows-1252"), Some("be"));
+
+    assert_eq!(decoded.encoding, WINDOWS_1252);
+    assert_eq!(decoded.source, CharsetSource::ContentType);
+    assert!(decoded.text.contains("Jean-Marc Delizée"));
+    assert!(!decoded.had_errors);
+}
+
+#[test]
+fn falls_back_to_the_meta_tag() {
+    let meta = page(r#"<meta charset="windows-1252">"#, DELIZEE_1252);
+    let http_equiv = page(
+        r#"<meta http-equiv="Content-Type" content="text/html; charset=iso-8859-1">"#,
+        DELIZEE_1252,
+    );
+
+    for body in [meta, http_equiv] {
+        let decoded = decode(&body, Some("text/html"), Some("be"));
+
+        assert_eq!(decoded.encoding, WINDOWS_1252);
+        assert_eq!(decoded.source, CharsetSource::MetaTag);
+        assert!(decoded.text.contains("Jean-Marc Delizée"));
+    }
+}
+
+#[test]
+fn falls_back_to_the_byte_order_mark() {
+    let mut body = b"\xef\xbb\xbf".to_vec();
+    body.extend(page("", "Jean-Marc Delizée".as_bytes()));
+
+    let decoded = decode(&body, None, Some("be"));
+
+    assert_eq!(decoded.encoding, UTF_8);
+    assert_eq!(decoded.source, CharsetSource::ByteOrderMark);
+    assert!(decoded.text.starts_with("<html>"));
+    assert!(decoded.text.contains("Jean-Marc Delizée"));
+}
+
+#[test]
+fn lets_a_byte_order_mark_override_a_declared_charset() {
+    let mut body = b"\xef\xbb\xbf".to_vec();
+    body.extend(page("", "Jean-Marc Delizée".as_bytes()));
+
+    let decoded = decode(&body, Some("text/html; charset=windows-1252"), Some("be"));
+
+    assert_eq!(decoded.encoding, UTF_8);
+    assert_eq!(decoded.source, CharsetSource::ByteOrderMark);
+    assert!(decoded.text.contains("Jean-Marc Delizée"));
+}
+
+#[test]
+fn sniffs_pages_that_declare_nothing() {
+    let utf8 = decode(&page("", "Jean-Marc Delizée".as_bytes()), None, Some("be"));
+    assert_eq!(utf8.encoding, UTF_8);
+    assert_eq!(utf8.source, CharsetSource::Sniffed);
+    assert!(utf8.text.contains("Jean-Marc Delizée"));
+
+    let legacy = decode(&page("", DELIZEE_1252), Some("text/html"), Some("be"));
+    assert_eq!(legacy.encoding, WINDOWS_1252);
+    assert_eq!(legacy.source, CharsetSource::Sniffed);
+    assert!(legacy.text.contains("Jean-Marc Delizée"));
+}
+
+#[test]
+fn reads_charset_declarations() {
+    assert_eq!(
+        charset_from_content_type("text/html; Charset=\"UTF-8\""),
+        Some(UTF_8)
+    );
+    assert_eq!(
+        charset_from_content_type("text/html;charset=latin1"),
+        Some(WINDOWS_1252)
+    );
+    assert_eq!(charset_from_content_type("text/html"), None);
+    assert_eq!(
+        charset_from_content_type("text/html; charset=klingon"),
+        None
+    );
+
+    // A document whose meta tag can be read as ASCII is not UTF-16.
+    assert_eq!(charset_from_meta(b"<meta charset=utf-16le>"), Some(UTF_8));
+    assert_eq!(charset_from_meta(b"<p>charset=utf-8</p>"), None);
+}
+
+#[tokio::test]
+async fn decodes_a_utf8_page_served_without_a_charset() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![Reply::ok("<p>Jean-Marc Delizée, Zoë Genot</p>").header("content-type", "text/html")],
+    )]);
+    let client = ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        respect_robots_txt: false,
+        cassette: None,
+        ..ClientConfig::default()
+    });
+
+    let page = client
+        .fetch(
+            &format!("{}/page", server.base_url),
+            CachePolicy::Revalidate,
+        )
+        .await
+        .unwrap();
+    let decoded = page.decode();
+
+    assert_eq!(decoded.encoding, UTF_8);
+    assert_eq!(decoded.source, CharsetSource::Sniffed);
+    assert_eq!(decoded.text, "<p>Jean-Marc Delizée, Zoë Genot</p>");
+    assert!(!decoded.had_errors);
+}
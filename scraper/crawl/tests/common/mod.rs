@@ -0,0 +1,158 @@
+// Each test file uses a different part of the fixture server.
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A response of the fixture server.
+#[derive(Clone)]
+pub struct Reply {
+    pub status: &'static str,
+    pub headers: Vec<(&'static str, String)>,
+    pub body: String,
+}
+
+impl Reply {
+    pub fn ok(body: &str) -> Self {
+        Reply {
+            status: "200 OK",
+            headers: vec![],
+            body: body.to_string(),
+        }
+    }
+
+    /// A response with `status` ("503 Service Unavailable") and no body.
+    pub fn status(status: &'static str) -> Self {
+        Reply {
+            status,
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+
+    pub fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+/// A request received by the fixture server: its path and headers, with lowercase names.
+#[derive(Clone, Debug)]
+pub struct Received {
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl Received {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Minimal HTTP server that answers GET requests from a fixed set of pages and 404s everything
+/// else.
+pub struct FixtureServer {
+    pub base_url: String,
+    requests: Arc<AtomicUsize>,
+    received: Arc<Mutex<Vec<Received>>>,
+}
+
+impl FixtureServer {
+    pub fn start(pages: &[(&str, &str)]) -> Self {
+        let routes: Vec<_> = pages
+            .iter()
+            .map(|(path, body)| (*path, vec![Reply::ok(body)]))
+            .collect();
+        Self::scripted(&routes)
+    }
+
+    /// Answers each request for a path with the next of its replies, repeating the last one.
+    pub fn scripted(routes: &[(&str, Vec<Reply>)]) -> Self {
+        let mut routes: HashMap<String, Vec<Reply>> = routes
+            .iter()
+            .map(|(path, replies)| (path.to_string(), replies.clone()))
+            .collect();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let received = Arc::new(Mutex::new(Vec::new()));
+
+        let counter = requests.clone();
+        let log = received.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let mut request_line = String::new();
+                let mut reader = BufReader::new(&stream);
+                reader.read_line(&mut request_line).unwrap();
+                let mut headers = vec![];
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    if let Some((name, value)) = line.trim_end().split_once(':') {
+                        headers.push((name.to_lowercase(), value.trim().to_string()));
+                    }
+                    line.clear();
+                }
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+                log.lock().unwrap().push(Received {
+                    path: path.to_string(),
+                    headers,
+                });
+                let reply = match routes.get_mut(path) {
+                    Some(replies) if replies.len() > 1 => replies.remove(0),
+                    Some(replies) => replies[0].clone(),
+                    None => Reply {
+                        status: "404 Not Found",
+                        headers: vec![],
+                        body: "not found".to_string(),
+                    },
+                };
+                let mut response = format!(
+                    "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n",
+                    reply.status,
+                    reply.body.len()
+                );
+                // Pages are UTF-8 unless a reply says otherwise.
+                if !reply
+                    .headers
+                    .iter()
+                    .any(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                {
+                    response.push_str("content-type: text/html; charset=utf-8\r\n");
+                }
+                for (name, value) in &reply.headers {
+                    response.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                response.push_str("\r\n");
+                response.push_str(&reply.body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        FixtureServer {
+            base_url,
+            requests,
+            received,
+        }
+    }
+
+    /// Number of requests the server has received so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// The requests the server has received so far, robots.txt included.
+    pub fn received(&self) -> Vec<Received> {
+        self.received.lock().unwrap().clone()
+    }
+}
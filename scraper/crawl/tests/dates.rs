@@ -0,0 +1,207 @@
+use chrono::{NaiveDate, NaiveTime};
+use crawl::utils::dates::{
+    DayPart, dutch_month_name, dutch_month_to_number, format_time, french_month_name,
+    french_month_to_number, month_to_number, parse_date, parse_time,
+};
+
+fn date(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+fn time(hour: u32, minute: u32) -> Option<NaiveTime> {
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+#[test]
+fn maps_every_dutch_and_french_month() {
+    let dutch = [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ];
+    let french = [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ];
+
+    for (number, (nl, fr)) in (1..=12).zip(dutch.iter().zip(french.iter())) {
+        assert_eq!(dutch_month_to_number(nl), Some(number), "{}", nl);
+        assert_eq!(french_month_to_number(fr), Some(number), "{}", fr);
+        assert_eq!(month_to_number(nl), Some(number), "{}", nl);
+        assert_eq!(month_to_number(fr), Some(number), "{}", fr);
+        assert_eq!(dutch_month_name(number), Some(*nl));
+        assert_eq!(french_month_name(number), Some(*fr));
+    }
+    assert_eq!(dutch_month_name(0), None);
+    assert_eq!(french_month_name(13), None);
+}
+
+#[test]
+fn month_names_ignore_case_and_accents() {
+    assert_eq!(month_to_number("Juni"), Some(6));
+    assert_eq!(month_to_number(" mei "), Some(5));
+    assert_eq!(month_to_number("FÉVRIER"), Some(2));
+    assert_eq!(month_to_number("fevrier"), Some(2));
+    assert_eq!(month_to_number("aout"), Some(8));
+    assert_eq!(month_to_number("decembre"), Some(12));
+    assert_eq!(month_to_number("juny"), None);
+    assert_eq!(month_to_number(""), None);
+}
+
+#[test]
+fn parses_dutch_date_phrases() {
+    assert_eq!(parse_date("donderdag 12 juni 2025"), date(2025, 6, 12));
+    assert_eq!(parse_date("Woensdag 1 januari 2025"), date(2025, 1, 1));
+    assert_eq!(parse_date("DINSDAG 31 DECEMBER 2024"), date(2024, 12, 31));
+    assert_eq!(parse_date("12 juni 2025"), date(2025, 6, 12));
+}
+
+#[test]
+fn parses_french_date_phrases() {
+    assert_eq!(parse_date("jeudi 12 juin 2025"), date(2025, 6, 12));
+    assert_eq!(parse_date("jeudi 1er mai 2025"), date(2025, 5, 1));
+    assert_eq!(parse_date("vendredi 14 février 2025"), date(2025, 2, 14));
+    assert_eq!(parse_date("vendredi 15 août 2025"), date(2025, 8, 15));
+    assert_eq!(parse_date("Mercredi 3 Décembre 2025"), date(2025, 12, 3));
+}
+
+#[test]
+fn finds_the_date_in_the_header_of_a_report() {
+    let header = "CRIV 56 PLEN 040 Integraal Verslag Compte rendu intégral \
+                  Donderdag 24 april 2025 Namiddag Jeudi 24 avril 2025 Après-midi";
+    assert_eq!(parse_date(header), date(2025, 4, 24));
+
+    // Numbers before other words are skipped.
+    assert_eq!(
+        parse_date("Zitting 56 leden 2025, vergadering van 3 juli 2025"),
+        date(2025, 7, 3)
+    );
+    // The whitespace between the parts may include line breaks and non-breaking spaces.
+    assert_eq!(
+        parse_date("donderdag\n12\u{a0}juni\t2025"),
+        date(2025, 6, 12)
+    );
+}
+
+#[test]
+fn rejects_impossible_or_missing_dates() {
+    assert_eq!(parse_date("31 juni 2025"), None);
+    assert_eq!(parse_date("29 februari 2025"), None);
+    assert_eq!(parse_date("29 février 2024"), date(2024, 2, 29));
+    assert_eq!(parse_date("0 juni 2025"), None);
+    assert_eq!(parse_date("12 juni"), None);
+    assert_eq!(parse_date("14/08/2024"), None);
+    assert_eq!(parse_date(""), None);
+    // An impossible date is skipped for a later valid one.
+    assert_eq!(parse_date("31 juni 2025 / 1 juli 2025"), date(2025, 7, 1));
+}
+
+#[test]
+fn parses_times() {
+    assert_eq!(parse_time("14.15 uur"), time(14, 15));
+    assert_eq!(parse_time("14.15uur"), time(14, 15));
+    assert_eq!(parse_time("14.15 0uur"), time(14, 15));
+    assert_eq!(parse_time("9.05 uur"), time(9, 5));
+    assert_eq!(parse_time("14.15 heures"), time(14, 15));
+    assert_eq!(parse_time("14.15 u."), time(14, 15));
+    assert_eq!(parse_time("14h15"), time(14, 15));
+    assert_eq!(parse_time("14H15"), time(14, 15));
+    assert_eq!(parse_time("14:15"), time(14, 15));
+    assert_eq!(parse_time("00.00 uur"), time(0, 0));
+}
+
+#[test]
+fn finds_the_time_in_a_sentence() {
+    assert_eq!(
+        parse_time(
+            "De vergadering wordt geopend om 14.17 uur en voorgezeten door de heer Peter De Roover."
+        ),
+        time(14, 17)
+    );
+    assert_eq!(
+        parse_time("La séance est ouverte à 14.17 heures et présidée par M. Peter De Roover."),
+        time(14, 17)
+    );
+    assert_eq!(
+        parse_time("De vergadering wordt gesloten om 19.41 uur. Volgende vergadering donderdag."),
+        time(19, 41)
+    );
+}
+
+#[test]
+fn rejects_numbers_that_are_not_times() {
+    assert_eq!(parse_time("artikel 1.23 van de wet"), None);
+    assert_eq!(parse_time("stuk 56 0095/004"), None);
+    assert_eq!(parse_time("25.00 uur"), None);
+    assert_eq!(parse_time("14.60 uur"), None);
+    assert_eq!(parse_time("14h5"), None);
+    assert_eq!(parse_time(""), None);
+    // An impossible time is skipped for a later valid one.
+    assert_eq!(parse_time("25.00 uur of 14.15 uur"), time(14, 15));
+}
+
+#[test]
+fn formats_times_like_the_data_files() {
+    assert_eq!(
+        format_time(NaiveTime::from_hms_opt(14, 15, 0).unwrap()),
+        "14h15"
+    );
+    assert_eq!(
+        format_time(NaiveTime::from_hms_opt(0, 31, 0).unwrap()),
+        "00h31"
+    );
+    assert_eq!(
+        format_time(NaiveTime::from_hms_opt(9, 5, 0).unwrap()),
+        "09h05"
+    );
+    assert_eq!(
+        parse_time(&format_time(NaiveTime::from_hms_opt(9, 5, 0).unwrap())),
+        time(9, 5)
+    );
+}
+
+#[test]
+fn parses_day_parts_in_dutch_and_french() {
+    assert_eq!(DayPart::from_label("voormiddag"), Some(DayPart::Morning));
+    assert_eq!(DayPart::from_label("matin"), Some(DayPart::Morning));
+    assert_eq!(DayPart::from_label("Namiddag"), Some(DayPart::Afternoon));
+    assert_eq!(DayPart::from_label("après-midi"), Some(DayPart::Afternoon));
+    assert_eq!(DayPart::from_label("Après-midi"), Some(DayPart::Afternoon));
+    assert_eq!(DayPart::from_label("apres-midi"), Some(DayPart::Afternoon));
+    assert_eq!(DayPart::from_label(" avond "), Some(DayPart::Evening));
+    assert_eq!(DayPart::from_label("soir"), Some(DayPart::Evening));
+    assert_eq!(DayPart::from_label("soirée"), Some(DayPart::Evening));
+    assert_eq!(DayPart::from_label("namiddagvergadering"), None);
+    assert_eq!(DayPart::from_label("morning"), None);
+}
+
+#[test]
+fn day_parts_round_trip_through_the_stored_value() {
+    for part in [DayPart::Morning, DayPart::Afternoon, DayPart::Evening] {
+        assert_eq!(part.as_str().parse::<DayPart>(), Ok(part));
+        assert_eq!(part.to_string(), part.as_str());
+        assert_eq!(DayPart::from_label(part.dutch_label()), Some(part));
+        assert_eq!(DayPart::from_label(part.french_label()), Some(part));
+    }
+    assert_eq!("namiddag".parse::<DayPart>(), Ok(DayPart::Afternoon));
+    assert!("middernacht".parse::<DayPart>().is_err());
+}
@@ -0,0 +1,107 @@
+use crawl::utils::debate::{Turn, resolve_speakers, speaker_name, split_turns};
+use crawl::utils::names::MemberIndex;
+
+#[test]
+fn splits_a_debate_into_turns() {
+    let paragraphs = [
+        "Vraag van Alexia Bertrand aan de eerste minister over 'De begroting' (56001154P)",
+        "03.01 Alexia Bertrand (Open Vld): Mijnheer de eerste minister, ik heb een vraag.",
+        "Wat is uw antwoord?",
+        "03.02 Bart De Wever , premier ministre: Madame Bertrand, je vous remercie.",
+        "De voorzitter : Dank u wel.",
+        "03.03 Alexia Bertrand (Open Vld): Ik dank u voor uw antwoord.",
+        "Het incident is gesloten.",
+    ];
+
+    assert_eq!(
+        split_turns(paragraphs),
+        [
+            Turn {
+                marker: Some("03.01".to_string()),
+                label: "Alexia Bertrand (Open Vld)".to_string(),
+                role: "member",
+                paragraphs: vec![
+                    "Mijnheer de eerste minister, ik heb een vraag.".to_string(),
+                    "Wat is uw antwoord?".to_string(),
+                ],
+            },
+            Turn {
+                marker: Some("03.02".to_string()),
+                label: "Bart De Wever , premier ministre".to_string(),
+                role: "minister",
+                paragraphs: vec!["Madame Bertrand, je vous remercie.".to_string()],
+            },
+            Turn {
+                marker: None,
+                label: "De voorzitter".to_string(),
+                role: "chair",
+                paragraphs: vec!["Dank u wel.".to_string()],
+            },
+            Turn {
+                marker: Some("03.03".to_string()),
+                label: "Alexia Bertrand (Open Vld)".to_string(),
+                role: "member",
+                paragraphs: vec!["Ik dank u voor uw antwoord.".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn does_not_take_times_in_the_text_for_turns() {
+    let turns =
+        split_turns(["01.01 Kurt Ravyts (VB): De vergadering begint om 20.00 uur: dat is laat."]);
+    assert_eq!(turns.len(), 1);
+    assert_eq!(
+        turns[0].paragraphs,
+        ["De vergadering begint om 20.00 uur: dat is laat."]
+    );
+}
+
+#[test]
+fn strips_titles_parties_and_functions_from_speakers() {
+    assert_eq!(
+        speaker_name("Alexia Bertrand (Open Vld)"),
+        "Alexia Bertrand"
+    );
+    assert_eq!(
+        speaker_name("Minister Annelies Verlinden "),
+        "Annelies Verlinden"
+    );
+    assert_eq!(
+        speaker_name("Eerste minister Bart De Wever"),
+        "Bart De Wever"
+    );
+    assert_eq!(
+        speaker_name("David Clarinval , ministre"),
+        "David Clarinval"
+    );
+}
+
+#[test]
+fn resolves_speakers_to_the_members_of_their_session() {
+    let members = MemberIndex::new([
+        ("56", "1", "Stefaan", "Van Hecke"),
+        ("55", "2", "Kristof", "Calvo"),
+    ]);
+    let mut speakers = [
+        ("56", "Stefaan Van Hecke (Groen)", None),
+        ("56", "Kristof Calvo (Groen)", Some("9".to_string())),
+        ("55", "Kristof Calvo (Groen)", None),
+        ("56", "Kristof Calvo (Groen)", None),
+    ];
+
+    let unmatched = resolve_speakers(
+        speakers
+            .iter_mut()
+            .map(|(session_id, label, member_id)| (*session_id, *label, member_id)),
+        &members,
+    );
+
+    let member_ids: Vec<_> = speakers
+        .iter()
+        .map(|(_, _, member_id)| member_id.as_deref())
+        .collect();
+    assert_eq!(member_ids, [Some("1"), None, Some("2"), None]);
+    assert_eq!(unmatched, ["Kristof Calvo (Groen)"]);
+}
@@ -0,0 +1,34 @@
+use crawl::utils::language::{detect_language, french_score};
+
+#[test]
+fn detects_the_language_of_a_turn() {
+    let dutch = ["Mijnheer de minister, ik heb een vraag over het beleid.".to_string()];
+    let french = ["Monsieur le ministre, je vous remercie pour la réponse.".to_string()];
+    assert_eq!(detect_language(&dutch), Some("nl"));
+    assert_eq!(detect_language(&french), Some("fr"));
+    assert_eq!(detect_language(&["(…)".to_string()]), None);
+}
+
+#[test]
+fn weighs_every_paragraph() {
+    let paragraphs = [
+        "Dank u wel.".to_string(),
+        "Je vous remercie pour la réponse et pour les chiffres.".to_string(),
+    ];
+    assert_eq!(detect_language(&paragraphs), Some("fr"));
+    assert_eq!(detect_language(&[]), None);
+}
+
+#[test]
+fn scores_agenda_item_headings() {
+    let dutch = "Wetsontwerp houdende diverse bepalingen inzake gezondheid";
+    let french = "Projet de loi portant des dispositions diverses en matière de santé";
+    assert!(french_score(dutch) < 0);
+    assert!(french_score(french) > 0);
+    assert!(french_score(french) > french_score(dutch));
+    assert_eq!(
+        french_score("Inoverwegingnemingen / Prises en considération"),
+        0
+    );
+    assert_eq!(french_score("56K0717"), 0);
+}
@@ -0,0 +1,270 @@
+use crawl::utils::names::{MemberIndex, normalize_person_name, person_slug, slugify};
+use std::path::Path;
+
+/// Every current member of the Chamber as (first name, last name, slug), from `members.parquet`.
+const CURRENT_MEMBERS: &[(&str, &str, &str)] = &[
+    ("Achraf", "El Yakhloufi", "achraf-el-yakhloufi"),
+    ("Alain", "Yzermans", "alain-yzermans"),
+    ("Alexander", "Van Hoecke", "alexander-van-hoecke"),
+    ("Alexia", "Bertrand", "alexia-bertrand"),
+    ("Anja", "Vanrobaeys", "anja-vanrobaeys"),
+    ("Anne", "Pirson", "anne-pirson"),
+    ("Annick", "Lambrecht", "annick-lambrecht"),
+    ("Annick", "Ponthier", "annick-ponthier"),
+    ("Annik", "Van den Bosch", "annik-van-den-bosch"),
+    ("Anthony", "Dufrane", "anthony-dufrane"),
+    ("Aurore", "Tourneur", "aurore-tourneur"),
+    ("Axel", "Ronse", "axel-ronse"),
+    ("Axel", "Weydts", "axel-weydts"),
+    ("Ayse", "Yigit", "ayse-yigit"),
+    ("Barbara", "Pas", "barbara-pas"),
+    ("Benoît", "Lutgen", "benoit-lutgen"),
+    ("Benoît", "Piedboeuf", "benoit-piedboeuf"),
+    ("Bert", "Wollants", "bert-wollants"),
+    ("Brent", "Meuleman", "brent-meuleman"),
+    ("Britt", "Huybrechts", "britt-huybrechts"),
+    ("Carmen", "Ramlot", "carmen-ramlot"),
+    ("Caroline", "Désir", "caroline-desir"),
+    ("Catherine", "Delcourt", "catherine-delcourt"),
+    ("Charlotte", "Deborsu", "charlotte-deborsu"),
+    ("Charlotte", "Verkeyn", "charlotte-verkeyn"),
+    ("Christoph", "D'Haese", "christoph-d-haese"),
+    ("Christophe", "Bombled", "christophe-bombled"),
+    ("Christophe", "Lacroix", "christophe-lacroix"),
+    ("Daniel", "Bacquelaine", "daniel-bacquelaine"),
+    ("Darya", "Safai", "darya-safai"),
+    ("Denis", "Ducarme", "denis-ducarme"),
+    ("Dieter", "Keuten", "dieter-keuten"),
+    ("Dieter", "Vanbesien", "dieter-vanbesien"),
+    ("Dimitri", "Legasse", "dimitri-legasse"),
+    ("Dominiek", "Sneppe", "dominiek-sneppe"),
+    ("Dorien", "Cuylaerts", "dorien-cuylaerts"),
+    ("Ellen", "Samyn", "ellen-samyn"),
+    ("Els", "Van Hoof", "els-van-hoof"),
+    ("Éric", "Thiébaut", "eric-thiebaut"),
+    ("Eva", "Demesmaeker", "eva-demesmaeker"),
+    ("Farah", "Jacquet", "farah-jacquet"),
+    ("Fatima", "Lamarti", "fatima-lamarti"),
+    ("Florence", "Reuter", "florence-reuter"),
+    ("Francesca", "Van Belleghem", "francesca-van-belleghem"),
+    ("François", "De Smet", "francois-de-smet"),
+    ("Frank", "Troosters", "frank-troosters"),
+    ("Franky", "Demon", "franky-demon"),
+    ("Frédéric", "Daerden", "frederic-daerden"),
+    ("Frieda", "Gijbels", "frieda-gijbels"),
+    ("Funda", "Oru", "funda-oru"),
+    ("Georges-Louis", "Bouchez", "georges-louis-bouchez"),
+    ("Gilles", "Foret", "gilles-foret"),
+    ("Greet", "Daems", "greet-daems"),
+    ("Hervé", "Cornillie", "herve-cornillie"),
+    ("Hugues", "Bayet", "hugues-bayet"),
+    ("Irina", "De Knop", "irina-de-knop"),
+    ("Isabelle", "Hansez", "isabelle-hansez"),
+    ("Ismaël", "Nuino", "ismael-nuino"),
+    ("Jan", "Bertels", "jan-bertels"),
+    ("Jean-François", "Gatelier", "jean-francois-gatelier"),
+    ("Jean-Marie", "Dedecker", "jean-marie-dedecker"),
+    ("Jeroen", "Bergers", "jeroen-bergers"),
+    ("Jeroen", "Soete", "jeroen-soete"),
+    ("Jeroen", "Van Lysebettens", "jeroen-van-lysebettens"),
+    ("Julie", "Taton", "julie-taton"),
+    ("Julien", "Matagne", "julien-matagne"),
+    ("Julien", "Ribaudo", "julien-ribaudo"),
+    ("Kathleen", "Depoorter", "kathleen-depoorter"),
+    ("Katja", "Gabriëls", "katja-gabriels"),
+    ("Katleen", "Bury", "katleen-bury"),
+    ("Katrijn", "van Riet", "katrijn-van-riet"),
+    ("Kemal", "Bilmez", "kemal-bilmez"),
+    ("Khalil", "Aouasti", "khalil-aouasti"),
+    ("Kim", "De Witte", "kim-de-witte"),
+    ("Kjell", "Vander Elst", "kjell-vander-elst"),
+    ("Koen", "Metsu", "koen-metsu"),
+    ("Koen", "Van den Heuvel", "koen-van-den-heuvel"),
+    ("Kristien", "Van Vaerenbergh", "kristien-van-vaerenbergh"),
+    ("Kristien", "Verbelen", "kristien-verbelen"),
+    ("Kurt", "Moons", "kurt-moons"),
+    ("Kurt", "Ravyts", "kurt-ravyts"),
+    ("Leentje", "Grillaert", "leentje-grillaert"),
+    ("Lieve", "Truyman", "lieve-truyman"),
+    ("Lode", "Vereeck", "lode-vereeck"),
+    ("Lotte", "Peeters", "lotte-peeters"),
+    ("Luc", "Frank", "luc-frank"),
+    ("Ludivine", "Dedonder", "ludivine-dedonder"),
+    ("Lydia", "Mutyebele Ngoi", "lydia-mutyebele-ngoi"),
+    ("Maaike", "De Vreese", "maaike-de-vreese"),
+    ("Marc", "Lejeune", "marc-lejeune"),
+    ("Marie", "Meunier", "marie-meunier"),
+    ("Marijke", "Dillen", "marijke-dillen"),
+    ("Mathieu", "Michel", "mathieu-michel"),
+    ("Matti", "Vandemaele", "matti-vandemaele"),
+    ("Meyrem", "Almaci", "meyrem-almaci"),
+    ("Michael", "Freilich", "michael-freilich"),
+    ("Michel", "De Maegd", "michel-de-maegd"),
+    ("Nabil", "Boukili", "nabil-boukili"),
+    ("Nadia", "Moscufo", "nadia-moscufo"),
+    ("Nahima", "Lanjri", "nahima-lanjri"),
+    ("Natalie", "Eggermont", "natalie-eggermont"),
+    ("Nathalie", "Muylle", "nathalie-muylle"),
+    ("Nawal", "Farih", "nawal-farih"),
+    ("Nele", "Daenen", "nele-daenen"),
+    ("Niels", "Tas", "niels-tas"),
+    ("Ortwin", "Depoortere", "ortwin-depoortere"),
+    ("Oskar", "Seuntjens", "oskar-seuntjens"),
+    ("Patrick", "Prévot", "patrick-prevot"),
+    ("Paul", "Magnette", "paul-magnette"),
+    ("Paul", "Van Tigchelt", "paul-van-tigchelt"),
+    ("Peter", "Buysrogge", "peter-buysrogge"),
+    ("Peter", "De Roover", "peter-de-roover"),
+    ("Peter", "Mertens", "peter-mertens"),
+    ("Phaedra", "Van Keymolen", "phaedra-van-keymolen"),
+    ("Philippe", "Courard", "philippe-courard"),
+    ("Philippe", "Goffin", "philippe-goffin"),
+    ("Pierre", "Jadoul", "pierre-jadoul"),
+    ("Pierre", "Kompany", "pierre-kompany"),
+    ("Pierre-Yves", "Dermagne", "pierre-yves-dermagne"),
+    ("Rajae", "Maouane", "rajae-maouane"),
+    ("Raoul", "Hedebouw", "raoul-hedebouw"),
+    ("Reccino", "Van Lommel", "reccino-van-lommel"),
+    ("Ridouane", "Chahid", "ridouane-chahid"),
+    ("Roberto", "D'Amico", "roberto-d-amico"),
+    ("Robin", "Tonniau", "robin-tonniau"),
+    ("Sam", "Van Rooy", "sam-van-rooy"),
+    ("Sammy", "Mahdi", "sammy-mahdi"),
+    ("Sandro", "Di Nunzio", "sandro-di-nunzio"),
+    ("Sarah", "Schlitz", "sarah-schlitz"),
+    ("Serge", "Hiligsmann", "serge-hiligsmann"),
+    ("Sofie", "Merckx", "sofie-merckx"),
+    ("Sophie", "De Wit", "sophie-de-wit"),
+    ("Sophie", "Thémont", "sophie-themont"),
+    ("Staf", "Aerts", "staf-aerts"),
+    ("Stefaan", "Van Hecke", "stefaan-van-hecke"),
+    ("Stéphane", "Lasseaux", "stephane-lasseaux"),
+    ("Steven", "Coenegrachts", "steven-coenegrachts"),
+    ("Steven", "Matheï", "steven-mathei"),
+    ("Steven", "Vandeput", "steven-vandeput"),
+    ("Tine", "Gielis", "tine-gielis"),
+    ("Tinne", "Van der Straeten", "tinne-van-der-straeten"),
+    ("Victoria", "Vandeberg", "victoria-vandeberg"),
+    ("Vincent", "Scourneau", "vincent-scourneau"),
+    ("Vincent", "Van Quickenborne", "vincent-van-quickenborne"),
+    ("Werner", "Somers", "werner-somers"),
+    ("Wim", "Van der Donckt", "wim-van-der-donckt"),
+    ("Wouter", "Raskin", "wouter-raskin"),
+    ("Wouter", "Vermeersch", "wouter-vermeersch"),
+    ("Xavier", "Dubois", "xavier-dubois"),
+    ("Youssef", "Handichi", "youssef-handichi"),
+];
+
+/// Current members whose picture has not been downloaded yet.
+const WITHOUT_IMAGE: &[&str] = &["axel-weydts"];
+
+#[test]
+fn slugs_every_current_member() {
+    for (first_name, last_name, slug) in CURRENT_MEMBERS {
+        assert_eq!(
+            person_slug(&format!("{} {}", first_name, last_name)),
+            *slug,
+            "{} {}",
+            first_name,
+            last_name
+        );
+        // The website joins the slugs of the first and last name.
+        assert_eq!(
+            format!("{}-{}", slugify(first_name), slugify(last_name)),
+            *slug
+        );
+    }
+}
+
+#[test]
+fn every_current_member_has_an_image_under_their_slug() {
+    let img_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../web/src/content/members/img");
+
+    let missing: Vec<&str> = CURRENT_MEMBERS
+        .iter()
+        .map(|(_, _, slug)| *slug)
+        .filter(|slug| !img_dir.join(format!("{}.jpg", slug)).exists())
+        .collect();
+
+    assert_eq!(missing, WITHOUT_IMAGE);
+}
+
+#[test]
+fn transliterates_accents_and_special_letters() {
+    assert_eq!(slugify("Désir"), "desir");
+    assert_eq!(slugify("Matheï"), "mathei");
+    assert_eq!(slugify("Benoît"), "benoit");
+    assert_eq!(slugify("Ayşe Yiğit"), "ayse-yigit");
+    assert_eq!(slugify("Ramón Núñez"), "ramon-nunez");
+    assert_eq!(slugify("Čapek Łukasz"), "capek-lukasz");
+    assert_eq!(slugify("Søren Kierkegaard"), "soren-kierkegaard");
+    assert_eq!(slugify("Œuvre Ærø"), "oeuvre-aero");
+    assert_eq!(slugify("Müller Straße"), "mueller-strasse");
+    assert_eq!(slugify("Öztürk"), "oeztuerk");
+}
+
+#[test]
+fn separates_words_on_punctuation() {
+    assert_eq!(slugify("Christoph D'Haese"), "christoph-d-haese");
+    assert_eq!(slugify("Roberto D\u{2019}Amico"), "roberto-d-amico");
+    assert_eq!(slugify("Jean-François Gatelier"), "jean-francois-gatelier");
+    assert_eq!(
+        slugify("  Tinne  Van der Straeten "),
+        "tinne-van-der-straeten"
+    );
+    assert_eq!(
+        slugify("Gouvernement/Regering (AUTEUR)"),
+        "gouvernement-regering-auteur"
+    );
+    assert_eq!(slugify("N-VA"), "n-va");
+    assert_eq!(slugify("Vooruit!"), "vooruit");
+    assert_eq!(slugify("-_-"), "");
+    assert_eq!(slugify(""), "");
+}
+
+#[test]
+fn splits_camel_case_like_the_website() {
+    assert_eq!(slugify("McDonald"), "mc-donald");
+    assert_eq!(slugify("PVDA-PTB"), "pvda-ptb");
+    assert_eq!(slugify("cd&v"), "cd-v");
+}
+
+#[test]
+fn decomposed_and_composed_accents_give_the_same_slug() {
+    assert_eq!(slugify("De\u{301}sir"), slugify("Désir"));
+    assert_eq!(slugify("Mu\u{308}ller"), "mueller");
+    assert_eq!(person_slug("Ismae\u{308}l Nuino"), "ismael-nuino");
+}
+
+#[test]
+fn normalizes_person_names() {
+    assert_eq!(normalize_person_name("Staf, Aerts"), "Staf Aerts");
+    assert_eq!(
+        normalize_person_name(" Tinne\u{a0}Van der\nStraeten "),
+        "Tinne Van der Straeten"
+    );
+    assert_eq!(normalize_person_name("Ismae\u{308}l Nuino"), "Ismaël Nuino");
+    assert_eq!(
+        normalize_person_name("Christoph D'Haese"),
+        "Christoph D'Haese"
+    );
+    assert_eq!(normalize_person_name(""), "");
+}
+
+#[test]
+fn finds_members_by_either_name_order_or_a_unique_last_name() {
+    let members = MemberIndex::new([
+        ("56", "1", "Stefaan", "Van Hecke"),
+        ("56", "2", "Ngoi", "Mutyebele"),
+        ("56", "3", "Frank", "Troosters"),
+        ("56", "4", "Kathleen", "Troosters"),
+        ("55", "5", "Kristof", "Calvo"),
+    ]);
+
+    assert_eq!(members.find("56", "Van Hecke Stefaan"), Some("1"));
+    assert_eq!(members.find("56", "Stefaan Van Hecke"), Some("1"));
+    assert_eq!(members.find("56", "van hecke stéfaan"), Some("1"));
+    assert_eq!(members.find("56", "Mutyebele"), Some("2"));
+    assert_eq!(members.find("56", "Troosters"), None);
+    assert_eq!(members.find("56", "Calvo Kristof"), None);
+}
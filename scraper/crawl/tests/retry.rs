@@ -0,0 +1,205 @@
+mod common;
+
+use common::{FixtureServer, Reply};
+use crawl::client::{ClientConfig, Error, ScrapingClient};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::time::{Duration, Instant, SystemTime};
+
+fn client(config: ClientConfig) -> ScrapingClient {
+    ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        respect_robots_txt: false,
+        cassette: None,
+        ..config
+    })
+}
+
+fn backoff_config(initial_backoff: Duration, max_backoff: Duration) -> ClientConfig {
+    ClientConfig {
+        initial_backoff,
+        max_backoff,
+        ..ClientConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn retries_transient_errors_with_a_growing_backoff() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::status("503 Service Unavailable"),
+            Reply::status("502 Bad Gateway"),
+            Reply::ok("<p>page</p>"),
+        ],
+    )]);
+    let client = client(backoff_config(
+        Duration::from_millis(100),
+        Duration::from_secs(5),
+    ));
+
+    let started = Instant::now();
+    let response = client
+        .get(&format!("{}/page", server.base_url))
+        .await
+        .unwrap();
+
+    // 100 ms before the first retry, at least 200 ms before the second.
+    assert!(started.elapsed() >= Duration::from_millis(300));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(server.requests(), 3);
+    let stats = client.stats();
+    assert_eq!((stats.requests, stats.retries, stats.failures), (1, 2, 0));
+}
+
+#[tokio::test]
+async fn gives_up_after_the_last_retry() {
+    let server =
+        FixtureServer::scripted(&[("/page", vec![Reply::status("500 Internal Server Error")])]);
+    let client = client(ClientConfig {
+        max_retries: 2,
+        ..backoff_config(Duration::from_millis(10), Duration::from_millis(50))
+    });
+
+    let result = client.get(&format!("{}/page", server.base_url)).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Status { status, .. }) if status == StatusCode::INTERNAL_SERVER_ERROR
+    ));
+    assert_eq!(server.requests(), 3);
+    assert_eq!(client.stats().failures, 1);
+}
+
+#[tokio::test]
+async fn does_not_retry_client_errors() {
+    let server = FixtureServer::start(&[]);
+    let client = client(ClientConfig::default());
+
+    let response = client
+        .get(&format!("{}/missing", server.base_url))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(server.requests(), 1);
+    assert_eq!(client.stats().retries, 0);
+}
+
+#[tokio::test]
+async fn waits_the_seconds_retry_after_asks_for() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::status("429 Too Many Requests").header("retry-after", "1"),
+            Reply::ok("<p>page</p>"),
+        ],
+    )]);
+    let client = client(backoff_config(
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    ));
+
+    let started = Instant::now();
+    let response = client
+        .get(&format!("{}/page", server.base_url))
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn waits_until_the_date_retry_after_asks_for() {
+    // HTTP dates have whole seconds, so the wait is between two and three seconds, less the time
+    // the first request took.
+    let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(3));
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::status("503 Service Unavailable").header("retry-after", date),
+            Reply::ok("<p>page</p>"),
+        ],
+    )]);
+    let client = client(backoff_config(
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    ));
+
+    let started = Instant::now();
+    let response = client
+        .get(&format!("{}/page", server.base_url))
+        .await
+        .unwrap();
+
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn gives_up_instead_of_retrying_sooner_than_retry_after() {
+    let server = FixtureServer::scripted(&[(
+        "/page",
+        vec![
+            Reply::status("503 Service Unavailable").header("retry-after", "3600"),
+            Reply::ok("<p>page</p>"),
+        ],
+    )]);
+    let client = client(backoff_config(
+        Duration::from_millis(10),
+        Duration::from_secs(60),
+    ));
+
+    let started = Instant::now();
+    let result = client.get(&format!("{}/page", server.base_url)).await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Status { status, .. }) if status == StatusCode::SERVICE_UNAVAILABLE
+    ));
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(server.requests(), 1);
+}
+
+#[tokio::test]
+async fn spaces_requests_by_the_interval_of_their_host() {
+    let server = FixtureServer::start(&[("/page", "<p>page</p>")]);
+    let client = client(ClientConfig {
+        host_intervals: HashMap::from([("127.0.0.1".to_string(), Duration::from_millis(150))]),
+        ..ClientConfig::default()
+    });
+
+    let started = Instant::now();
+    for _ in 0..3 {
+        client
+            .get(&format!("{}/page", server.base_url))
+            .await
+            .unwrap();
+    }
+
+    // The first request goes out at once, the next two each wait an interval.
+    assert!(started.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests(), 3);
+}
+
+#[tokio::test]
+async fn does_not_space_requests_to_different_hosts() {
+    let server = FixtureServer::start(&[("/page", "<p>page</p>")]);
+    let client = client(ClientConfig {
+        host_intervals: HashMap::from([("127.0.0.1".to_string(), Duration::from_secs(2))]),
+        ..ClientConfig::default()
+    });
+    // The same server under a second host name, with the default interval of zero.
+    let other_host = server.base_url.replace("127.0.0.1", "localhost");
+
+    let started = Instant::now();
+    client
+        .get(&format!("{}/page", server.base_url))
+        .await
+        .unwrap();
+    client.get(&format!("{}/page", other_host)).await.unwrap();
+
+    assert!(started.elapsed() < Duration::from_secs(2));
+    assert_eq!(server.requests(), 2);
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use common::FixtureServer;
+use crawl::cache::{CachePolicy, HttpCache};
+use crawl::client::{
+    ClientConfig, DEFAULT_FROM, DEFAULT_USER_AGENT, Error, Robots, ScrapingClient,
+};
+use reqwest::StatusCode;
+use std::time::{Duration, Instant};
+
+const ROBOTS_TXT: &str = "\
+# Comments and unknown lines are ignored.
+Sitemap: https://www.dekamer.be/sitemap.xml
+
+User-agent: *
+Disallow: /kvvcr/
+Allow: /kvvcr/showpage.cfm
+Disallow: /*.pdf$
+
+User-agent: OtherBot
+User-agent: zijwerkenvooru
+Disallow: /private
+Crawl-delay: 2.5
+";
+
+fn cached_client(cache_dir: &std::path::Path) -> ScrapingClient {
+    ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        cassette: None,
+        ..ClientConfig::default()
+    })
+    .with_cache(HttpCache::new(cache_dir))
+}
+
+#[test]
+fn uses_the_group_of_our_user_agent() {
+    let robots = Robots::parse(ROBOTS_TXT, DEFAULT_USER_AGENT);
+    assert!(!robots.is_allowed("/private/page.html"));
+    assert!(robots.is_allowed("/kvvcr/pdf_sections/index.cfm"));
+    assert!(robots.is_allowed("/doc/flwb/pdf/56/0095/56K0095001.pdf"));
+    assert_eq!(robots.crawl_delay(), Some(Duration::from_millis(2500)));
+}
+
+#[test]
+fn falls_back_to_the_wildcard_group() {
+    let robots = Robots::parse(ROBOTS_TXT, "SomeOtherScraper/2.0");
+    assert!(robots.is_allowed("/private/page.html"));
+    assert!(robots.is_allowed("/"));
+    assert!(!robots.is_allowed("/kvvcr/pdf_sections/index.cfm"));
+    assert!(!robots.is_allowed("/doc/flwb/pdf/56/0095/56K0095001.pdf"));
+    // `$` anchors the end of the path.
+    assert!(robots.is_allowed("/doc/flwb/pdf/56/0095/56K0095001.pdf?download=1"));
+    assert_eq!(robots.crawl_delay(), None);
+}
+
+#[test]
+fn the_longest_matching_rule_wins() {
+    let robots = Robots::parse(ROBOTS_TXT, "SomeOtherScraper/2.0");
+    assert!(robots.is_allowed("/kvvcr/showpage.cfm?section=/depute&language=nl"));
+    assert!(!robots.is_allowed("/kvvcr/showpage"));
+
+    let robots = Robots::parse(
+        "User-agent: *\nDisallow: /page\nAllow: /page\nDisallow: /a*c\nDisallow:\n",
+        DEFAULT_USER_AGENT,
+    );
+    // Allow wins a tie, and an empty Disallow allows everything.
+    assert!(robots.is_allowed("/page.html"));
+    assert!(!robots.is_allowed("/abc"));
+    assert!(!robots.is_allowed("/a/b/c/d"));
+    assert!(robots.is_allowed("/b"));
+}
+
+#[test]
+fn an_empty_robots_txt_allows_everything() {
+    for robots in [Robots::parse("", DEFAULT_USER_AGENT), Robots::allow_all()] {
+        assert!(robots.is_allowed("/kvvcr/index.cfm"));
+        assert_eq!(robots.crawl_delay(), None);
+    }
+    let robots = Robots::parse("User-agent: *\nDisallow: /\n", DEFAULT_USER_AGENT);
+    assert!(!robots.is_allowed("/kvvcr/index.cfm"));
+    assert!(robots.is_allowed("/robots.txt"));
+}
+
+#[tokio::test]
+async fn refuses_urls_that_robots_txt_disallows() {
+    let server = FixtureServer::start(&[
+        (
+            "/robots.txt",
+            "User-agent: zijwerkenvooru\nDisallow: /private\n",
+        ),
+        ("/public.html", "<p>Openbaar</p>"),
+        ("/private.html", "<p>Privé</p>"),
+    ]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = cached_client(cache_dir.path());
+
+    let page = client
+        .fetch(
+            &format!("{}/public.html", server.base_url),
+            CachePolicy::Forever,
+        )
+        .await
+        .unwrap();
+    assert_eq!(page.status, StatusCode::OK);
+
+    let private = format!("{}/private.html", server.base_url);
+    assert!(matches!(
+        client.fetch(&private, CachePolicy::Forever).await,
+        Err(Error::Disallowed { url, user_agent }) if url == private && user_agent == DEFAULT_USER_AGENT
+    ));
+    assert!(matches!(
+        client.get(&private).await,
+        Err(Error::Disallowed { .. })
+    ));
+    // The robots.txt and the public page; the private page is never requested.
+    assert_eq!(server.requests(), 2);
+
+    // The robots.txt is cached, so a new run does not download it again.
+    let client = cached_client(cache_dir.path());
+    assert!(matches!(
+        client.get(&private).await,
+        Err(Error::Disallowed { .. })
+    ));
+    assert_eq!(server.requests(), 2);
+
+    // Sites can still be crawled without robots.txt checks.
+    let client = ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        respect_robots_txt: false,
+        cassette: None,
+        ..ClientConfig::default()
+    });
+    assert_eq!(client.get(&private).await.unwrap().status(), StatusCode::OK);
+    assert_eq!(server.requests(), 3);
+}
+
+#[tokio::test]
+async fn waits_for_the_crawl_delay() {
+    let server = FixtureServer::start(&[
+        ("/robots.txt", "User-agent: *\nCrawl-delay: 0.1\n"),
+        ("/ip001x.html", "<p>1</p>"),
+        ("/ip002x.html", "<p>2</p>"),
+        ("/ip003x.html", "<p>3</p>"),
+    ]);
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = cached_client(cache_dir.path());
+
+    let started = Instant::now();
+    for id in 1..=3 {
+        let url = format!("{}/ip00{}x.html", server.base_url, id);
+        let page = client.fetch(&url, CachePolicy::Forever).await.unwrap();
+        assert_eq!(page.status, StatusCode::OK);
+    }
+    // The crawl delay raises the interval of zero, so the three pages are two delays apart.
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn identifies_itself_without_imitating_a_browser() {
+    let server = FixtureServer::start(&[("/page", "<p>page</p>")]);
+    let client = ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        respect_robots_txt: false,
+        cassette: None,
+        ..ClientConfig::default()
+    });
+
+    client
+        .get(&format!("{}/page", server.base_url))
+        .await
+        .unwrap();
+
+    let request = &server.received()[0];
+    assert_eq!(request.header("user-agent"), Some(DEFAULT_USER_AGENT));
+    assert_eq!(request.header("from"), Some(DEFAULT_FROM));
+    assert_eq!(request.header("referer"), None);
+    assert_eq!(request.header("upgrade-insecure-requests"), None);
+}
@@ -0,0 +1,98 @@
+use crawl::sessions::{
+    CURRENT_SESSION, FIRST_SESSION, SessionRange, read_progress, write_progress,
+};
+
+#[test]
+fn parses_single_sessions_and_ranges() {
+    let sessions = |text: &str| {
+        text.parse::<SessionRange>()
+            .unwrap()
+            .sessions()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(sessions("56"), [56]);
+    assert_eq!(sessions("52..=56"), [52, 53, 54, 55, 56]);
+    assert_eq!(sessions("54..56"), [54, 55]);
+    assert_eq!(sessions(" 55 ..= 56 "), [55, 56]);
+}
+
+#[test]
+fn rejects_empty_ranges_and_unknown_sessions() {
+    for text in [
+        "", "a", "56..=52", "56..56", "0..0", "51", "52..=57", "50..=53",
+    ] {
+        assert!(text.parse::<SessionRange>().is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn covers_every_session_with_html_reports() {
+    let all = SessionRange::all();
+    assert_eq!(all.sessions().next(), Some(FIRST_SESSION));
+    assert_eq!(all.sessions().last(), Some(CURRENT_SESSION));
+    assert!(all.contains(54));
+    assert!(!SessionRange::current().contains(55));
+}
+
+#[test]
+fn displays_ranges_the_way_they_are_parsed() {
+    for text in ["56", "52..=56"] {
+        assert_eq!(text.parse::<SessionRange>().unwrap().to_string(), text);
+    }
+}
+
+#[test]
+fn lists_the_sessions_older_than_the_verified_layout() {
+    let all = SessionRange::all();
+    assert_eq!(all.unverified_sessions(), [52, 53, 54, 55]);
+    assert!(SessionRange::current().unverified_sessions().is_empty());
+}
+
+#[test]
+fn starts_a_session_without_progress_at_zero() {
+    let progress_dir = tempfile::tempdir().unwrap();
+
+    assert_eq!(
+        read_progress(progress_dir.path(), "plenary", 56).unwrap(),
+        0
+    );
+    // The directory is created when progress is first written.
+    assert_eq!(
+        read_progress(&progress_dir.path().join("missing"), "plenary", 56).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn keeps_progress_per_scraper_and_session() {
+    let progress_dir = tempfile::tempdir().unwrap();
+    let progress = progress_dir.path().join("progress");
+
+    write_progress(&progress, "plenary", 56, 120).unwrap();
+    write_progress(&progress, "commission", 56, 3400).unwrap();
+    write_progress(&progress, "plenary", 55, 310).unwrap();
+    write_progress(&progress, "plenary", 56, 121).unwrap();
+
+    assert_eq!(read_progress(&progress, "plenary", 56).unwrap(), 121);
+    assert_eq!(read_progress(&progress, "plenary", 55).unwrap(), 310);
+    assert_eq!(read_progress(&progress, "commission", 56).unwrap(), 3400);
+    assert_eq!(read_progress(&progress, "commission", 55).unwrap(), 0);
+    assert_eq!(
+        std::fs::read_to_string(progress.join("plenary_56.txt")).unwrap(),
+        "121"
+    );
+}
+
+#[test]
+fn fails_on_progress_that_is_not_a_number() {
+    let progress_dir = tempfile::tempdir().unwrap();
+    std::fs::write(progress_dir.path().join("plenary_56.txt"), "12a\n").unwrap();
+    std::fs::write(progress_dir.path().join("plenary_55.txt"), " 310\n").unwrap();
+
+    assert!(read_progress(progress_dir.path(), "plenary", 56).is_err());
+    assert_eq!(
+        read_progress(progress_dir.path(), "plenary", 55).unwrap(),
+        310
+    );
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::FixtureServer;
+use crawl::cache::{CachePolicy, CacheStatus, HttpCache};
+use crawl::client::{ClientConfig, ScrapingClient};
+use crawl::urls::{Source, Urls};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+fn real_urls() -> Urls {
+    Urls::from_env()
+        .with_base_url(Source::Dekamer, Source::Dekamer.default_base_url())
+        .with_base_url(Source::Regimand, Source::Regimand.default_base_url())
+}
+
+#[test]
+fn builds_the_urls_of_the_real_websites() {
+    let urls = real_urls();
+
+    assert_eq!(
+        urls.plenary_report(56, 7),
+        "https://www.dekamer.be/doc/PCRI/html/56/ip007x.html"
+    );
+    assert_eq!(
+        urls.commission_report(56, 123),
+        "https://www.dekamer.be/doc/CCRI/html/56/ic123x.html"
+    );
+    assert_eq!(
+        urls.dossier(56, "95"),
+        "https://www.dekamer.be/kvvcr/showpage.cfm?section=/flwb&language=nl&cfm=/site/wwwcfm/flwb/flwbn.cfm?lang=N&legislat=56&dossierID=95"
+    );
+    assert_eq!(
+        urls.session_member_list(56),
+        "https://www.dekamer.be/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=cvlist54.cfm?legis=56&today=n"
+    );
+    assert_eq!(
+        urls.remuneration("Magda Aelvoet", 2020),
+        "https://public.regimand.be/?mandatary=Magda Aelvoet&year=2020"
+    );
+}
+
+#[tokio::test]
+async fn fetches_pages_from_a_fixture_server() {
+    let fixtures = real_urls();
+    let path = |url: String| url.replace(Source::Dekamer.default_base_url(), "");
+    let server = FixtureServer::start(&[
+        (
+            &path(fixtures.plenary_report(56, 1)),
+            "<p>Plenaire vergadering</p>",
+        ),
+        (&path(fixtures.dossier(56, "95")), "<p>Wetsontwerp</p>"),
+        (
+            &path(fixtures.member_page("showpage.cfm?key=O0001")),
+            "<p>Volksvertegenwoordiger</p>",
+        ),
+    ]);
+
+    let urls = real_urls().with_base_url(Source::Dekamer, &format!("{}/", server.base_url));
+    let cache_dir = tempfile::tempdir().unwrap();
+    let client = ScrapingClient::with_config(ClientConfig {
+        min_interval: Duration::ZERO,
+        cassette: None,
+        ..ClientConfig::default()
+    })
+    .with_cache(HttpCache::new(cache_dir.path()));
+
+    let pages = [
+        (urls.plenary_report(56, 1), "<p>Plenaire vergadering</p>"),
+        (urls.dossier(56, "95"), "<p>Wetsontwerp</p>"),
+        (
+            urls.member_page("showpage.cfm?key=O0001"),
+            "<p>Volksvertegenwoordiger</p>",
+        ),
+    ];
+    for (url, body) in &pages {
+        assert!(url.starts_with(&server.base_url));
+        let page = client.fetch(url, CachePolicy::Forever).await.unwrap();
+        assert_eq!(page.status, StatusCode::OK);
+        assert_eq!(page.cache_status, CacheStatus::Miss);
+        assert_eq!(page.text(), *body);
+    }
+
+    let missing = client
+        .fetch(&urls.plenary_report(56, 2), CachePolicy::Forever)
+        .await
+        .unwrap();
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+
+    // A second run is served from the cache. The fifth request was the robots.txt.
+    let page = client
+        .fetch(&pages[0].0, CachePolicy::Forever)
+        .await
+        .unwrap();
+    assert_eq!(page.cache_status, CacheStatus::Fresh);
+    assert_eq!(server.requests(), 5);
+}
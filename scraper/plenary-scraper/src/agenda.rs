@@ -0,0 +1,230 @@
+use crawl::utils::clean_text;
+use crawl::utils::debate::{speaker_name, split_turns};
+use crawl::utils::language::{detect_language, french_score};
+use itertools::Itertools;
+use model::{AgendaItem, Utterance};
+use regex::Regex;
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+
+/// The headings and paragraphs of an agenda item.
+pub struct AgendaItemText {
+    /// Number of the item in the report: "07".
+    pub number: String,
+    /// Heading of the section the item is in: "Naamstemmingen" or "Votes nominatifs". Empty for the
+    /// items before the first section, such as the agenda and the order of business.
+    pub section: String,
+    /// The Dutch and French headings, each followed by its sub-headings on new lines.
+    pub headings: Vec<String>,
+    pub paragraphs: Vec<String>,
+}
+
+/// Splits a report into its agenda items. Every item has a Dutch and a French heading with the same
+/// number, and most are listed under a section such as "Mondelinge vragen". The details of the
+/// roll-call votes at the end of the report are not part of any item.
+pub fn split_agenda_items(document: &Html) -> Vec<AgendaItemText> {
+    let item_regex = Regex::new(r"^(\d+)\s+(.+)$").unwrap();
+    let mut section = String::new();
+    let mut in_item = false;
+    let mut items: Vec<AgendaItemText> = Vec::new();
+
+    for element in document.select(&Selector::parse("h1, h2, p").unwrap()) {
+        let text = clean_text(&element.text().collect::<String>())
+            .split_whitespace()
+            .join(" ");
+
+        match element.value().name() {
+            "h1" => {
+                if ["DETAIL", "TELLING", "COMPTAGE"]
+                    .iter()
+                    .any(|keyword| text.contains(keyword))
+                {
+                    break;
+                }
+                // The second heading of a section is the translation of the first, which classifies
+                // the same.
+                section = text;
+                in_item = false;
+            }
+            "h2" => {
+                if let Some(captures) = item_regex.captures(&text) {
+                    if items.last().is_none_or(|item| item.number != captures[1]) {
+                        items.push(AgendaItemText {
+                            number: captures[1].to_string(),
+                            section: section.clone(),
+                            headings: Vec::new(),
+                            paragraphs: Vec::new(),
+                        });
+                    }
+                    if let Some(item) = items.last_mut() {
+                        item.headings.push(captures[2].replace("\"", "'"));
+                    }
+                    in_item = true;
+                } else if in_item && text.starts_with('-') {
+                    // A joined question or text: "- Nadia Moscufo aan David Clarinval (...) over
+                    // ...".
+                    if let Some(heading) =
+                        items.last_mut().and_then(|item| item.headings.last_mut())
+                    {
+                        heading.push('\n');
+                        heading.push_str(&text.replace("\"", "'"));
+                    }
+                }
+            }
+            _ => {
+                let in_table = element
+                    .ancestors()
+                    .filter_map(ElementRef::wrap)
+                    .any(|ancestor| ancestor.value().name() == "table");
+                if !in_item || in_table || text.is_empty() {
+                    continue;
+                }
+                if let Some(item) = items.last_mut() {
+                    item.paragraphs.push(text);
+                }
+            }
+        }
+    }
+
+    items
+}
+
+/// The `item_type` of an agenda item, from the section it is in and its headings. Points of order
+/// and government statements are told by their heading, as they come before the first section.
+/// Questions need a question heading, as a questions section can end with other items, such as
+/// communications.
+pub fn agenda_item_type(section: &str, headings: &str) -> &'static str {
+    let section = section.to_lowercase();
+    let headings = headings.to_lowercase();
+    let section_has = |keywords: &[&str]| keywords.iter().any(|keyword| section.contains(keyword));
+    let headings_have =
+        |keywords: &[&str]| keywords.iter().any(|keyword| headings.contains(keyword));
+    let government_statement = [
+        "verklaring van de regering",
+        "regeringsverklaring",
+        "déclaration du gouvernement",
+    ];
+
+    if headings_have(&[
+        "regeling van de werkzaamheden",
+        "ordre des travaux",
+        "ordemotie",
+        "motion d'ordre",
+        "persoonlijk feit",
+        "fait personnel",
+        "vordering van een regeringslid",
+        "requérir la présence",
+    ]) {
+        "point_of_order"
+    } else if section_has(&government_statement) || headings_have(&government_statement) {
+        "government_statement"
+    } else if (section.is_empty() || section_has(&["vragen", "questions"]))
+        && headings_have(&[
+            "vraag van",
+            "vragen van",
+            "question de",
+            "questions jointes",
+        ])
+    {
+        "question"
+    } else if section_has(&["interpellati"]) {
+        "interpellation"
+    } else if section_has(&["stemming", "vote", "scrutin"]) {
+        "vote"
+    } else if section_has(&["mededeling", "communication"]) {
+        "communication"
+    } else if section_has(&[
+        "wet",
+        "voorstel",
+        "begroting",
+        "projet",
+        "proposition",
+        "budget",
+    ]) {
+        "bill"
+    } else {
+        "other"
+    }
+}
+
+/// The agenda items of a report and the speech turns of each. `question_items` gives the question ID
+/// of the items that are questions, by item number.
+pub fn extract_agenda_rows(
+    document: &Html,
+    session_id: u32,
+    meeting_id: u32,
+    question_items: &HashMap<String, String>,
+) -> (Vec<AgendaItem>, Vec<Utterance>) {
+    // (558/1-5), (56000182I)
+    let dossier_regex = Regex::new(r"\((\d+)/\d+(?:-\d+)?\)|\((\d{8}[A-Z])\)").unwrap();
+    let mut agenda_items = Vec::new();
+    let mut utterances = Vec::new();
+    for item in split_agenda_items(document) {
+        let question_id = question_items.get(&item.number).cloned();
+        utterances.extend(extract_utterances(
+            session_id,
+            meeting_id,
+            &item.number,
+            question_id.as_deref(),
+            &item.paragraphs,
+        ));
+
+        let (title_nl, title_fr) = match item.headings.as_slice() {
+            [] => (String::new(), String::new()),
+            [title] => (title.clone(), String::new()),
+            [first, second, ..] if french_score(first) > french_score(second) => {
+                (second.clone(), first.clone())
+            }
+            [first, second, ..] => (first.clone(), second.clone()),
+        };
+        let headings = item.headings.join("\n");
+        let dossier_ids: Vec<String> = dossier_regex
+            .captures_iter(&headings)
+            .filter_map(|captures| captures.get(1).or_else(|| captures.get(2)))
+            .map(|number| number.as_str().to_string())
+            .unique()
+            .collect();
+
+        agenda_items.push(AgendaItem {
+            session_id: session_id.to_string(),
+            meeting_id: meeting_id.to_string(),
+            item_type: agenda_item_type(&item.section, &headings).to_string(),
+            item_id: item.number,
+            title_nl,
+            title_fr,
+            dossier_ids,
+            question_id,
+        });
+    }
+    (agenda_items, utterances)
+}
+
+/// The speech turns of an agenda item, from its paragraphs. The `member_id`s are set by
+/// `resolve_speakers`.
+pub fn extract_utterances(
+    session_id: u32,
+    meeting_id: u32,
+    item_id: &str,
+    question_id: Option<&str>,
+    paragraphs: &[String],
+) -> Vec<Utterance> {
+    split_turns(paragraphs.iter().map(String::as_str))
+        .into_iter()
+        .enumerate()
+        .map(|(sequence, turn)| Utterance {
+            session_id: session_id.to_string(),
+            meeting_id: meeting_id.to_string(),
+            meeting_type: "plenary".to_string(),
+            question_id: question_id.map(str::to_string),
+            sequence: sequence as i32,
+            minister: (turn.role == "minister").then(|| speaker_name(&turn.label)),
+            speaker: turn.label,
+            member_id: None,
+            role: turn.role.to_string(),
+            language: detect_language(&turn.paragraphs).map(str::to_string),
+            paragraphs: turn.paragraphs,
+            marker: turn.marker,
+            item_id: Some(item_id.to_string()),
+        })
+        .collect()
+}
@@ -0,0 +1,198 @@
+use crate::rows::{MeetingRows, meeting_key};
+use chrono::NaiveDate;
+use crawl::utils::clean_text;
+use crawl::utils::names::{MemberIndex, normalize_person_name};
+use itertools::Itertools;
+use model::{Attendance, Member};
+use scraper::{Html, Selector};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// A member on the list of excused members of a report.
+pub struct Excused {
+    pub name: String,
+    pub reason_nl: Option<String>,
+    pub reason_fr: Option<String>,
+}
+
+/// Reads the list of members who sent their apologies, near the start of a report.
+///
+/// The list follows the heading "Berichten van verhindering" or "Hebben zich verontschuldigd" and
+/// its French translation, in the same or the next paragraphs, and ends at the first empty
+/// paragraph after it. Entries are separated by semicolons, and an entry is one or more names
+/// followed by the reason in both languages: "Jan Peeters, Anna Smet, ziek / malade". Names without
+/// a reason are separated by commas: "Ludivine Dedonder, Alexander De Croo, Theo Francken."
+pub fn extract_excused(document: &Html) -> Vec<Excused> {
+    let headings = [
+        "Berichten van verhindering",
+        "Hebben zich verontschuldigd",
+        "Se sont excusés",
+        "Excusés",
+    ];
+    let mut in_list = false;
+    let mut excused = Vec::new();
+
+    for element in document.select(&Selector::parse("h1, h2, p").unwrap()) {
+        let mut text = clean_text(&element.text().collect::<String>())
+            .split_whitespace()
+            .join(" ");
+        if in_list && element.value().name() != "p" {
+            break;
+        }
+
+        // Drop the headings, which can share a paragraph with each other and with the list.
+        loop {
+            let trimmed = text.trim_start_matches([' ', ':', '/', '-']);
+            match headings
+                .iter()
+                .find(|heading| trimmed.starts_with(*heading))
+            {
+                Some(heading) => {
+                    in_list = true;
+                    text = trimmed[heading.len()..].to_string();
+                }
+                None => {
+                    text = trimmed.to_string();
+                    break;
+                }
+            }
+        }
+        if !in_list {
+            continue;
+        }
+        if text.is_empty() {
+            match excused.is_empty() {
+                true => continue,
+                false => break,
+            }
+        }
+
+        for entry in text.split(';') {
+            let entry = entry.trim().trim_end_matches('.');
+            let (names, reason_nl, reason_fr) = match entry.split_once('/') {
+                Some((before, reason_fr)) => match before.rsplit_once(',') {
+                    Some((names, reason_nl)) => (names, Some(reason_nl), Some(reason_fr)),
+                    None => continue,
+                },
+                None => (entry, None, None),
+            };
+            let reason = |reason: Option<&str>| reason.map(|reason| reason.trim().to_string());
+            for name in names
+                .split(',')
+                .map(normalize_person_name)
+                .filter(|name| name.chars().any(char::is_alphabetic))
+            {
+                excused.push(Excused {
+                    name,
+                    reason_nl: reason(reason_nl),
+                    reason_fr: reason(reason_fr),
+                });
+            }
+        }
+    }
+
+    excused
+}
+
+/// Sets the `member_id` of every excused member, and adds a row for every other member in office at
+/// each meeting with a roll-call vote: "voted" when they cast a ballot, "absent" otherwise. Returns
+/// the excused names that match no member.
+///
+/// Members have no end date, so a member who is no longer active is taken to be in office up to the
+/// last meeting they voted in.
+pub fn fill_attendance(
+    rows: &mut MeetingRows,
+    members: &[Member],
+    member_index: &MemberIndex,
+) -> Vec<String> {
+    let mut unmatched = BTreeSet::new();
+    rows.attendance.retain(|row| row.status == "excused");
+    for row in &mut rows.attendance {
+        row.member_id = member_index
+            .find(&row.session_id, &row.name)
+            .map(str::to_string);
+        if row.member_id.is_none() {
+            unmatched.insert(row.name.clone());
+        }
+    }
+
+    let meeting_dates: HashMap<(&str, &str), NaiveDate> = rows
+        .meetings
+        .iter()
+        .map(|meeting| {
+            (
+                (meeting.session_id.as_str(), meeting.meeting_id.as_str()),
+                meeting.date,
+            )
+        })
+        .collect();
+    // Members who voted at least once, per meeting, and the date of the last meeting each member
+    // voted in.
+    let mut voters: HashMap<(&str, &str), HashSet<&str>> = HashMap::new();
+    let mut last_voted: HashMap<(&str, &str), NaiveDate> = HashMap::new();
+    for ballot in &rows.ballots {
+        let Some(member_id) = ballot.member_id.as_deref() else {
+            continue;
+        };
+        let meeting = (ballot.session_id.as_str(), ballot.meeting_id.as_str());
+        voters.entry(meeting).or_default().insert(member_id);
+        if let Some(&date) = meeting_dates.get(&meeting) {
+            let last = last_voted.entry((meeting.0, member_id)).or_insert(date);
+            *last = date.max(*last);
+        }
+    }
+    let excused: HashSet<(&str, &str, &str)> = rows
+        .attendance
+        .iter()
+        .filter_map(|row| {
+            let member_id = row.member_id.as_deref()?;
+            Some((row.session_id.as_str(), row.meeting_id.as_str(), member_id))
+        })
+        .collect();
+
+    let mut filled = Vec::new();
+    for meeting in &rows.meetings {
+        let Some(meeting_voters) =
+            voters.get(&(meeting.session_id.as_str(), meeting.meeting_id.as_str()))
+        else {
+            continue;
+        };
+        for member in members
+            .iter()
+            .filter(|member| member.session_id == meeting.session_id)
+        {
+            let in_office = member.start.is_none_or(|start| start <= meeting.date)
+                && (member.active
+                    || last_voted
+                        .get(&(member.session_id.as_str(), member.member_id.as_str()))
+                        .is_some_and(|&last| meeting.date <= last));
+            let key = (
+                meeting.session_id.as_str(),
+                meeting.meeting_id.as_str(),
+                member.member_id.as_str(),
+            );
+            if !in_office || excused.contains(&key) {
+                continue;
+            }
+            let status = match meeting_voters.contains(member.member_id.as_str()) {
+                true => "voted",
+                false => "absent",
+            };
+            filled.push(Attendance {
+                session_id: meeting.session_id.clone(),
+                meeting_id: meeting.meeting_id.clone(),
+                member_id: Some(member.member_id.clone()),
+                name: format!("{} {}", member.first_name, member.last_name),
+                status: status.to_string(),
+                reason_nl: None,
+                reason_fr: None,
+            });
+        }
+    }
+
+    rows.attendance.extend(filled);
+    // The sort is stable, so the excused members of a meeting stay first, in the order of the
+    // report.
+    rows.attendance
+        .sort_by_key(|row| meeting_key(&row.session_id, &row.meeting_id));
+    unmatched.into_iter().collect()
+}
@@ -0,0 +1,141 @@
+use crate::ids::{RowIds, decision_stable_id};
+use crate::votes::{extract_outcome, extract_vote_data};
+use chrono::NaiveDate;
+use crawl::utils::clean_text;
+use crawl::utils::language::french_score;
+use itertools::Itertools;
+use model::Vote;
+use regex::Regex;
+use scraper::{Html, Selector};
+use std::error::Error;
+
+/// A decision taken without a roll call.
+pub struct Decision {
+    /// "unanimous" or "show_of_hands", as in `Vote::vote_kind`.
+    pub kind: &'static str,
+    pub title_nl: String,
+    pub title_fr: String,
+    pub outcome: Option<&'static str>,
+    pub majority: &'static str,
+    /// The paragraph that states the decision.
+    pub text: String,
+}
+
+/// Extracts the decisions taken without a roll call: urgencies and agenda changes voted by sitting
+/// and standing ("De urgentie wordt aangenomen bij zitten en opstaan.") and texts adopted without
+/// objection ("Geen bezwaar? (Nee)" followed by "Dan zijn ze aangenomen.").
+///
+/// A decision gets the title of the agenda item it is part of. Decisions outside of a numbered
+/// agenda item, such as the credentials approved in the first meeting of a session, are skipped.
+pub fn extract_decisions(document: &Html) -> Vec<Decision> {
+    let item_regex = Regex::new(r"^(\d+)\s+(.+)$").unwrap();
+    let mut item_number = String::new();
+    let mut item_titles: Vec<String> = Vec::new();
+    let mut no_objection = false;
+    let mut decisions = Vec::new();
+
+    for element in document.select(&Selector::parse("h1, h2, p").unwrap()) {
+        let text = clean_text(&element.text().collect::<String>())
+            .split_whitespace()
+            .join(" ");
+
+        if element.value().name() != "p" {
+            no_objection = false;
+            // Every agenda item has a Dutch and a French heading with the same number.
+            if let Some(captures) = item_regex.captures(&text) {
+                if captures[1] != item_number {
+                    item_number = captures[1].to_string();
+                    item_titles.clear();
+                }
+                item_titles.push(captures[2].replace("\"", "'"));
+            }
+            continue;
+        }
+
+        if text.ends_with("(Nee)") {
+            no_objection = true;
+            continue;
+        }
+        // The French question sits between the Dutch question and its answer.
+        if text.is_empty() || text.ends_with("(Non)") {
+            continue;
+        }
+
+        let decided = if text.contains("zitten en opstaan")
+            && (text.contains("aangenomen") || text.contains("verworpen"))
+        {
+            Some("show_of_hands")
+        } else if text.contains("aangenomen")
+            && (no_objection || text.contains("geen bezwaar") || text.contains("eenparig"))
+        {
+            Some("unanimous")
+        } else {
+            None
+        };
+        no_objection = false;
+        let Some(kind) = decided else {
+            continue;
+        };
+
+        let (title_nl, title_fr) = match item_titles.as_slice() {
+            [] => continue,
+            [title] => (title.clone(), String::new()),
+            [first, second, ..] if french_score(first) > french_score(second) => {
+                (second.clone(), first.clone())
+            }
+            [first, second, ..] => (first.clone(), second.clone()),
+        };
+        let (outcome, majority) = extract_outcome(&text);
+        decisions.push(Decision {
+            kind,
+            title_nl,
+            title_fr,
+            outcome,
+            majority,
+            text,
+        });
+    }
+
+    decisions
+}
+
+/// The decisions of a report taken without a roll call, as votes without a tally. They were never
+/// numbered by position, so they have no old ID to map.
+pub fn extract_decision_votes(
+    document: &Html,
+    ids: &mut RowIds,
+    date: NaiveDate,
+) -> Result<Vec<Vote>, Box<dyn Error + Send + Sync>> {
+    let mut votes = Vec::new();
+    for decision in extract_decisions(document) {
+        let stable_id = ids.unique(
+            "vote",
+            decision_stable_id(decision.kind, &decision.title_nl, &decision.text),
+        );
+        let decision_nl = extract_vote_data(decision.title_nl)?;
+        let decision_fr = extract_vote_data(decision.title_fr)?;
+
+        votes.push(Vote {
+            vote_id: stable_id,
+            session_id: ids.session_id.clone(),
+            meeting_id: ids.meeting_id.clone(),
+            date,
+            title_nl: decision_nl.topic,
+            title_fr: decision_fr.topic,
+            yes: None,
+            no: None,
+            abstain: None,
+            members_yes: Vec::new(),
+            members_no: Vec::new(),
+            members_abstain: Vec::new(),
+            dossier_id: decision_nl.dossier_id,
+            document_id: decision_nl.document_id,
+            motion_id: decision_nl.motion_id,
+            vote_kind: decision.kind.to_string(),
+            outcome: decision.outcome.map(str::to_string),
+            majority: decision.majority.to_string(),
+            tally_mismatch: false,
+        });
+    }
+    Ok(votes)
+}
@@ -0,0 +1,171 @@
+use crawl::utils::language::detect_language;
+use itertools::Itertools;
+
+/// The text of one line of one column of a PDF page.
+pub struct TextRun {
+    pub left: f64,
+    pub right: f64,
+    pub y: f64,
+    pub text: String,
+}
+
+/// Collects the characters of a PDF into the runs of each page, so the columns of the page can be
+/// told apart.
+#[derive(Default)]
+struct PageRuns {
+    /// The horizontal middle of each page and its runs, in the order they were drawn.
+    pages: Vec<(f64, Vec<TextRun>)>,
+    page_height: f64,
+    new_word: bool,
+}
+
+impl pdf_extract::OutputDev for PageRuns {
+    fn begin_page(
+        &mut self,
+        _page_num: u32,
+        media_box: &pdf_extract::MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), pdf_extract::OutputError> {
+        self.page_height = media_box.ury - media_box.lly;
+        self.pages
+            .push(((media_box.llx + media_box.urx) / 2.0, Vec::new()));
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), pdf_extract::OutputError> {
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &pdf_extract::Transform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), pdf_extract::OutputError> {
+        let Some((_, runs)) = self.pages.last_mut() else {
+            return Ok(());
+        };
+        // Position from the top left of the page, and the font size on the page, as in the
+        // extractor's plain text.
+        let (x, y) = (trm.m31, self.page_height - trm.m32);
+        let size = ((font_size * (trm.m11 + trm.m21)) * (font_size * (trm.m12 + trm.m22)))
+            .abs()
+            .sqrt();
+        let end = x + width * size;
+        match runs.last_mut() {
+            // A character on the same line continues the run, unless it is as far away as the next
+            // column.
+            Some(run)
+                if (y - run.y).abs() <= size * 0.5
+                    && x > run.right - size
+                    && x < run.right + size * 2.0 =>
+            {
+                if self.new_word && x > run.right + size * 0.1 {
+                    run.text.push(' ');
+                }
+                run.text.push_str(char);
+                run.right = end;
+            }
+            _ => runs.push(TextRun {
+                left: x,
+                right: end,
+                y,
+                text: char.to_string(),
+            }),
+        }
+        self.new_word = false;
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), pdf_extract::OutputError> {
+        self.new_word = true;
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), pdf_extract::OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), pdf_extract::OutputError> {
+        Ok(())
+    }
+}
+
+/// The columns of a page: its left and right half when hardly any line crosses the middle, the
+/// whole page otherwise.
+pub fn page_columns(middle: f64, runs: &[TextRun]) -> Vec<String> {
+    let length =
+        |runs: &mut dyn Iterator<Item = &TextRun>| runs.map(|run| run.text.len()).sum::<usize>();
+    let total = length(&mut runs.iter());
+    let crossing = length(
+        &mut runs
+            .iter()
+            .filter(|run| run.left < middle && run.right > middle),
+    );
+    let left = length(&mut runs.iter().filter(|run| run.right <= middle));
+    let lines = |runs: &mut dyn Iterator<Item = &TextRun>| {
+        runs.map(|run| run.text.trim())
+            .filter(|text| !text.is_empty())
+            .join("\n")
+    };
+    // Headings across both columns go with the left one.
+    if crossing * 10 <= total && left * 10 >= total && (total - left - crossing) * 10 >= total {
+        vec![
+            lines(&mut runs.iter().filter(|run| run.left < middle)),
+            lines(&mut runs.iter().filter(|run| run.left >= middle)),
+        ]
+    } else {
+        vec![lines(&mut runs.iter())]
+    }
+}
+
+/// Fills in the text of `text` from its PDF. Each column is added to the text of the language it is
+/// in, and the columns of which no language can be told (page numbers, tables, names) to
+/// `text_other`.
+pub fn extract_document_text(
+    mut text: model::DocumentText,
+    pdf: &[u8],
+) -> Result<model::DocumentText, String> {
+    // The extractor panics on some malformed files instead of returning an error.
+    let pages = std::panic::catch_unwind(|| -> Result<_, pdf_extract::OutputError> {
+        let mut document = pdf_extract::Document::load_mem(pdf)?;
+        if document.is_encrypted() {
+            document.decrypt("")?;
+        }
+        let mut runs = PageRuns::default();
+        pdf_extract::output_doc(&document, &mut runs)?;
+        Ok(runs.pages)
+    })
+    .map_err(|_| "the PDF could not be parsed".to_string())?
+    .map_err(|error| error.to_string())?;
+
+    let mut columns = vec![];
+    let mut nl = vec![];
+    let mut fr = vec![];
+    let mut other = vec![];
+    for (middle, runs) in &pages {
+        for column in page_columns(*middle, runs) {
+            match detect_language(std::slice::from_ref(&column)) {
+                Some("nl") => nl.push(column.clone()),
+                Some("fr") => fr.push(column.clone()),
+                _ => other.push(column.clone()),
+            }
+            columns.push(column);
+        }
+    }
+    let join = |columns: Vec<String>| {
+        let text = columns
+            .into_iter()
+            .filter(|column| !column.is_empty())
+            .join("\n\n");
+        Some(text).filter(|text| !text.is_empty())
+    };
+    text.pages = pages.len() as i32;
+    text.text = join(columns).unwrap_or_default();
+    text.text_nl = join(nl);
+    text.text_fr = join(fr);
+    text.text_other = join(other);
+    Ok(text)
+}
@@ -0,0 +1,486 @@
+use crawl::utils::names::normalize_person_name;
+use itertools::Itertools;
+use model::{DocumentStatus, DocumentType};
+use regex::Regex;
+use scraper::{Html, Selector};
+use std::error::Error;
+
+/// A document listed under "Subdocumenten" on a dossier page.
+pub struct Subdocument {
+    pub dossier_id: String,
+    pub id: String,
+    /// The type as written on the page when it is not a known one.
+    pub document_type: Result<DocumentType, String>,
+    pub date: String,
+    pub authors: Vec<String>,
+}
+
+/// What a dossier page says about the dossier, see `model::Dossier`. Dates are as written on the
+/// page, "03/10/2025".
+pub struct Dossier {
+    pub title: String,
+    pub authors: Vec<String>,
+    pub submission_date: String,
+    pub end_date: String,
+    pub vote_date: String,
+    /// The type or status as written on the page when it is not a known one.
+    pub document_type: Result<DocumentType, String>,
+    pub status: Result<DocumentStatus, String>,
+    pub subdocuments: Vec<Subdocument>,
+    pub committee: Option<String>,
+    pub procedure: Option<&'static str>,
+    pub events: Vec<DossierEvent>,
+    /// (kind, keyword)
+    pub keywords: Vec<(&'static str, String)>,
+    /// (number, path) of the PDF of each document of the dossier, the main one ("001") included.
+    pub documents: Vec<(String, String)>,
+}
+
+/// A step in the procedure of a dossier, see `model::DossierEvent`.
+pub struct DossierEvent {
+    pub date: String,
+    pub kind: &'static str,
+    pub stage: Option<&'static str>,
+    pub committee: Option<String>,
+    pub description: String,
+    pub document_id: Option<String>,
+}
+
+/// Splits the calendar of a dossier page ("24/09/2025 IN BESPREKING 21/10/2025 AANGENOMEN") into
+/// its dated steps.
+pub fn parse_dated_entries(text: &str) -> Vec<(String, String)> {
+    let date_re = Regex::new(r"\d{2}/\d{2}/\d{4}").unwrap();
+    let dates: Vec<_> = date_re.find_iter(text).collect();
+    dates
+        .iter()
+        .enumerate()
+        .map(|(i, date)| {
+            let end = dates.get(i + 1).map_or(text.len(), |next| next.start());
+            let description = text[date.end()..end]
+                .replace("(Zie opmerking in volledige fiche)", "")
+                .split_whitespace()
+                .join(" ");
+            (date.as_str().to_string(), description)
+        })
+        .collect()
+}
+
+/// The kind of a step in the calendar of a dossier page, from its description.
+pub fn dossier_event_kind(description: &str) -> &'static str {
+    let description = description.to_uppercase();
+    if description.contains("URGENTIE") {
+        match description.contains("VERWORPEN") {
+            true => "urgency_rejected",
+            false => "urgency_adopted",
+        }
+    } else if description.starts_with("IN BESPREKING") {
+        "discussed"
+    } else if description.starts_with("START VERGADERING") {
+        "meeting"
+    } else if description.starts_with("AANGENOMEN") {
+        "adopted"
+    } else if description.starts_with("VERWORPEN") || description.starts_with("VERWERPING AANG") {
+        "rejected"
+    } else if description.starts_with("ZONDER VOORWERP") {
+        "without_object"
+    } else if description.starts_with("ZONDER VERSLAG") {
+        "without_report"
+    } else if description.starts_with("TERUGZENDING") {
+        "sent_back"
+    } else if description.starts_with("AANGELEGENHEID BEDOELD IN ARTIKEL") {
+        "procedure"
+    } else if description.starts_with("BEEINDIGD") {
+        "ended"
+    } else if description.starts_with("VRAAG OM ADVIES VAN DE RAAD VAN STATE") {
+        "council_of_state_requested"
+    } else {
+        "other"
+    }
+}
+
+/// Reads a dossier page. Fails when the page lacks the table of the dossier or of its
+/// subdocuments, as error pages do.
+pub fn scrape_dossier(dossier_id: &str, document: &Html) -> Result<Dossier, Box<dyn Error>> {
+    // Selectors.
+    let title_selector = Selector::parse("#story h4 center").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    let title = document
+        .select(&title_selector)
+        .next()
+        .and_then(|el| el.text().next())
+        .unwrap_or("")
+        .trim()
+        .to_string();
+    let mut submission_date = String::new();
+    let mut vote_date = String::new();
+    let mut end_date = String::new();
+    let mut dossier_authors = Vec::new();
+    let mut document_type = Ok(DocumentType::Onbekend);
+    let mut status = Ok(DocumentStatus::Onbekend);
+    let mut subdocuments = Vec::new();
+    let mut committee = None;
+    let mut procedure = None;
+    let mut events = Vec::new();
+    let mut keywords = Vec::new();
+
+    // A numbered "COMMISSIE KAMER" or "PLENAIRE VERGADERING KAMER" heading starts the calendar of
+    // that stage.
+    let stage_re = Regex::new(
+        r"^(?:\d+\. )?(commissie kamer|plenaire vergadering kamer|plenaire vergadering senaat)$",
+    )
+    .unwrap();
+    let mut stage = None;
+    let mut stage_committee: Option<String> = None;
+    // The "Document Kamer" or "Document Senaat" the rows are about, and the type of the latter.
+    let mut document_block = None;
+    let mut senate_document_type = String::new();
+
+    let document_table = document
+        .select(&Selector::parse("table").unwrap())
+        .next()
+        .ok_or("no table of the dossier on the page")?;
+
+    if let Some(tbody) = document_table
+        .select(&Selector::parse("tbody").unwrap())
+        .next()
+    {
+        for row in document_table.select(&Selector::parse("tr").unwrap()) {
+            if row.parent().unwrap() == *tbody {
+                let mut cells = row.select(&cell_selector);
+                let cell_1 = cells.next();
+                let cell_2 = cells.next();
+
+                if let (Some(cell_1), Some(cell_2)) = (cell_1, cell_2) {
+                    let label = cell_1
+                        .text()
+                        .collect::<String>()
+                        .to_lowercase()
+                        .trim()
+                        .to_string();
+                    let value = cell_2
+                        .text()
+                        .collect::<String>()
+                        .to_lowercase()
+                        .trim()
+                        .to_string();
+
+                    // The label as written, and normalised for matching.
+                    let label_text = cell_1
+                        .text()
+                        .collect::<String>()
+                        .split_whitespace()
+                        .join(" ");
+                    let heading = label_text.to_lowercase();
+                    let text = cell_2
+                        .text()
+                        .collect::<String>()
+                        .split_whitespace()
+                        .join(" ");
+                    if let Some(captures) = stage_re.captures(&heading) {
+                        document_block = None;
+                        stage = Some(match &captures[1] {
+                            "commissie kamer" => "committee",
+                            "plenaire vergadering kamer" => "plenary",
+                            _ => "senate",
+                        });
+                        stage_committee = match stage {
+                            Some("committee") => {
+                                Some(text.trim_end_matches("(OPENBAAR)").trim().to_string())
+                            }
+                            _ => None,
+                        };
+                        if committee.is_none() {
+                            committee = stage_committee.clone();
+                        }
+                    } else if heading == "document kamer" || heading == "document senaat" {
+                        document_block = Some(heading.trim_start_matches("document ").to_string());
+                        stage = None;
+                    }
+                    let event = |date: &str,
+                                 kind,
+                                 stage: Option<&'static str>,
+                                 description: &str| DossierEvent {
+                        date: date.to_string(),
+                        kind,
+                        stage,
+                        committee: stage.and(stage_committee.clone()),
+                        description: description.to_string(),
+                        document_id: None,
+                    };
+
+                    // Rows that date a single step.
+                    let evoked = match senate_document_type.contains("NIET-GEEVOCEERD") {
+                        true => "not_evoked",
+                        false => "evoked",
+                    };
+                    let step = match (heading.as_str(), document_block.as_deref()) {
+                        ("indieningsdatum", Some("kamer")) => {
+                            Some(("submitted", label_text.as_str()))
+                        }
+                        ("indieningsdatum", Some("senaat")) => {
+                            Some(("sent_to_senate", label_text.as_str()))
+                        }
+                        ("einddatum", Some("senaat"))
+                            if senate_document_type.contains("GEEVOCEERD") =>
+                        {
+                            Some((evoked, senate_document_type.as_str()))
+                        }
+                        ("verzendingsdatum", _) => Some(("sent_to_committee", label_text.as_str())),
+                        ("inoverwegingneming", _) => {
+                            Some(("taken_into_consideration", label_text.as_str()))
+                        }
+                        ("stemming senaat", _) => Some(("senate_vote", label_text.as_str())),
+                        ("datum wet", _) => Some(("royal_assent", label_text.as_str())),
+                        ("staatsblad datum", _) => Some(("published", "Staatsblad")),
+                        _ => None,
+                    };
+                    if let Some((kind, description)) = step {
+                        events.push(event(&text, kind, None, description));
+                    }
+
+                    match (heading.as_str(), document_block.as_deref()) {
+                        ("document type", Some("senaat")) => senate_document_type = text.clone(),
+                        ("staatsblad nr.", _) => {
+                            if let Some(event) = events
+                                .iter_mut()
+                                .rev()
+                                .find(|event| event.kind == "published")
+                            {
+                                event.description = format!("Staatsblad nr. {}", text);
+                            }
+                        }
+                        ("staatsblad erratum", _) => {
+                            for (date, number) in parse_dated_entries(&text) {
+                                let description = format!("Staatsblad erratum nr. {}", number);
+                                events.push(event(&date, "erratum_published", None, &description));
+                            }
+                        }
+                        ("kalender", _) | ("incident", _) | ("bevoegdheid", _) => {
+                            let stage = stage.filter(|_| heading == "kalender");
+                            for (date, description) in parse_dated_entries(&text) {
+                                events.push(event(
+                                    &date,
+                                    dossier_event_kind(&description),
+                                    stage,
+                                    &description,
+                                ));
+                            }
+                        }
+                        ("artikel grondwet", _) => {
+                            procedure = match text.split_whitespace().next() {
+                                Some("74") => Some("monocameral"),
+                                Some("77") => Some("bicameral"),
+                                Some("78") => Some("optional_bicameral"),
+                                _ => None,
+                            }
+                        }
+                        ("eurovoc-hoofddescriptor", _)
+                        | ("eurovoc descriptoren", _)
+                        | ("eurovoc kandidaat-descriptoren", _)
+                        | ("vrije trefwoorden", _) => {
+                            let kind = match heading.as_str() {
+                                "eurovoc-hoofddescriptor" => "main_descriptor",
+                                "eurovoc descriptoren" => "descriptor",
+                                "eurovoc kandidaat-descriptoren" => "candidate_descriptor",
+                                _ => "free",
+                            };
+                            for keyword in text
+                                .split('|')
+                                .map(str::trim)
+                                .filter(|keyword| !keyword.is_empty())
+                            {
+                                keywords.push((kind, keyword.to_string()));
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    // Submission date.
+                    if label.contains("indieningsdatum") {
+                        submission_date = value;
+                    }
+                    // Vote date.
+                    else if label.contains("stemming kamer") {
+                        vote_date = value;
+                    }
+                    // End date.
+                    else if label.contains("einddatum") {
+                        end_date = value;
+                    }
+                    // Author section start
+                    else if label.contains("auteur(s)") {
+                        for link in cell_2.select(&Selector::parse("a").unwrap()) {
+                            if let Some(name_raw) = link.text().next() {
+                                dossier_authors.push(normalize_person_name(name_raw));
+                            }
+                        }
+
+                        // Fallback: if no links found, try raw text
+                        if dossier_authors.is_empty() {
+                            for text_node in cell_2.text() {
+                                let name = text_node.trim();
+                                if !name.is_empty() {
+                                    dossier_authors.push(normalize_person_name(name));
+                                }
+                            }
+                        }
+                    }
+                    // Type.
+                    else if label.contains("document type") {
+                        document_type = DocumentType::parse(&text).ok_or(text);
+                    }
+                    // Status.
+                    else if label.contains("status") {
+                        status = DocumentStatus::parse(&text).ok_or(text);
+                    }
+                    // Subdocuments.
+                    else if label.contains("subdocumenten") {
+                        let subdocument_table = cell_2
+                            .select(&Selector::parse("table").unwrap())
+                            .next()
+                            .ok_or("no table in the subdocuments row")?;
+
+                        let mut document_id = String::new();
+                        let mut document_type = Ok(DocumentType::Onbekend);
+                        let mut document_date = String::new();
+                        let mut document_authors: Vec<String> = Vec::new();
+
+                        let mut parsing_authors = false;
+                        let mut complete_subdocument = false;
+
+                        for row in subdocument_table.select(&Selector::parse("tr").unwrap()) {
+                            let mut cells = row.select(&cell_selector);
+                            let cell_1 = cells.next();
+                            let cell_2 = cells.next();
+
+                            // If cell 2 is empty, that's the end of the subdocument.
+                            if cell_2.is_none() {
+                                if complete_subdocument {
+                                    subdocuments.push(Subdocument {
+                                        dossier_id: dossier_id.to_string(),
+                                        id: document_id.clone(),
+                                        document_type: document_type.clone(),
+                                        date: document_date.to_string(),
+                                        authors: document_authors.clone(),
+                                    });
+
+                                    // Reset all fields for next subdocument
+                                    document_id.clear();
+                                    document_date.clear();
+                                    document_authors.clear();
+                                    complete_subdocument = false;
+                                    parsing_authors = false;
+                                }
+                                continue;
+                            }
+
+                            if let (Some(cell_1), Some(cell_2)) = (cell_1, cell_2) {
+                                let label = cell_1
+                                    .text()
+                                    .collect::<String>()
+                                    .to_lowercase()
+                                    .trim()
+                                    .to_string();
+                                let value = cell_2
+                                    .text()
+                                    .collect::<String>()
+                                    .to_lowercase()
+                                    .trim()
+                                    .to_string();
+
+                                // Document id.
+                                if let Some(link) =
+                                    cell_1.select(&Selector::parse("a").unwrap()).last()
+                                {
+                                    if let Some(id_text) = link.text().next() {
+                                        document_id = id_text.trim().to_string();
+                                    }
+                                }
+
+                                // Document type.
+                                if let Some(font) =
+                                    cell_2.select(&Selector::parse("font").unwrap()).next()
+                                {
+                                    let type_text =
+                                        font.text().collect::<String>().trim().to_string();
+                                    document_type =
+                                        DocumentType::parse(&type_text).ok_or(type_text);
+                                }
+
+                                // Date.
+                                if label.contains("datum ronddeling") {
+                                    document_date = value;
+                                }
+
+                                // Author section start
+                                if label.contains("auteur(s)") {
+                                    parsing_authors = true;
+                                }
+
+                                // Collect subdocument author if in author mode
+                                if parsing_authors {
+                                    if let Some(link) =
+                                        cell_2.select(&Selector::parse("a").unwrap()).next()
+                                    {
+                                        if let Some(name) = link.text().next() {
+                                            document_authors.push(normalize_person_name(name));
+                                        }
+                                    }
+                                }
+
+                                // Ready to commit subdocument when key fields are filled
+                                if !document_id.is_empty() && !document_date.is_empty() {
+                                    complete_subdocument = true;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    for subdocument in &subdocuments {
+        if let Ok(DocumentType::AdviesVanDeRaadVanState) = subdocument.document_type {
+            events.push(DossierEvent {
+                date: subdocument.date.clone(),
+                kind: "council_of_state_opinion",
+                stage: None,
+                committee: None,
+                description: DocumentType::AdviesVanDeRaadVanState.label_nl().to_string(),
+                document_id: Some(subdocument.id.clone()),
+            });
+        }
+    }
+
+    // The PDFs of the documents. The page also links to those of related dossiers, which are left
+    // out.
+    let pdf_re = Regex::new(r"^/FLWB/PDF/\d+/(\d+)/\d+K\d{4}(\d{3})\.pdf$").unwrap();
+    let mut documents: Vec<(String, String)> = Vec::new();
+    for link in document.select(&Selector::parse("a[href]").unwrap()) {
+        let href = link.value().attr("href").unwrap_or_default();
+        if let Some(captures) = pdf_re.captures(href) {
+            let same_dossier = captures[1].parse::<u32>().ok() == dossier_id.parse().ok();
+            if same_dossier && !documents.iter().any(|(number, _)| *number == captures[2]) {
+                documents.push((captures[2].to_string(), href.to_string()));
+            }
+        }
+    }
+
+    Ok(Dossier {
+        title,
+        authors: dossier_authors,
+        submission_date,
+        end_date,
+        vote_date,
+        document_type,
+        status,
+        subdocuments,
+        committee,
+        procedure,
+        events,
+        keywords,
+        documents,
+    })
+}
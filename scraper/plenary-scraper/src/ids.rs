@@ -0,0 +1,89 @@
+use model::IdMapping;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Gives the votes, questions and propositions of a meeting their IDs, and records the positions
+/// they replace.
+pub struct RowIds {
+    pub session_id: String,
+    pub meeting_id: String,
+    /// Number of rows that were given each ID, per kind.
+    given: HashMap<(&'static str, String), usize>,
+    /// The old position IDs of the rows that have one, for `id_mappings.parquet`.
+    pub mappings: Vec<IdMapping>,
+}
+
+impl RowIds {
+    pub fn new(session_id: u32, meeting_id: u32) -> RowIds {
+        RowIds {
+            session_id: session_id.to_string(),
+            meeting_id: meeting_id.to_string(),
+            given: HashMap::new(),
+            mappings: Vec::new(),
+        }
+    }
+
+    /// Returns `id` for a row of `kind`, followed by "-2", "-3"... when earlier rows of the meeting
+    /// already got it, such as two urgency requests under the same agenda item.
+    pub fn unique(&mut self, kind: &'static str, id: String) -> String {
+        let count = self.given.entry((kind, id.clone())).or_default();
+        *count += 1;
+        if *count == 1 {
+            id
+        } else {
+            format!("{id}-{count}")
+        }
+    }
+
+    /// Returns the unique `id` for the row of `kind` that used to be identified by its `position`.
+    pub fn assign(&mut self, kind: &'static str, position: String, id: String) -> String {
+        let id = self.unique(kind, id);
+        self.mappings.push(IdMapping {
+            session_id: self.session_id.clone(),
+            meeting_id: self.meeting_id.clone(),
+            kind: kind.to_string(),
+            old_id: position,
+            new_id: id.clone(),
+        });
+        id
+    }
+}
+
+/// ID for a row without an official number: the first 12 hex digits of the SHA-256 hash of its
+/// text.
+pub fn content_id(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.trim()))[..12].to_string()
+}
+
+/// ID of a question: the number of its first joined question ("56001154P"), or a hash of its
+/// heading.
+pub fn question_stable_id(dossier_ids: &[String], heading: &str) -> String {
+    dossier_ids
+        .iter()
+        .find_map(|id| id.strip_prefix('Q').filter(|number| !number.is_empty()))
+        .map(str::to_string)
+        .unwrap_or_else(|| content_id(heading))
+}
+
+/// The number of a roll-call vote, "3" for "Stemming 3", which also labels its lists of names
+/// ("Naamstemming: 3").
+pub fn vote_number(name: &str) -> String {
+    name.replace("Stemming ", "").trim().to_string()
+}
+
+/// ID of a roll-call vote: its number in the report, or a hash of its title when it has none.
+pub fn vote_stable_id(vote_number: &str, title_nl: &str) -> String {
+    if vote_number.is_empty() {
+        content_id(title_nl)
+    } else {
+        vote_number.to_string()
+    }
+}
+
+/// ID of a decision without a roll call: "d" and a hash of its kind, title and text.
+pub fn decision_stable_id(kind: &str, title_nl: &str, text: &str) -> String {
+    format!(
+        "d{}",
+        content_id(&format!("{}\n{}\n{}", kind, title_nl, text))
+    )
+}
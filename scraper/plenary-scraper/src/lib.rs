@@ -0,0 +1,8 @@
+pub mod agenda;
+pub mod attendance;
+pub mod decisions;
+pub mod documents;
+pub mod dossiers;
+pub mod ids;
+pub mod rows;
+pub mod votes;
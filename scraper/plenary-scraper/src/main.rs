@@ -1,31 +1,98 @@
-use arrow::array::{ArrayRef, RecordBatch, StringArray};
-use arrow::datatypes::{DataType, Field, Schema};
-use chrono::{Local, NaiveDate};
-use crawl::client::ScrapingClient;
+use chrono::{Local, NaiveDate, NaiveTime};
+use clap::Parser;
+use crawl::cache::{CachePolicy, CacheStatus, HttpCache};
+use crawl::client::{Page, ScrapingClient};
+use crawl::sessions::{SessionRange, read_progress, write_progress};
+use crawl::urls::Urls;
 use crawl::utils::clean_text;
-use encoding_rs::WINDOWS_1252;
+use crawl::utils::dates::{DayPart, parse_date, parse_time};
+use crawl::utils::debate::{resolve_speakers, split_turns};
+use crawl::utils::names::{MemberIndex, normalize_person_name};
+use futures::stream::{self, StreamExt};
 use http::StatusCode;
 use itertools::EitherOrBoth;
 use itertools::Itertools;
-use parquet::arrow::ArrowWriter;
+use model::{
+    Attendance, Ballot, Meeting, Member, Proposition, Question, Table, Vote, VoteCorrection,
+    VoteExplanation, VoteIssue,
+};
+use plenary_scraper::agenda::extract_agenda_rows;
+use plenary_scraper::attendance::{extract_excused, fill_attendance};
+use plenary_scraper::decisions::extract_decision_votes;
+use plenary_scraper::documents::extract_document_text;
+use plenary_scraper::dossiers::scrape_dossier;
+use plenary_scraper::ids::{RowIds, content_id, question_stable_id, vote_number, vote_stable_id};
+use plenary_scraper::rows::{MeetingRows, meeting_key};
+use plenary_scraper::votes::{
+    apply_vote_corrections, check_vote_tallies, extract_outcome, extract_vote_corrections,
+    extract_vote_data, flag_tally_mismatches,
+};
 use regex::Regex;
 use scraper::{ElementRef, Html, Selector};
-use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::error::Error;
 use std::fmt;
-use std::fs::{File, read_to_string};
-use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
 use tokio::fs;
-use tokio::fs::{create_dir_all, read_dir, remove_file, write};
+use tokio::task;
+
+/// Reports are still corrected for a while after they are published, so cached copies are
+/// revalidated monthly.
+const REPORT_MAX_AGE: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Scrapes the plenary meeting reports into the Parquet files under `web/src/data`.
+///
+/// By default only the reports that are new or changed since the last run are parsed, and their
+/// rows replace the rows of the same meetings in the existing files. Rows of sessions that are not
+/// scraped are kept.
+#[derive(Parser)]
+struct Args {
+    /// Sessions to scrape, e.g. "56" or "52..=56".
+    #[arg(long, default_value_t = SessionRange::current(), conflicts_with = "all_sessions")]
+    session: SessionRange,
+
+    /// Scrape every session with HTML reports.
+    #[arg(long)]
+    all_sessions: bool,
+
+    /// Parse every report of the sessions again instead of only the new and changed ones.
+    #[arg(long)]
+    full_rebuild: bool,
+
+    /// Do not fetch the PDFs of documents without extracted text, only keep the texts of earlier
+    /// runs.
+    #[arg(long)]
+    skip_documents: bool,
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    // Create client.
-    let client = ScrapingClient::new();
+    let args = Args::parse();
+    let sessions = match args.all_sessions {
+        true => SessionRange::all(),
+        false => args.session,
+    };
+    let unverified = sessions.unverified_sessions();
+    if !unverified.is_empty() {
+        eprintln!(
+            "The reports of session(s) {} may use a layout this scraper does not handle yet, \
+             check their rows before publishing them.",
+            unverified
+                .iter()
+                .map(|session_id| session_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
 
-    let session_id = 56;
+    // Create client.
+    let cache_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("data/cache");
+    let client = ScrapingClient::new().with_cache(HttpCache::new(cache_dir));
+    let urls = Urls::from_env();
 
     // Create folders.
     //let root = PathBuf::from("./web/src/data");
@@ -37,805 +104,538 @@ async fn main() -> Result<(), Box<dyn Error>> {
         .join("web/src/data");
     // println!("root = {:?}", root.canonicalize()?);
 
-    let meetings_path = root.join("meetings.parquet");
-    let votes_path = root.join("votes.parquet");
-    let questions_path = root.join("questions.parquet");
-    let propositions_path = root.join("propositions.parquet");
-    let dossiers_path = root.join("dossiers.parquet");
-    let subdocuments_path = root.join("subdocuments.parquet");
+    fs::create_dir_all(&root).await?;
+
+    // Check every session for new meetings.
+    let progress_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .join("progress");
+    let mut last_meeting_ids = BTreeMap::new();
+    for session_id in sessions.sessions() {
+        let current_meeting_id = read_progress(&progress_dir, "plenary", session_id)?;
+        let mut last_meeting_id = current_meeting_id;
+
+        loop {
+            let probe = last_meeting_id + 1;
+            let url = urls.plenary_report(session_id, probe);
+            // Through the cache, so that the reports are not downloaded again below.
+            let page = client
+                .fetch(&url, CachePolicy::MaxAge(REPORT_MAX_AGE))
+                .await?;
+
+            if page.status == StatusCode::NOT_FOUND {
+                break;
+            } else {
+                // found a new one, move forward
+                last_meeting_id = probe;
+            }
+        }
 
-    if let Some(parent) = meetings_path.parent() {
-        fs::create_dir_all(parent).await?;
+        if last_meeting_id == current_meeting_id {
+            println!("Session {}: no new meeting available.", session_id);
+        } else {
+            println!(
+                "Session {}: found new meetings up to {}",
+                session_id, last_meeting_id
+            );
+        }
+        last_meeting_ids.insert(session_id, last_meeting_id);
     }
-    if let Some(parent) = votes_path.parent() {
-        fs::create_dir_all(parent).await?;
+
+    // Rows of the meetings parsed by earlier runs.
+    let mut rows = MeetingRows::read(&root)?.unwrap_or_else(|| {
+        println!("Some tables are missing or outdated, parsing every meeting.");
+        MeetingRows::default()
+    });
+    if args.full_rebuild {
+        rows.retain_sessions(|session_id| !sessions.contains(session_id));
     }
-    if let Some(parent) = questions_path.parent() {
-        fs::create_dir_all(parent).await?;
+    let parsed_meetings: HashSet<(u32, u32)> = rows
+        .meetings
+        .iter()
+        .map(|meeting| meeting_key(&meeting.session_id, &meeting.meeting_id))
+        .collect();
+
+    // Fetch the reports concurrently and parse them on a pool of blocking workers, keeping the
+    // meeting order. A report that did not change since its meeting was parsed keeps its rows.
+    let workers = std::thread::available_parallelism().map_or(4, |workers| workers.get());
+    let meetings: Vec<(u32, u32)> = last_meeting_ids
+        .iter()
+        .flat_map(|(&session_id, &last_meeting_id)| {
+            (1..=last_meeting_id).map(move |meeting| (session_id, meeting))
+        })
+        .collect();
+    let requests = meetings.iter().map(|&(session_id, meeting)| {
+        (
+            urls.plenary_report(session_id, meeting),
+            CachePolicy::MaxAge(REPORT_MAX_AGE),
+        )
+    });
+    let parsed_meetings = &parsed_meetings;
+    let mut reports = client
+        .fetch_all(requests)
+        .zip(stream::iter(meetings.iter().copied()))
+        .map(|((_, page), (session_id, meeting))| async move {
+            let page = page?;
+            let unchanged = matches!(
+                page.cache_status,
+                CacheStatus::Fresh | CacheStatus::NotModified
+            );
+            if unchanged && parsed_meetings.contains(&(session_id, meeting)) {
+                return Ok(None);
+            }
+            let rows =
+                task::spawn_blocking(move || scrape_meeting(session_id, meeting, &page)).await?;
+            rows.map(Some).map_err(|error| error as Box<dyn Error>)
+        })
+        .buffered(workers);
+
+    let mut changed = MeetingRows::default();
+    while let Some(meeting_rows) = reports.next().await {
+        if let Some(meeting_rows) = meeting_rows? {
+            changed.append(meeting_rows);
+        }
     }
-    if let Some(parent) = propositions_path.parent() {
-        fs::create_dir_all(parent).await?;
+    println!(
+        "Parsed {} of {} meetings.",
+        changed.meetings.len(),
+        meetings.len()
+    );
+    rows.upsert(changed);
+
+    // Match the names on the ballots to members again, as members.parquet may have been updated
+    // since they were parsed.
+    let members_path = root.join(Member::FILE_NAME);
+    let members = match members_path.exists() {
+        true => Member::read(&members_path)?,
+        false => vec![],
+    };
+    let member_index = MemberIndex::new(members.iter().map(|member| {
+        (
+            member.session_id.as_str(),
+            member.member_id.as_str(),
+            member.first_name.as_str(),
+            member.last_name.as_str(),
+        )
+    }));
+    let unmatched = resolve_ballot_members(&mut rows.ballots, &member_index);
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} name(s) on ballots to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
     }
-    if let Some(parent) = dossiers_path.parent() {
-        fs::create_dir_all(parent).await?;
+    let unmatched = resolve_speakers(
+        rows.utterances
+            .iter_mut()
+            .filter(|utterance| utterance.role == "member")
+            .map(|utterance| {
+                (
+                    utterance.session_id.as_str(),
+                    utterance.speaker.as_str(),
+                    &mut utterance.member_id,
+                )
+            }),
+        &member_index,
+    );
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} speaker(s) to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
+    }
+    let unmatched = fill_attendance(&mut rows, &members, &member_index);
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} excused name(s) to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
     }
-    if let Some(parent) = subdocuments_path.parent() {
-        fs::create_dir_all(parent).await?;
+    let unmatched = resolve_speakers(
+        rows.vote_explanations.iter_mut().map(|explanation| {
+            (
+                explanation.session_id.as_str(),
+                explanation.speaker.as_str(),
+                &mut explanation.member_id,
+            )
+        }),
+        &member_index,
+    );
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} speaker(s) explaining their vote to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
+    }
+    let unmatched = apply_vote_corrections(&mut rows, &members, &member_index);
+    if !unmatched.is_empty() {
+        eprintln!(
+            "Could not match {} name(s) in vote corrections to a member: {}",
+            unmatched.len(),
+            unmatched.join(", ")
+        );
     }
 
-    // Create parquet files.
-    let meetings_file = File::create(meetings_path).unwrap();
-    let meeting_schema = Arc::new(Schema::new(vec![
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("meeting_id", DataType::Utf8, false),
-        Field::new("date", DataType::Utf8, false),
-        Field::new("time_of_day", DataType::Utf8, false),
-        Field::new("start_time", DataType::Utf8, false),
-        Field::new("end_time", DataType::Utf8, false),
-    ]));
-
-    let votes_file = File::create(votes_path).unwrap();
-    let votes_schema = Arc::new(Schema::new(vec![
-        Field::new("vote_id", DataType::Utf8, false),
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("meeting_id", DataType::Utf8, false),
-        Field::new("date", DataType::Utf8, false),
-        Field::new("title_nl", DataType::Utf8, false),
-        Field::new("title_fr", DataType::Utf8, false),
-        Field::new("yes", DataType::Utf8, false),
-        Field::new("no", DataType::Utf8, false),
-        Field::new("abstain", DataType::Utf8, false),
-        Field::new("members_yes", DataType::Utf8, false),
-        Field::new("members_no", DataType::Utf8, false),
-        Field::new("members_abstain", DataType::Utf8, false),
-        Field::new("dossier_id", DataType::Utf8, false),
-        Field::new("document_id", DataType::Utf8, false),
-        Field::new("motion_id", DataType::Utf8, false),
-    ]));
-
-    let questions_file = File::create(questions_path).unwrap();
-    let questions_schema = Arc::new(Schema::new(vec![
-        Field::new("question_id", DataType::Utf8, false),
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("meeting_id", DataType::Utf8, false),
-        Field::new("questioners", DataType::Utf8, false),
-        Field::new("respondents", DataType::Utf8, false),
-        Field::new("topics_nl", DataType::Utf8, false),
-        Field::new("topics_fr", DataType::Utf8, false),
-        Field::new("discussion", DataType::Utf8, false),
-        Field::new("dossier_ids", DataType::Utf8, false),
-    ]));
-
-    let propositions_file = File::create(propositions_path).unwrap();
-    let propositions_schema = Arc::new(Schema::new(vec![
-        Field::new("proposition_id", DataType::Utf8, false),
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("meeting_id", DataType::Utf8, false),
-        Field::new("title_nl", DataType::Utf8, false),
-        Field::new("title_fr", DataType::Utf8, false),
-        Field::new("dossier_id", DataType::Utf8, false),
-        Field::new("document_id", DataType::Utf8, false),
-    ]));
-
-    // Scrape data.
-    let mut meeting_ids = vec![];
-    let mut meeting_session_ids = vec![];
-    let mut dates = vec![];
-    let mut times_of_day = vec![];
-    let mut start_times = vec![];
-    let mut end_times = vec![];
-    let mut vote_ids = vec![];
-    let mut vote_session_ids = vec![];
-    let mut vote_meeting_ids = vec![];
-    let mut vote_dates = vec![];
-    let mut vote_titles_nl = vec![];
-    let mut vote_titles_fr = vec![];
-    let mut vote_yes = vec![];
-    let mut vote_no = vec![];
-    let mut vote_abstain = vec![];
-    let mut vote_members_yes = vec![];
-    let mut vote_members_no = vec![];
-    let mut vote_members_abstain = vec![];
-    let mut vote_dossier_ids = vec![];
-    let mut vote_document_ids = vec![];
-    let mut vote_motion_ids = vec![];
-    let mut question_ids = vec![];
-    let mut question_session_ids = vec![];
-    let mut question_meeting_ids = vec![];
-    let mut question_questioners = vec![];
-    let mut question_respondents = vec![];
-    let mut question_topics_nl = vec![];
-    let mut question_topics_fr = vec![];
-    let mut question_discussions = vec![];
-    let mut question_dossier_ids = vec![];
-    let mut proposition_ids = vec![];
-    let mut proposition_session_ids = vec![];
-    let mut proposition_meeting_ids = vec![];
-    let mut proposition_titles_nl = vec![];
-    let mut proposition_titles_fr = vec![];
-    let mut proposition_dossier_ids = vec![];
-    let mut proposition_document_ids = vec![];
-
-    let mut web_request_count = 0;
-    // println!("OK");
-    let meeting_id_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+    // The issues are not published, only the votes they concern are flagged.
+    let issues = check_vote_tallies(&rows.votes, &rows.ballots);
+    flag_tally_mismatches(&mut rows.votes, &issues);
+    let diagnostics_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
         .parent()
         .unwrap()
-        .join("current_plenary_id.txt");
-
-    let current_meeting_id: u32 = std::fs::read_to_string(&meeting_id_path)?.trim().parse()?;
-
-    // let current_meeting_id: u32 = std::fs::read_to_string("../../current_meeting_id.txt")?.trim().parse()?;
-    // println!("NOK");
-
-    let mut last_meeting_id = current_meeting_id;
-
-    loop {
-        let probe = last_meeting_id + 1;
-        let url = format!(
-            "https://www.dekamer.be/doc/PCRI/html/{}/ip{:03}x.html",
-            session_id, probe
+        .join("data/diagnostics");
+    fs::create_dir_all(&diagnostics_dir).await?;
+    let issues_path = diagnostics_dir.join(VoteIssue::FILE_NAME);
+    VoteIssue::write(&issues_path, &issues)?;
+    if !issues.is_empty() {
+        eprintln!(
+            "Found {} issue(s) with the tallies and names of roll-call votes, see {}: {}",
+            issues.len(),
+            issues_path.display(),
+            issues
+                .iter()
+                .map(|issue| match (&issue.name, issue.counted, issue.listed) {
+                    (Some(name), _, _) => format!(
+                        "{} of meeting {} vote {}: {} ({})",
+                        issue.kind, issue.meeting_id, issue.vote_id, name, issue.choice
+                    ),
+                    (None, counted, listed) => format!(
+                        "{} of meeting {} vote {}: {} {}, {} name(s)",
+                        issue.kind,
+                        issue.meeting_id,
+                        issue.vote_id,
+                        counted.unwrap_or_default(),
+                        issue.choice,
+                        listed.unwrap_or_default()
+                    ),
+                })
+                .join(", ")
         );
-        let resp = client.get(&url).await?;
-        web_request_count += 1;
-
-        if resp.status() == StatusCode::NOT_FOUND {
-            break;
-        } else {
-            // found a new one, move forward
-            last_meeting_id = probe;
-        }
     }
 
-    if last_meeting_id == current_meeting_id {
-        println!("No new meeting available.");
-    } else {
-        println!("Found new meetings up to {}", last_meeting_id);
-    }
+    rows.write(&root)?;
 
-    for meeting in 1..=last_meeting_id {
-        scrape_meeting(
-            &client,
-            56,
-            meeting,
-            &mut meeting_ids,
-            &mut meeting_session_ids,
-            &mut dates,
-            &mut times_of_day,
-            &mut start_times,
-            &mut end_times,
-            &mut vote_ids,
-            &mut vote_session_ids,
-            &mut vote_meeting_ids,
-            &mut vote_dates,
-            &mut vote_titles_nl,
-            &mut vote_titles_fr,
-            &mut vote_yes,
-            &mut vote_no,
-            &mut vote_abstain,
-            &mut vote_members_yes,
-            &mut vote_members_no,
-            &mut vote_members_abstain,
-            &mut vote_dossier_ids,
-            &mut vote_document_ids,
-            &mut vote_motion_ids,
-            &mut question_ids,
-            &mut question_session_ids,
-            &mut question_meeting_ids,
-            &mut question_questioners,
-            &mut question_respondents,
-            &mut question_discussions,
-            &mut question_topics_nl,
-            &mut question_topics_fr,
-            &mut question_dossier_ids,
-            &mut proposition_ids,
-            &mut proposition_session_ids,
-            &mut proposition_meeting_ids,
-            &mut proposition_titles_nl,
-            &mut proposition_titles_fr,
-            &mut proposition_dossier_ids,
-            &mut proposition_document_ids,
-            &mut web_request_count,
-        )
-        .await?;
+    for (&session_id, &last_meeting_id) in &last_meeting_ids {
+        write_progress(&progress_dir, "plenary", session_id, last_meeting_id)?;
     }
 
-    std::fs::write(&meeting_id_path, last_meeting_id.to_string())?;
-
-    let meetings_batch = RecordBatch::try_new(
-        meeting_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(meeting_session_ids)) as ArrayRef,
-            Arc::new(StringArray::from(meeting_ids)),
-            Arc::new(StringArray::from(dates)),
-            Arc::new(StringArray::from(times_of_day)),
-            Arc::new(StringArray::from(start_times)),
-            Arc::new(StringArray::from(end_times)),
-        ],
-    )?;
-
-    let mut meetings = ArrowWriter::try_new(meetings_file, meeting_schema, None)?;
-    meetings.write(&meetings_batch)?;
-    meetings.close().unwrap();
-
-    let votes_batch = RecordBatch::try_new(
-        votes_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(vote_ids)) as ArrayRef,
-            Arc::new(StringArray::from(vote_session_ids)),
-            Arc::new(StringArray::from(vote_meeting_ids)),
-            Arc::new(StringArray::from(vote_dates)),
-            Arc::new(StringArray::from(vote_titles_nl)),
-            Arc::new(StringArray::from(vote_titles_fr)),
-            Arc::new(StringArray::from(vote_yes)),
-            Arc::new(StringArray::from(vote_no)),
-            Arc::new(StringArray::from(vote_abstain)),
-            Arc::new(StringArray::from(vote_members_yes)),
-            Arc::new(StringArray::from(vote_members_no)),
-            Arc::new(StringArray::from(vote_members_abstain)),
-            Arc::new(StringArray::from(vote_dossier_ids)),
-            Arc::new(StringArray::from(vote_document_ids)),
-            Arc::new(StringArray::from(vote_motion_ids)),
-        ],
-    )?;
-
-    let mut votes = ArrowWriter::try_new(votes_file, votes_schema, None)?;
-    votes.write(&votes_batch)?;
-    votes.close().unwrap();
-
-    let questions_batch = RecordBatch::try_new(
-        questions_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(question_ids)) as ArrayRef,
-            Arc::new(StringArray::from(question_session_ids)),
-            Arc::new(StringArray::from(question_meeting_ids)),
-            Arc::new(StringArray::from(question_questioners)),
-            Arc::new(StringArray::from(question_respondents)),
-            Arc::new(StringArray::from(question_topics_nl)),
-            Arc::new(StringArray::from(question_topics_fr)),
-            Arc::new(StringArray::from(question_discussions)),
-            Arc::new(StringArray::from(question_dossier_ids)),
-        ],
-    )?;
+    // Dossiers referenced by votes and propositions, with the date of the last meeting that refers
+    // to them.
+    let meeting_dates: HashMap<(&String, &String), NaiveDate> = rows
+        .meetings
+        .iter()
+        .map(|meeting| ((&meeting.session_id, &meeting.meeting_id), meeting.date))
+        .collect();
+    let mut referenced_dossiers: BTreeMap<(u32, String), NaiveDate> = BTreeMap::new();
+    for (session_id, dossier_id, meeting_id) in rows
+        .votes
+        .iter()
+        .map(|vote| (&vote.session_id, &vote.dossier_id, &vote.meeting_id))
+        .chain(rows.propositions.iter().map(|proposition| {
+            (
+                &proposition.session_id,
+                &proposition.dossier_id,
+                &proposition.meeting_id,
+            )
+        }))
+        .filter_map(|(session_id, dossier_id, meeting_id)| {
+            dossier_id
+                .as_ref()
+                .map(|dossier_id| (session_id, dossier_id, meeting_id))
+        })
+    {
+        let date = meeting_dates[&(session_id, meeting_id)];
+        let key = (session_id.parse()?, dossier_id.clone());
+        let latest = referenced_dossiers.entry(key).or_insert(date);
+        *latest = date.max(*latest);
+    }
 
-    let mut questions = ArrowWriter::try_new(questions_file, questions_schema, None)?;
-    questions.write(&questions_batch)?;
-    questions.close().unwrap();
-
-    let propositions_batch = RecordBatch::try_new(
-        propositions_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(proposition_ids)) as ArrayRef,
-            Arc::new(StringArray::from(proposition_session_ids)),
-            Arc::new(StringArray::from(proposition_meeting_ids)),
-            Arc::new(StringArray::from(proposition_titles_nl)),
-            Arc::new(StringArray::from(proposition_titles_fr)),
-            Arc::new(StringArray::from(proposition_dossier_ids)),
-            Arc::new(StringArray::from(proposition_document_ids)),
-        ],
-    )?;
+    // Scrape dossiers.
+    let mut dossiers = vec![];
+    let mut subdocuments = vec![];
+    let mut dossier_events = vec![];
+    let mut dossier_keywords = vec![];
+    let mut unmapped_types = BTreeSet::new();
+    // (session_id, dossier_id, document number, path of the PDF)
+    let mut documents = vec![];
+
+    // A cached dossier page is refreshed when it was last validated before the last meeting that
+    // refers to it.
+    let requests: Vec<_> = referenced_dossiers
+        .iter()
+        .map(|((session_id, dossier_id), date)| {
+            (
+                urls.dossier(*session_id, dossier_id),
+                CachePolicy::RefreshIfOlderThan(start_of_day(*date)),
+            )
+        })
+        .collect();
+    let mut pages = client
+        .fetch_all(requests)
+        .zip(stream::iter(referenced_dossiers.into_keys()));
+    while let Some(((url, page), (session_id, dossier_id))) = pages.next().await {
+        let page = page?;
+        let document = Html::parse_document(&page.decode_logged().text);
+        // A page without the expected tables, such as an error page, only loses that dossier.
+        let dossier = match scrape_dossier(dossier_id.as_str(), &document) {
+            Ok(dossier) => dossier,
+            Err(error) => {
+                eprintln!("{}: skipping dossier {}: {}", url, dossier_id, error);
+                continue;
+            }
+        };
 
-    let mut propositions = ArrowWriter::try_new(propositions_file, propositions_schema, None)?;
-    propositions.write(&propositions_batch)?;
-    propositions.close().unwrap();
+        dossiers.push(model::Dossier {
+            session_id: session_id.to_string(),
+            id: dossier_id.clone(),
+            title: dossier.title,
+            authors: dossier.authors,
+            submission_date: parse_dossier_date(&dossier.submission_date),
+            end_date: parse_dossier_date(&dossier.end_date),
+            vote_date: parse_dossier_date(&dossier.vote_date),
+            document_type: type_name(dossier.document_type, &mut unmapped_types),
+            status: type_name(dossier.status, &mut unmapped_types),
+            committee: dossier.committee,
+            procedure: dossier.procedure.map(str::to_string),
+        });
+
+        // The steps in the order they happened, those on the same day in the order of the page.
+        let mut events: Vec<_> = dossier
+            .events
+            .into_iter()
+            .filter_map(|event| {
+                Some(model::DossierEvent {
+                    session_id: session_id.to_string(),
+                    dossier_id: dossier_id.clone(),
+                    date: parse_dossier_date(&event.date)?,
+                    kind: event.kind.to_string(),
+                    stage: event.stage.map(str::to_string),
+                    committee: event.committee,
+                    description: event.description,
+                    document_id: event.document_id,
+                })
+            })
+            .collect();
+        events.sort_by_key(|event| event.date);
+        dossier_events.extend(events);
+        for (kind, keyword) in dossier.keywords {
+            dossier_keywords.push(model::DossierKeyword {
+                session_id: session_id.to_string(),
+                dossier_id: dossier_id.clone(),
+                keyword,
+                kind: kind.to_string(),
+            });
+        }
 
-    // Scrape dossiers.
-    let mut dossier_session_ids = vec![];
-    let mut dossier_ids = vec![];
-    let mut dossier_titles = vec![];
-    let mut dossier_authors = vec![];
-    let mut dossier_submission_dates = vec![];
-    let mut dossier_end_dates = vec![];
-    let mut dossier_vote_dates = vec![];
-    let mut dossier_document_types = vec![];
-    let mut dossier_statuses = vec![];
-
-    let mut subdocument_dossier_ids = vec![];
-    let mut subdocument_ids = vec![];
-    let mut subdocument_dates = vec![];
-    let mut subdocument_types = vec![];
-    let mut subdocument_authors = vec![];
-
-    let dossier_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
-    let foldername = format!("data/sources/sessions/{}/dossiers", session_id);
-    let dossier_dir = dossier_root.join(foldername);
-
-    //  let dossier_dir = "scraper/data/sources/dossiers";
-    let mut entries = read_dir(dossier_dir).await?;
-    while let Some(entry) = entries.next_entry().await? {
-        let path = entry.path();
-        if path.extension().and_then(|s| s.to_str()) == Some("html") {
-            let path_str = path.to_string_lossy().to_string();
-
-            let dossier_id = path
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .and_then(|s| s.split('_').nth(1)) // get the second part
-                .unwrap_or("")
-                .to_string();
+        for (number, path) in dossier.documents {
+            documents.push((session_id.to_string(), dossier_id.clone(), number, path));
+        }
 
-            let content = match read_to_string(&path_str) {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!("Failed to read {}: {}", path_str, e);
-                    continue;
-                }
-            };
-            let document = Html::parse_document(&content);
-            let dossier = scrape_dossier(dossier_id.as_str(), &document).await?;
-
-            dossier_session_ids.push(session_id.to_string());
-            dossier_ids.push(dossier_id);
-            dossier_titles.push(dossier.title);
-            dossier_authors.push(dossier.authors.join(",").to_string());
-            dossier_submission_dates.push(dossier.submission_date);
-            dossier_end_dates.push(dossier.end_date);
-            dossier_vote_dates.push(dossier.vote_date);
-            dossier_document_types.push(dossier.document_type.to_string());
-            dossier_statuses.push(dossier.status.to_string());
-
-            // Parse subdocuments.
-            for subdocument in dossier.subdocuments {
-                subdocument_dossier_ids.push(subdocument.dossier_id);
-                subdocument_ids.push(subdocument.id);
-                subdocument_dates.push(subdocument.date);
-                subdocument_types.push(subdocument.document_type.to_string());
-                subdocument_authors.push(subdocument.authors.join(",").to_string());
-            }
+        // Parse subdocuments.
+        for subdocument in dossier.subdocuments {
+            subdocuments.push(model::Subdocument {
+                dossier_id: subdocument.dossier_id,
+                id: subdocument.id,
+                date: parse_dossier_date(&subdocument.date),
+                document_type: type_name(subdocument.document_type, &mut unmapped_types),
+                authors: subdocument.authors,
+                session_id: session_id.to_string(),
+            });
         }
     }
 
-    let subdocuments_file = File::create(subdocuments_path).unwrap();
-    let subdocuments_schema = Arc::new(Schema::new(vec![
-        Field::new("dossier_id", DataType::Utf8, false),
-        Field::new("id", DataType::Utf8, false),
-        Field::new("date", DataType::Utf8, false),
-        Field::new("type", DataType::Utf8, false),
-        Field::new("authors", DataType::Utf8, false),
-    ]));
-    let subdocuments_batch = RecordBatch::try_new(
-        subdocuments_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(subdocument_dossier_ids)),
-            Arc::new(StringArray::from(subdocument_ids)),
-            Arc::new(StringArray::from(subdocument_dates)),
-            Arc::new(StringArray::from(subdocument_types)),
-            Arc::new(StringArray::from(subdocument_authors)),
-        ],
-    )?;
-    let mut subdocuments = ArrowWriter::try_new(subdocuments_file, subdocuments_schema, None)?;
-    subdocuments.write(&subdocuments_batch)?;
-    subdocuments.close().unwrap();
-
-    let dossiers_file = File::create(dossiers_path).unwrap();
-    let dossiers_schema = Arc::new(Schema::new(vec![
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("id", DataType::Utf8, false),
-        Field::new("title", DataType::Utf8, false),
-        Field::new("authors", DataType::Utf8, false),
-        Field::new("submission_date", DataType::Utf8, false),
-        Field::new("end_date", DataType::Utf8, false),
-        Field::new("vote_date", DataType::Utf8, false),
-        Field::new("document_type", DataType::Utf8, false),
-        Field::new("status", DataType::Utf8, false),
-    ]));
-    let dossiers_batch = RecordBatch::try_new(
-        dossiers_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(dossier_session_ids)),
-            Arc::new(StringArray::from(dossier_ids)),
-            Arc::new(StringArray::from(dossier_titles)),
-            Arc::new(StringArray::from(dossier_authors)),
-            Arc::new(StringArray::from(dossier_submission_dates)),
-            Arc::new(StringArray::from(dossier_end_dates)),
-            Arc::new(StringArray::from(dossier_vote_dates)),
-            Arc::new(StringArray::from(dossier_document_types)),
-            Arc::new(StringArray::from(dossier_statuses)),
-        ],
+    model::Subdocument::write(&root.join(model::Subdocument::FILE_NAME), &subdocuments)?;
+    model::Dossier::write(&root.join(model::Dossier::FILE_NAME), &dossiers)?;
+    model::DossierEvent::write(&root.join(model::DossierEvent::FILE_NAME), &dossier_events)?;
+    model::DossierKeyword::write(
+        &root.join(model::DossierKeyword::FILE_NAME),
+        &dossier_keywords,
     )?;
-    let mut dossiers = ArrowWriter::try_new(dossiers_file, dossiers_schema, None)?;
-    dossiers.write(&dossiers_batch)?;
-    dossiers.close().unwrap();
-
-    println!("Scraped data using {} web request(s).", web_request_count);
-
-    Ok(())
-}
-
-#[derive(Debug, Clone, Copy)]
-enum DocumentStatus {
-    ZonderVoorwerp, // 14
-    Verworpen,      // 15
-    Aangenomen,     // 16
-    Onbekend,
-}
-
-impl fmt::Display for DocumentStatus {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+    if !unmapped_types.is_empty() {
+        eprintln!(
+            "Could not map {} type(s) or status(es) on dossier pages to a known one: {}",
+            unmapped_types.len(),
+            unmapped_types.iter().join(", ")
+        );
     }
-}
-
-#[derive(Debug, Clone, Copy)]
-enum DocumentType {
-    AangenomenTekst,
-    Amendement,
-    Advies,
-    AdviesVanDeRaadVanState,
-    Verslag,
-    WetsOntwerp,                 // 02
-    OvergezondenOntwerp,         // 03
-    WetsVoorstel,                // 05
-    VoorstelVanResolutie,        // 06
-    VoorstelTotHerziening,       // 08
-    VoorstelOnderzoekscommissie, // 20
-    VoorstelReglement,           // 21
-    ArtikelenBijEersteStemmingAangenomen,
-    Unknown,
-}
 
-impl fmt::Display for DocumentType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+    // Extract the text of the PDFs of the documents. A published document does not change, so only
+    // the documents without text from an earlier run are extracted, from the cache when it has the
+    // PDF.
+    let texts_path = root.join(model::DocumentText::FILE_NAME);
+    let mut texts: HashMap<(String, String, String), model::DocumentText> =
+        match texts_path.exists() && model::schema_version(&texts_path)? == model::SCHEMA_VERSION {
+            true => model::DocumentText::read(&texts_path)?
+                .into_iter()
+                .map(|text| {
+                    (
+                        (
+                            text.session_id.clone(),
+                            text.dossier_id.clone(),
+                            text.document_id.clone(),
+                        ),
+                        text,
+                    )
+                })
+                .collect(),
+            false => HashMap::new(),
+        };
+    let missing: Vec<_> = documents
+        .iter()
+        .filter(|&(session_id, dossier_id, number, _)| {
+            !args.skip_documents
+                && !texts.contains_key(&(session_id.clone(), dossier_id.clone(), number.clone()))
+        })
+        .map(
+            |(session_id, dossier_id, number, path)| model::DocumentText {
+                session_id: session_id.clone(),
+                dossier_id: dossier_id.clone(),
+                document_id: number.clone(),
+                url: path.clone(),
+                ..Default::default()
+            },
+        )
+        .collect();
+    let requests: Vec<_> = missing
+        .iter()
+        .map(|text| (urls.dekamer_path(&text.url), CachePolicy::Forever))
+        .collect();
+    let mut extracted = client
+        .fetch_all(requests)
+        .zip(stream::iter(missing))
+        .map(|((url, page), text)| async move {
+            let pdf = match page {
+                Ok(page) if page.status.is_success() => page.body,
+                Ok(page) => return Err(format!("{} ({})", url, page.status)),
+                Err(error) => return Err(error.to_string()),
+            };
+            match task::spawn_blocking(move || extract_document_text(text, &pdf)).await {
+                Ok(Ok(text)) => Ok(text),
+                Ok(Err(error)) => Err(format!("{} ({})", url, error)),
+                Err(error) => Err(format!("{} ({})", url, error)),
+            }
+        })
+        .buffered(workers);
+    let mut failed = vec![];
+    let mut extracted_count = 0;
+    while let Some(text) = extracted.next().await {
+        match text {
+            Ok(text) => {
+                let key = (
+                    text.session_id.clone(),
+                    text.dossier_id.clone(),
+                    text.document_id.clone(),
+                );
+                texts.insert(key, text);
+                extracted_count += 1;
+            }
+            Err(error) => failed.push(error),
+        }
     }
-}
+    println!("Extracted the text of {} new documents.", extracted_count);
+    if !failed.is_empty() {
+        eprintln!(
+            "Could not extract the text of {} document(s): {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
+    // The documents in the order of the dossiers, without those no dossier links to anymore.
+    let document_texts: Vec<_> = documents
+        .into_iter()
+        .filter_map(|(session_id, dossier_id, number, _)| {
+            texts.remove(&(session_id, dossier_id, number))
+        })
+        .collect();
+    model::DocumentText::write(&texts_path, &document_texts)?;
 
-struct Subdocument {
-    dossier_id: String,
-    id: String,
-    document_type: DocumentType,
-    date: String,
-    authors: Vec<String>,
-}
+    println!("Scraped data using {}.", client.stats());
 
-struct Dossier {
-    title: String,
-    authors: Vec<String>,
-    submission_date: String,
-    end_date: String,
-    vote_date: String,
-    document_type: DocumentType,
-    status: DocumentStatus,
-    subdocuments: Vec<Subdocument>,
+    Ok(())
 }
 
-fn parse_status(raw: &str) -> DocumentStatus {
-    let raw = raw.trim().to_lowercase();
-    if raw.contains("aangenomen") {
-        DocumentStatus::Aangenomen
-    } else if raw.contains("verworpen") {
-        DocumentStatus::Verworpen
-    } else if raw.contains("zonder voorwerp") {
-        DocumentStatus::ZonderVoorwerp
-    } else {
-        DocumentStatus::Onbekend
-    }
+/// The name of a type or status parsed from a dossier page, or the text on the page when it is not
+/// a known one, which is then added to `unmapped`.
+fn type_name<T: fmt::Display>(
+    parsed: Result<T, String>,
+    unmapped: &mut BTreeSet<String>,
+) -> String {
+    parsed
+        .map(|value| value.to_string())
+        .unwrap_or_else(|text| {
+            unmapped.insert(text.clone());
+            text
+        })
 }
 
-fn parse_document_type(raw: &str) -> DocumentType {
-    let raw = raw.trim().to_lowercase();
-    if raw.contains("voorstel van resolutie") {
-        DocumentType::VoorstelVanResolutie
-    } else if raw.contains("amendement") {
-        DocumentType::Amendement
-    } else if raw.contains("voorstel tot herziening") {
-        DocumentType::VoorstelTotHerziening
-    } else if raw.contains("wetsvoorstel") {
-        DocumentType::WetsVoorstel
-    } else if raw.contains("wetsontwerp") {
-        DocumentType::WetsOntwerp
-    } else if raw.contains("overgezonden ontwerp") {
-        DocumentType::OvergezondenOntwerp
-    } else if raw.contains("verslag") {
-        DocumentType::Verslag
-    } else if raw.contains("advies van de raad van state") {
-        DocumentType::AdviesVanDeRaadVanState
-    } else if raw.contains("advies") {
-        DocumentType::Advies
-    } else if raw.contains("voorstel onderzoekscommissie") {
-        DocumentType::VoorstelOnderzoekscommissie
-    } else if raw.contains("voorstel reglement") {
-        DocumentType::VoorstelReglement
-    } else if raw.contains("artikelen bij 1e stemming aangenomen") {
-        DocumentType::ArtikelenBijEersteStemmingAangenomen
-    } else if raw.contains("aangenomen tekst") {
-        DocumentType::AangenomenTekst
-    } else {
-        DocumentType::Unknown
-    }
+/// Parses the dates of dossier pages, e.g. "03/10/2025".
+fn parse_dossier_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%d/%m/%Y").ok()
 }
 
-async fn scrape_dossier(dossier_id: &str, document: &Html) -> Result<Dossier, Box<dyn Error>> {
-    // Selectors.
-    let title_selector = Selector::parse("#story h4 center").unwrap();
-    let cell_selector = Selector::parse("td").unwrap();
-
-    let title = document
-        .select(&title_selector)
-        .next()
-        .and_then(|el| el.text().next())
-        .unwrap_or("")
-        .trim()
-        .to_string();
-    let mut submission_date = String::new();
-    let mut vote_date = String::new();
-    let mut end_date = String::new();
-    let mut dossier_authors = Vec::new();
-    let mut document_type = DocumentType::Unknown;
-    let mut status = DocumentStatus::Onbekend;
-    let mut subdocuments = Vec::new();
-
-    let document_table = document
-        .select(&Selector::parse("table").unwrap())
-        .next()
-        .unwrap();
-
-    if let Some(tbody) = document_table
-        .select(&Selector::parse("tbody").unwrap())
-        .next()
-    {
-        for (_i, row) in document_table
-            .select(&Selector::parse("tr").unwrap())
-            .enumerate()
-        {
-            if row.parent().unwrap() == *tbody {
-                let mut cells = row.select(&cell_selector);
-                let cell_1 = cells.next();
-                let cell_2 = cells.next();
-
-                if let (Some(cell_1), Some(cell_2)) = (cell_1, cell_2) {
-                    let label = cell_1
-                        .text()
-                        .collect::<String>()
-                        .to_lowercase()
-                        .trim()
-                        .to_string();
-                    let value = cell_2
-                        .text()
-                        .collect::<String>()
-                        .to_lowercase()
-                        .trim()
-                        .to_string();
-
-                    // Submission date.
-                    if label.contains("indieningsdatum") {
-                        submission_date = value;
-                    }
-                    // Vote date.
-                    else if label.contains("stemming kamer") {
-                        vote_date = value;
-                    }
-                    // End date.
-                    else if label.contains("einddatum") {
-                        end_date = value;
-                    }
-                    // Author section start
-                    else if label.contains("auteur(s)") {
-                        for link in cell_2.select(&Selector::parse("a").unwrap()) {
-                            if let Some(name_raw) = link.text().next() {
-                                // Optional: reformat "Last, First" to "First Last"
-                                let name_clean = name_raw.trim();
-                                let name = name_clean.replace(",", ""); // or better: custom logic if order matters
-                                dossier_authors.push(name);
-                            }
-                        }
-
-                        // Fallback: if no links found, try raw text
-                        if dossier_authors.is_empty() {
-                            for text_node in cell_2.text() {
-                                let name = text_node.trim();
-                                if !name.is_empty() {
-                                    dossier_authors.push(name.to_string());
-                                }
-                            }
-                        }
-                    }
-                    // Type.
-                    else if label.contains("document type") {
-                        document_type = parse_document_type(&value);
-                    }
-                    // Status.
-                    else if label.contains("status") {
-                        status = parse_status(&value);
-                    }
-                    // Subdocuments.
-                    else if label.contains("subdocumenten") {
-                        let subdocument_table = cell_2
-                            .select(&Selector::parse("table").unwrap())
-                            .next()
-                            .unwrap();
-
-                        let mut document_id = String::new();
-                        let mut document_type: DocumentType = DocumentType::Unknown;
-                        let mut document_date = String::new();
-                        let mut document_authors: Vec<String> = Vec::new();
-
-                        let mut parsing_authors = false;
-                        let mut complete_subdocument = false;
-
-                        for (_i, row) in subdocument_table
-                            .select(&Selector::parse("tr").unwrap())
-                            .enumerate()
-                        {
-                            let mut cells = row.select(&cell_selector);
-                            let cell_1 = cells.next();
-                            let cell_2 = cells.next();
-
-                            // If cell 2 is empty, that's the end of the subdocument.
-                            if cell_2.is_none() {
-                                if complete_subdocument {
-                                    subdocuments.push(Subdocument {
-                                        dossier_id: dossier_id.to_string(),
-                                        id: document_id.clone(),
-                                        document_type: document_type.clone(),
-                                        date: document_date.to_string(),
-                                        authors: document_authors.clone(),
-                                    });
-
-                                    // Reset all fields for next subdocument
-                                    document_id.clear();
-                                    document_date.clear();
-                                    document_authors.clear();
-                                    complete_subdocument = false;
-                                    parsing_authors = false;
-                                }
-                                continue;
-                            }
-
-                            if let (Some(cell_1), Some(cell_2)) = (cell_1, cell_2) {
-                                let label = cell_1
-                                    .text()
-                                    .collect::<String>()
-                                    .to_lowercase()
-                                    .trim()
-                                    .to_string();
-                                let value = cell_2
-                                    .text()
-                                    .collect::<String>()
-                                    .to_lowercase()
-                                    .trim()
-                                    .to_string();
-
-                                // Document id.
-                                if let Some(link) =
-                                    cell_1.select(&Selector::parse("a").unwrap()).last()
-                                {
-                                    if let Some(id_text) = link.text().next() {
-                                        document_id = id_text.trim().to_string();
-                                    }
-                                }
-
-                                // Document type.
-                                if let Some(font) =
-                                    cell_2.select(&Selector::parse("font").unwrap()).next()
-                                {
-                                    let type_text =
-                                        font.text().collect::<String>().trim().to_string();
-                                    document_type = parse_document_type(&type_text);
-                                }
-
-                                // Date.
-                                if label.contains("datum ronddeling") {
-                                    document_date = value;
-                                }
-
-                                // Author section start
-                                if label.contains("auteur(s)") {
-                                    parsing_authors = true;
-                                }
-
-                                // Collect subdocument author if in author mode
-                                if parsing_authors {
-                                    if let Some(link) =
-                                        cell_2.select(&Selector::parse("a").unwrap()).next()
-                                    {
-                                        if let Some(name) = link.text().next() {
-                                            document_authors
-                                                .push(name.replace(",", "").trim().to_string());
-                                        }
-                                    }
-                                }
-
-                                // Ready to commit subdocument when key fields are filled
-                                if !document_id.is_empty() && !document_date.is_empty() {
-                                    complete_subdocument = true;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+/// Sets the `member_id` of every ballot to the member of its session with that name, and returns
+/// the names that match no member.
+fn resolve_ballot_members(ballots: &mut [Ballot], members: &MemberIndex) -> Vec<String> {
+    let mut unmatched = BTreeSet::new();
+    for ballot in ballots {
+        ballot.member_id = members
+            .find(&ballot.session_id, &ballot.name)
+            .map(str::to_string);
+        if ballot.member_id.is_none() {
+            unmatched.insert(ballot.name.clone());
         }
     }
+    unmatched.into_iter().collect()
+}
 
-    Ok(Dossier {
-        title,
-        authors: dossier_authors,
-        submission_date,
-        end_date,
-        vote_date,
-        document_type,
-        status,
-        subdocuments,
-    })
+/// Adds the question with the headings `heading_nl` and `heading_fr` and returns its ID. `position`
+/// is the ID it had when questions were numbered by their position, `None` for the questions that
+/// were not parsed then. Its debate is in the utterances of its agenda item.
+fn push_question(
+    rows: &mut MeetingRows,
+    ids: &mut RowIds,
+    position: Option<i32>,
+    typo_map: &HashMap<String, String>,
+    heading_nl: &str,
+    heading_fr: &str,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
+    let question_nl = extract_question_data(typo_map.clone(), heading_nl.to_string())?;
+    let question_fr = extract_question_data(typo_map.clone(), heading_fr.to_string())?;
+
+    let stable_id = question_stable_id(&question_nl.dossier_ids, heading_nl);
+    let stable_id = match position {
+        Some(position) => ids.assign("question", position.to_string(), stable_id),
+        None => ids.unique("question", stable_id),
+    };
+    rows.questions.push(Question {
+        question_id: stable_id.clone(),
+        session_id: ids.session_id.clone(),
+        meeting_id: ids.meeting_id.clone(),
+        questioners: question_nl.questioners, // NL used here
+        respondents: question_nl.respondents,
+        topics_nl: question_nl.topics,
+        topics_fr: question_fr.topics,
+        dossier_ids: question_nl.dossier_ids,
+    });
+    Ok(stable_id)
 }
 
-async fn scrape_meeting(
-    client: &ScrapingClient,
+/// Parses a plenary meeting report. Runs on a blocking worker, so it does not fetch anything
+/// itself.
+fn scrape_meeting(
     session_id: u32,
     meeting_id: u32,
-    meeting_ids: &mut Vec<String>,
-    meeting_session_ids: &mut Vec<String>,
-    dates: &mut Vec<String>,
-    times_of_day: &mut Vec<String>,
-    start_times: &mut Vec<String>,
-    end_times: &mut Vec<String>,
-    vote_ids: &mut Vec<String>,
-    vote_session_ids: &mut Vec<String>,
-    vote_meeting_ids: &mut Vec<String>,
-    vote_dates: &mut Vec<String>,
-    vote_titles_nl: &mut Vec<String>,
-    vote_titles_fr: &mut Vec<String>,
-    vote_yes: &mut Vec<String>,
-    vote_no: &mut Vec<String>,
-    vote_abstain: &mut Vec<String>,
-    vote_members_yes: &mut Vec<String>,
-    vote_members_no: &mut Vec<String>,
-    vote_members_abstain: &mut Vec<String>,
-    vote_dossier_ids: &mut Vec<String>,
-    vote_document_ids: &mut Vec<String>,
-    vote_motion_ids: &mut Vec<String>,
-    question_ids: &mut Vec<String>,
-    question_session_ids: &mut Vec<String>,
-    question_meeting_ids: &mut Vec<String>,
-    question_questioners: &mut Vec<String>,
-    question_respondents: &mut Vec<String>,
-    question_discussions: &mut Vec<String>,
-    question_topics_nl: &mut Vec<String>,
-    question_topics_fr: &mut Vec<String>,
-    question_dossier_ids: &mut Vec<String>,
-    proposition_ids: &mut Vec<String>,
-    proposition_session_ids: &mut Vec<String>,
-    proposition_meeting_ids: &mut Vec<String>,
-    proposition_titles_nl: &mut Vec<String>,
-    proposition_titles_fr: &mut Vec<String>,
-    proposition_dossier_ids: &mut Vec<String>,
-    proposition_document_ids: &mut Vec<String>,
-    web_request_count: &mut u32,
-) -> Result<(), Box<dyn Error>> {
-    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
-    let filename = format!(
-        "data/sources/sessions/{}/meetings/plenary/{}-{}.html",
-        session_id, session_id, meeting_id
-    );
-    let filepath = root.join(filename);
-
-    // Check if file already exists.
-    //  let filename = format!("scraper/data/sources/meetings/{}-{}.html", session_id, meeting_id);
-    // let filepath = Path::new(&filename);
+    page: &Page,
+) -> Result<MeetingRows, Box<dyn Error + Send + Sync>> {
+    let mut rows = MeetingRows::default();
+    let mut ids = RowIds::new(session_id, meeting_id);
 
-    // Download meeting html if it does not exist yet.
-    if !filepath.exists() {
-        let url = format!(
-            "https://www.dekamer.be/doc/PCRI/html/{}/ip{:03}x.html",
-            session_id, meeting_id
-        );
-
-        // Fetch and parse the HTML content.
-        let response = client.get(&url).await?;
-        *web_request_count += 1;
-        let raw_bytes = response.bytes().await?;
-        let (decoded_str, _, _) = WINDOWS_1252.decode(&raw_bytes); // Decode data using windows_1252 (see meta tag of site <meta http-equiv="Content-Type" content="text/html; charset=windows-1252">)
-        let content = decoded_str.to_string();
-        if let Some(parent) = filepath.parent() {
-            std::fs::create_dir_all(parent).unwrap();
-        }
-        std::fs::write(&filepath, content).unwrap();
-    }
-
-    // Fetch and parse the HTML content.
-    let content = read_to_string(filepath)?;
-    let document = Html::parse_document(&content);
+    // Parse the HTML content.
+    let document = Html::parse_document(&page.decode_logged().text);
 
     // Extract meeting data.
     let date = extract_date_from_document(&document)?;
@@ -860,11 +660,20 @@ async fn scrape_meeting(
     // Extract questions.
     let mut previous_question_nl = String::new();
     let mut previous_question_fr = String::new();
-    let mut previous_discussion = String::new();
     let mut question_id: i32 = 0;
-    let mut found_questions = false;
     let mut processing_questions = false;
     // let mut collecting_subquestions = false;
+    // Some reports start with the questions, before any section heading.
+    let mut questions_section = true;
+    // Only the questions of the first questions section were parsed when questions were numbered by
+    // their position.
+    let mut in_first_questions_section = false;
+    let mut after_first_questions_section = false;
+    // The agenda items are linked to their question by the number of the item.
+    let item_regex = Regex::new(r"^(\d+)\s+(.*)$").unwrap();
+    let mut current_item = String::new();
+    let mut previous_question_item = String::new();
+    let mut question_items: HashMap<String, String> = HashMap::new();
 
     for element in document.select(&Selector::parse("h1, h2, p").unwrap()) {
         let tag_name = element.value().name();
@@ -878,62 +687,59 @@ async fn scrape_meeting(
                 .trim()
                 .to_string();
 
-            // Detect start of questions section.
-            if text.contains("Mondelinge vragen")
+            // Detect start of questions section. Questions can continue in a later section, after
+            // the votes.
+            let is_question_header = text.contains("Mondelinge vragen")
                 || text.contains("Vragen")
                 || text.contains("Questions orales")
-                || text.contains("Questions")
+                || text.contains("Questions");
+
+            // Save the grouped question.
+            if processing_questions
+                && !is_question_header
+                && (!previous_question_nl.is_empty() || !previous_question_fr.is_empty())
             {
-                found_questions = true;
-                processing_questions = true;
-            } else if found_questions {
-                // Avoid breaking on the second question header (which is just a translation).
-                let is_question_header = text.contains("Mondelinge vragen")
-                    || text.contains("Vragen")
-                    || text.contains("Questions orales")
-                    || text.contains("Questions");
-
-                if !is_question_header {
-                    // Save the grouped question.
-                    if !previous_question_nl.is_empty() || !previous_question_fr.is_empty() {
-                        let question_nl = extract_question_data(
-                            typo_map.clone(),
-                            previous_question_nl.clone(),
-                            previous_discussion.clone(),
-                        )
-                        .await?;
-                        let question_fr = extract_question_data(
-                            typo_map.clone(),
-                            previous_question_fr.clone(),
-                            previous_discussion.clone(),
-                        )
-                        .await?;
-
-                        question_ids.push(question_id.to_string());
-                        question_meeting_ids.push(meeting_id.to_string());
-                        question_session_ids.push(session_id.to_string());
-                        question_discussions.push(question_nl.discussion.clone());
-                        question_questioners.push(question_nl.questioners.join(","));
-                        question_respondents.push(question_nl.respondents.join(","));
-                        question_topics_nl.push(question_nl.topics.join(";"));
-                        question_topics_fr.push(question_fr.topics.join(";"));
-                        question_dossier_ids.push(question_nl.dossier_ids.join(","));
-                        // question_id += 1;
-                        previous_discussion.clear();
-                        previous_question_nl.clear();
-                        previous_question_fr.clear();
-                    }
-                    break;
-                }
+                let stable_id = push_question(
+                    &mut rows,
+                    &mut ids,
+                    in_first_questions_section.then_some(question_id),
+                    &typo_map,
+                    &previous_question_nl,
+                    &previous_question_fr,
+                )?;
+                question_items.insert(previous_question_item.clone(), stable_id);
+                previous_question_nl.clear();
+                previous_question_fr.clear();
             }
+
+            if is_question_header {
+                in_first_questions_section = !after_first_questions_section;
+            } else if in_first_questions_section {
+                in_first_questions_section = false;
+                after_first_questions_section = true;
+            }
+            questions_section = is_question_header;
+            processing_questions = is_question_header;
         }
 
-        if !processing_questions {
+        if !(processing_questions || (questions_section && tag_name == "h2")) {
             continue;
         }
 
         // Question.
         if tag_name == "h2" {
+            // A numbered heading starts the next agenda item. When it is not a question, such as
+            // the communications after the last question, it ends the questions.
+            let heading = clean_text(&element.text().collect::<String>());
+            let mut ends_questions = false;
+            if let Some(captures) = item_regex.captures(heading.trim()) {
+                current_item = captures[1].to_string();
+                ends_questions = !(["Vraag van", "Question de", "Samengevoegde"]
+                    .iter()
+                    .any(|start| captures[2].starts_with(start))
+                    || captures[2].contains("jointes"));
+            }
+
             // NOTE: Here we extract dutch/french language parts HOWEVER, they are not 100% reliable and may be incorrect.
             // NOTE: So, we analyze the text to be sure as well.
             // NOTE: For SOME REASON in plenary meeting 71 they use NL-BE instead of NL
@@ -988,57 +794,65 @@ async fn scrape_meeting(
             // Combine decision-making logic based on Dutch/French headers
             let is_group_start = found_text_nl
                 .as_deref()
-                .map_or(false, |t| t.starts_with("Samengevoegde"))
+                .is_some_and(|t| t.starts_with("Samengevoegde"))
                 || found_text_fr
                     .as_deref()
-                    .map_or(false, |t| t.contains("jointes"));
+                    .is_some_and(|t| t.contains("jointes"));
 
-            let is_subquestion = found_text_nl
-                .as_deref()
-                .map_or(false, |t| t.starts_with("-"))
-                || found_text_fr
-                    .as_deref()
-                    .map_or(false, |t| t.starts_with("-"));
+            let is_subquestion = found_text_nl.as_deref().is_some_and(|t| t.starts_with("-"))
+                || found_text_fr.as_deref().is_some_and(|t| t.starts_with("-"));
 
             let is_single_question = found_text_nl
                 .as_deref()
-                .map_or(false, |t| t.starts_with("Vraag van"))
+                .is_some_and(|t| t.starts_with("Vraag van"))
                 || found_text_fr
                     .as_deref()
-                    .map_or(false, |t| t.starts_with("Question de"));
+                    .is_some_and(|t| t.starts_with("Question de"));
+
+            if ends_questions
+                && (!previous_question_nl.is_empty() || !previous_question_fr.is_empty())
+            {
+                let stable_id = push_question(
+                    &mut rows,
+                    &mut ids,
+                    in_first_questions_section.then_some(question_id),
+                    &typo_map,
+                    &previous_question_nl,
+                    &previous_question_fr,
+                )?;
+                question_items.insert(previous_question_item.clone(), stable_id);
+                if in_first_questions_section {
+                    question_id += 1;
+                }
+                previous_question_nl.clear();
+                previous_question_fr.clear();
+            }
+            if ends_questions || !(processing_questions || is_group_start || is_single_question) {
+                processing_questions = false;
+                continue;
+            }
+            processing_questions = true;
 
             if is_group_start || is_single_question {
                 if !previous_question_nl.is_empty() && !previous_question_fr.is_empty() {
-                    let question_nl = extract_question_data(
-                        typo_map.clone(),
-                        previous_question_nl.clone(),
-                        previous_discussion.clone(),
-                    )
-                    .await?;
-                    let question_fr = extract_question_data(
-                        typo_map.clone(),
-                        previous_question_fr.clone(),
-                        previous_discussion.clone(),
-                    )
-                    .await?;
-
-                    question_ids.push(question_id.to_string());
-                    question_meeting_ids.push(meeting_id.to_string());
-                    question_session_ids.push(session_id.to_string());
-                    question_discussions.push(question_nl.discussion.clone());
-                    question_questioners.push(question_nl.questioners.join(",")); // NL used here
-                    question_respondents.push(question_nl.respondents.join(","));
-                    question_topics_nl.push(question_nl.topics.join(";"));
-                    question_topics_fr.push(question_fr.topics.join(";"));
-                    question_dossier_ids.push(question_nl.dossier_ids.join(","));
-
-                    question_id += 1;
-                    previous_discussion.clear();
+                    let stable_id = push_question(
+                        &mut rows,
+                        &mut ids,
+                        in_first_questions_section.then_some(question_id),
+                        &typo_map,
+                        &previous_question_nl,
+                        &previous_question_fr,
+                    )?;
+                    question_items.insert(previous_question_item.clone(), stable_id);
+                    if in_first_questions_section {
+                        question_id += 1;
+                    }
                     previous_question_nl.clear();
                     previous_question_fr.clear();
                 }
 
                 // Start new grouped question.
+                previous_question_item = current_item.clone();
                 if let Some(text) = found_text_nl {
                     previous_question_nl = text;
                 }
@@ -1048,31 +862,30 @@ async fn scrape_meeting(
                 // collecting_subquestions = true;
             } else if is_subquestion {
                 if let Some(text) = found_text_nl {
-                    previous_question_nl.push_str("\n");
+                    previous_question_nl.push('\n');
                     previous_question_nl.push_str(text.as_str());
                 }
                 if let Some(text) = found_text_fr {
-                    previous_question_fr.push_str("\n");
+                    previous_question_fr.push('\n');
                     previous_question_fr.push_str(text.as_str());
                 }
             }
         }
+    }
 
-        if tag_name == "p" {
-            let paragraph_text = element
-                .text()
-                .collect::<Vec<_>>()
-                .join(" ")
-                .trim()
-                .to_string();
-
-            let discussion_paragraph = clean_text(&paragraph_text);
-
-            if !paragraph_text.is_empty() {
-                previous_discussion.push_str(discussion_paragraph.as_str());
-                previous_discussion.push_str("NEWPARAGRAPH");
-            }
-        }
+    // Save the last question of reports that end with the questions, which was not parsed before.
+    if processing_questions
+        && (!previous_question_nl.is_empty() || !previous_question_fr.is_empty())
+    {
+        let stable_id = push_question(
+            &mut rows,
+            &mut ids,
+            None,
+            &typo_map,
+            &previous_question_nl,
+            &previous_question_fr,
+        )?;
+        question_items.insert(previous_question_item, stable_id);
     }
 
     // Extract propositions + related dossiers.
@@ -1110,43 +923,26 @@ async fn scrape_meeting(
                 // skip french translation of the header
                 if !previous_proposition_nl.is_empty() || !previous_proposition_fr.is_empty() {
                     // Flush proposition
-                    let proposition_nl =
-                        extract_proposition_data(previous_proposition_nl.clone()).await?;
-                    let proposition_fr =
-                        extract_proposition_data(previous_proposition_fr.clone()).await?;
-                    let has_dossier_id = proposition_nl.dossier_id.is_some();
-                    let dossier_id_opt = proposition_nl.dossier_id.clone();
-
-                    proposition_ids.push(proposition_id.to_string());
-                    proposition_meeting_ids.push(meeting_id.to_string());
-                    proposition_session_ids.push(session_id.to_string());
-
-                    proposition_titles_nl.push(proposition_nl.topic.clone());
-                    proposition_titles_fr.push(proposition_fr.topic.clone());
-                    proposition_dossier_ids.push(match proposition_nl.dossier_id {
-                        None => "".to_string(),
-                        Some(dossier_id) => dossier_id.clone(),
-                    });
-                    proposition_document_ids.push(match proposition_nl.document_id {
-                        None => "".to_string(),
-                        Some(document_id) => document_id.clone(),
+                    let proposition_nl = extract_proposition_data(previous_proposition_nl.clone())?;
+                    let proposition_fr = extract_proposition_data(previous_proposition_fr.clone())?;
+
+                    rows.propositions.push(Proposition {
+                        proposition_id: ids.assign(
+                            "proposition",
+                            proposition_id.to_string(),
+                            content_id(&previous_proposition_nl),
+                        ),
+                        session_id: session_id.to_string(),
+                        meeting_id: meeting_id.to_string(),
+                        title_nl: proposition_nl.topic.clone(),
+                        title_fr: proposition_fr.topic.clone(),
+                        dossier_id: proposition_nl.dossier_id,
+                        document_id: proposition_nl.document_id,
                     });
 
                     // proposition_id += 1;
                     previous_proposition_nl.clear();
                     previous_proposition_fr.clear();
-
-                    // Download dossier if needed.
-                    if has_dossier_id {
-                        check_and_download_dossier_file(
-                            dossier_id_opt.unwrap().as_str(),
-                            session_id,
-                            date.parse().unwrap(),
-                            client,
-                            web_request_count,
-                        )
-                        .await?;
-                    }
                 }
 
                 break;
@@ -1207,7 +1003,7 @@ async fn scrape_meeting(
 
             if let Some(cleaned_nl) = cleaned_nl {
                 if cleaned_nl.starts_with("-") {
-                    previous_proposition_nl.push_str("\n");
+                    previous_proposition_nl.push('\n');
                 } else if !previous_proposition_nl.is_empty() || !previous_proposition_fr.is_empty()
                 {
                     let previous_fr_clone = previous_proposition_fr.clone();
@@ -1223,56 +1019,34 @@ async fn scrape_meeting(
                         .collect::<Vec<_>>();
 
                     for pair in propositions_nl.iter().zip_longest(propositions_fr.iter()) {
-                        match pair {
-                            EitherOrBoth::Both(text_nl, text_fr) => {
-                                let prop_text_nl = text_nl.to_string();
-                                let prop_text_fr = text_fr.to_string();
-
-                                // Flush proposition
-                                let proposition_nl = extract_proposition_data(
-                                    prop_text_nl.clone().replace("- ", ""),
-                                )
-                                .await?;
-                                let proposition_fr = extract_proposition_data(
-                                    prop_text_fr.clone().replace("- ", ""),
-                                )
-                                .await?;
-                                let has_dossier_id = proposition_nl.dossier_id.is_some();
-                                let dossier_id_opt = proposition_nl.dossier_id.clone();
-
-                                proposition_ids.push(proposition_id.to_string());
-                                proposition_meeting_ids.push(meeting_id.to_string());
-                                proposition_session_ids.push(session_id.to_string());
-
-                                proposition_titles_nl.push(proposition_nl.topic.clone());
-                                proposition_titles_fr.push(proposition_fr.topic.clone());
-                                proposition_dossier_ids.push(match proposition_nl.dossier_id {
-                                    None => "".to_string(),
-                                    Some(id) => id.clone(),
-                                });
-                                proposition_document_ids.push(match proposition_nl.document_id {
-                                    None => "".to_string(),
-                                    Some(id) => id.clone(),
-                                });
-
-                                proposition_id += 1;
-
-                                // Download dossier if needed.
-                                if has_dossier_id {
-                                    check_and_download_dossier_file(
-                                        dossier_id_opt.unwrap().as_str(),
-                                        session_id,
-                                        date.parse().unwrap(),
-                                        client,
-                                        web_request_count,
-                                    )
-                                    .await?;
-                                }
-
-                                previous_proposition_nl.clear();
-                                previous_proposition_fr.clear();
-                            }
-                            _ => {}
+                        if let EitherOrBoth::Both(text_nl, text_fr) = pair {
+                            let prop_text_nl = text_nl.to_string();
+                            let prop_text_fr = text_fr.to_string();
+
+                            // Flush proposition
+                            let proposition_nl =
+                                extract_proposition_data(prop_text_nl.clone().replace("- ", ""))?;
+                            let proposition_fr =
+                                extract_proposition_data(prop_text_fr.clone().replace("- ", ""))?;
+
+                            rows.propositions.push(Proposition {
+                                proposition_id: ids.assign(
+                                    "proposition",
+                                    proposition_id.to_string(),
+                                    content_id(&prop_text_nl),
+                                ),
+                                session_id: session_id.to_string(),
+                                meeting_id: meeting_id.to_string(),
+                                title_nl: proposition_nl.topic.clone(),
+                                title_fr: proposition_fr.topic.clone(),
+                                dossier_id: proposition_nl.dossier_id,
+                                document_id: proposition_nl.document_id,
+                            });
+
+                            proposition_id += 1;
+
+                            previous_proposition_nl.clear();
+                            previous_proposition_fr.clear();
                         }
                     }
                 }
@@ -1282,7 +1056,7 @@ async fn scrape_meeting(
 
             if let Some(cleaned_fr) = cleaned_fr {
                 if cleaned_fr.starts_with('-') {
-                    previous_proposition_fr.push_str("\n");
+                    previous_proposition_fr.push('\n');
                 }
                 previous_proposition_fr.push_str(&cleaned_fr);
             }
@@ -1298,11 +1072,126 @@ async fn scrape_meeting(
     // let mut vote_count: i32 = 0;
     let mut vote_id: i32 = 0;
     let mut collecting_grouped_vote = false;
+    let mut awaiting_outcome = false;
+    // Explanations are asked for before a vote, reasons for abstaining after it. The explanations
+    // are linked to the roll-call vote that follows them, the reasons and the corrections to the
+    // last one.
+    let mut last_roll_call: Option<String> = None;
+    let mut explanation: Option<(&'static str, Option<String>)> = None;
+    let mut explanation_paragraphs: Vec<String> = Vec::new();
+    let mut collecting_explanation = false;
 
     // Find vote summaries.
-    for element in document.select(&Selector::parse("h1, h2, table").unwrap()) {
+    for element in document.select(&Selector::parse("h1, h2, table, p").unwrap()) {
         let tag_name = element.value().name();
 
+        if tag_name == "p" {
+            let in_table = element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| ancestor.value().name() == "table");
+            if !found_votes || in_table {
+                continue;
+            }
+            let text = clean_text(&element.text().collect::<String>())
+                .split_whitespace()
+                .join(" ");
+
+            // A paragraph after the results table states the outcome, sometimes after some
+            // interjections.
+            if awaiting_outcome {
+                let (outcome, majority) = extract_outcome(&text);
+                if let (Some(outcome), Some(vote)) = (outcome, rows.votes.last_mut()) {
+                    vote.outcome = Some(outcome.to_string());
+                    vote.majority = majority.to_string();
+                    awaiting_outcome = false;
+                }
+            }
+
+            if let Some(vote_id) = &last_roll_call {
+                for note in extract_vote_corrections(&text) {
+                    rows.vote_corrections.push(VoteCorrection {
+                        session_id: session_id.to_string(),
+                        meeting_id: meeting_id.to_string(),
+                        vote_id: vote_id.clone(),
+                        member_id: None,
+                        name: note.name,
+                        recorded_choice: None,
+                        intended_choice: note.choice.map(str::to_string),
+                        like_group: note.like_group,
+                        text: note.text,
+                    });
+                }
+            }
+
+            // Members also mention the explanation they give ("ook ik wil nog een stemverklaring
+            // afleggen"), which continues the explanations of the same vote. A prompt nobody
+            // answered ends in "(Nee)".
+            let asks_explanation =
+                text.contains("stemverklaring") || text.contains("déclaration avant le vote");
+            let asks_abstention_reason =
+                text.starts_with("Reden van onthouding") || text.starts_with("Raison d'abstention");
+            let answered_no = text.replace(' ', "").ends_with("(Nee)")
+                || text.replace(' ', "").ends_with("(Non)");
+            let continues_explanation = matches!(explanation, Some(("explanation", _)));
+            if (asks_explanation && !continues_explanation) || asks_abstention_reason {
+                if let Some((kind, vote_id)) = explanation.take() {
+                    push_vote_explanations(
+                        &mut rows,
+                        session_id,
+                        meeting_id,
+                        kind,
+                        vote_id,
+                        &explanation_paragraphs,
+                    );
+                }
+                explanation_paragraphs.clear();
+                explanation = match asks_explanation {
+                    true => Some(("explanation", None)),
+                    false => last_roll_call
+                        .clone()
+                        .map(|vote_id| ("abstention", Some(vote_id))),
+                };
+                collecting_explanation = explanation.is_some() && !answered_no;
+                if collecting_explanation {
+                    explanation_paragraphs.push(text);
+                }
+            } else if asks_explanation {
+                collecting_explanation = !answered_no;
+                explanation_paragraphs.push(text);
+            } else if [
+                "Begin van de stemming",
+                "Stemming over",
+                "Vote sur",
+                "Mag de uitslag",
+            ]
+            .iter()
+            .any(|start| text.starts_with(start))
+            {
+                collecting_explanation = false;
+            } else if collecting_explanation {
+                explanation_paragraphs.push(text);
+            }
+            continue;
+        }
+        awaiting_outcome = false;
+        collecting_explanation = false;
+        // A heading ends the explanations of a decision without a roll call and the corrections of
+        // the last vote.
+        if tag_name != "table" {
+            if let Some((kind, vote_id)) = explanation.take() {
+                push_vote_explanations(
+                    &mut rows,
+                    session_id,
+                    meeting_id,
+                    kind,
+                    vote_id,
+                    &explanation_paragraphs,
+                );
+            }
+            last_roll_call = None;
+        }
+
         // Votes section.
         if tag_name == "h1" {
             let text = element
@@ -1345,7 +1234,7 @@ async fn scrape_meeting(
                     previous_vote_title_nl = vote_text_nl.clone();
                     collecting_grouped_vote = true;
                 } else if collecting_grouped_vote && vote_text_nl.starts_with("-") {
-                    previous_vote_title_nl.push_str("\n");
+                    previous_vote_title_nl.push('\n');
                     previous_vote_title_nl.push_str(&vote_text_nl);
                 } else {
                     if !previous_vote_title_nl.is_empty() {
@@ -1369,7 +1258,7 @@ async fn scrape_meeting(
                     previous_vote_title_fr = vote_text_fr.clone();
                     collecting_grouped_vote = true;
                 } else if collecting_grouped_vote && vote_text_fr.starts_with("-") {
-                    previous_vote_title_fr.push_str("\n");
+                    previous_vote_title_fr.push('\n');
                     previous_vote_title_fr.push_str(&vote_text_fr);
                 } else {
                     if !previous_vote_title_fr.is_empty() {
@@ -1383,181 +1272,174 @@ async fn scrape_meeting(
 
         if tag_name == "table" {
             let vote = extract_vote(element);
+            let vote_number = vote_number(&vote.name);
             let (yes_voters, no_voters, abstain_voters) =
-                extract_voter_names(&document, &vote.name.replace("Stemming ", ""));
+                extract_voter_names(&document, &vote_number);
 
             let converted_yes_voters: Vec<String> = yes_voters
                 .split(',')
                 .map(|name| convert_name(name.trim()))
+                .filter(|name| !name.is_empty())
                 .collect();
 
             let converted_no_voters: Vec<String> = no_voters
                 .split(',')
                 .map(|name| convert_name(name.trim()))
+                .filter(|name| !name.is_empty())
                 .collect();
 
             let converted_abstain_voters: Vec<String> = abstain_voters
                 .split(',')
                 .map(|name| convert_name(name.trim()))
+                .filter(|name| !name.is_empty())
                 .collect();
 
             if vote.yes > 0 || vote.no > 0 || vote.abstain > 0 {
                 // if !previous_vote_title_nl.is_empty() || !previous_vote_title_fr.is_empty() {
                 //      vote_count += 1;
 
-                vote_ids.push(vote_id.to_string());
-                vote_meeting_ids.push(meeting_id.to_string());
-                vote_dates.push(date.to_string());
-                vote_session_ids.push(session_id.to_string());
-
-                let vote_nl = extract_vote_data(previous_vote_title_nl.clone()).await?;
-                let vote_fr = extract_vote_data(previous_vote_title_fr.clone()).await?;
-
-                if !vote_nl.topic.is_empty() {
-                    vote_titles_nl.push(vote_nl.topic.clone());
-                } else {
-                    vote_titles_nl.push(vote_text_nl.clone().to_string());
-                }
-
-                if !vote_fr.topic.is_empty() {
-                    vote_titles_fr.push(vote_fr.topic.clone());
-                } else {
-                    vote_titles_fr.push(vote_text_fr.clone().to_string());
-                }
+                let vote_nl = extract_vote_data(previous_vote_title_nl.clone())?;
+                let vote_fr = extract_vote_data(previous_vote_title_fr.clone())?;
+                let stable_id = ids.assign(
+                    "vote",
+                    vote_id.to_string(),
+                    vote_stable_id(&vote_number, &previous_vote_title_nl),
+                );
+
+                rows.votes.push(Vote {
+                    vote_id: stable_id.clone(),
+                    session_id: session_id.to_string(),
+                    meeting_id: meeting_id.to_string(),
+                    date,
+                    title_nl: if !vote_nl.topic.is_empty() {
+                        vote_nl.topic.clone()
+                    } else {
+                        vote_text_nl.clone().to_string()
+                    },
+                    title_fr: if !vote_fr.topic.is_empty() {
+                        vote_fr.topic.clone()
+                    } else {
+                        vote_text_fr.clone().to_string()
+                    },
+                    yes: Some(vote.yes),
+                    no: Some(vote.no),
+                    abstain: Some(vote.abstain),
+                    members_yes: converted_yes_voters,
+                    members_no: converted_no_voters,
+                    members_abstain: converted_abstain_voters,
+                    dossier_id: vote_nl.dossier_id,
+                    document_id: vote_nl.document_id,
+                    motion_id: vote_nl.motion_id,
+                    vote_kind: "roll_call".to_string(),
+                    outcome: None,
+                    majority: "simple".to_string(),
+                    // Set once all meetings are parsed, see `check_vote_tallies`.
+                    tally_mismatch: false,
+                });
+                awaiting_outcome = true;
 
-                // Download dossier if needed.
-                let has_dossier_id = vote_nl.dossier_id.is_some();
-                let dossier_id_opt = vote_nl.dossier_id.clone();
-                if has_dossier_id {
-                    check_and_download_dossier_file(
-                        dossier_id_opt.unwrap().as_str(),
+                if let Some((kind, vote_id)) = explanation.take() {
+                    let vote_id = vote_id.or_else(|| Some(stable_id.clone()));
+                    push_vote_explanations(
+                        &mut rows,
                         session_id,
-                        date.parse().unwrap(),
-                        client,
-                        web_request_count,
-                    )
-                    .await?;
+                        meeting_id,
+                        kind,
+                        vote_id,
+                        &explanation_paragraphs,
+                    );
                 }
-
-                vote_yes.push(vote.yes.to_string());
-                vote_no.push(vote.no.to_string());
-                vote_abstain.push(vote.abstain.to_string());
-                vote_members_yes.push(converted_yes_voters.join(", "));
-                vote_members_no.push(converted_no_voters.join(", "));
-                vote_members_abstain.push(converted_abstain_voters.join(", "));
-                vote_dossier_ids.push(match vote_nl.dossier_id {
-                    None => "".to_string(),
-                    Some(dossier_id) => dossier_id.to_string(),
-                });
-                vote_document_ids.push(match vote_nl.document_id {
-                    None => "".to_string(),
-                    Some(document_id) => document_id.to_string(),
-                });
-                vote_motion_ids.push(match vote_nl.motion_id {
-                    None => "".to_string(),
-                    Some(motion_id) => motion_id.to_string(),
-                });
-
-                vote_id += 1;
-                //   }
-            }
-        }
-    }
-
-    meeting_session_ids.push(session_id.to_string());
-    meeting_ids.push(meeting_id.to_string());
-    dates.push(date.to_string());
-    times_of_day.push(time_of_day.to_string());
-    start_times.push(start_time.to_string());
-    end_times.push(end_time.to_string());
-
-    Ok(())
-}
-
-async fn check_and_download_dossier_file(
-    dossier_id: &str,
-    session_id: u32,
-    date: NaiveDate,
-    client: &ScrapingClient,
-    web_request_count: &mut u32,
-) -> Result<(), Box<dyn Error>> {
-    let dossier_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
-
-    let foldername = format!("data/sources/sessions/{}/dossiers", session_id);
-
-    let dossier_dir = dossier_root.join(foldername);
-
-    // let dossier_dir = "scraper/data/sources/dossiers";
-    let filename_prefix = format!("{}_{}_", session_id, dossier_id);
-    let mut should_download = true;
-
-    let mut existing_file: Option<PathBuf> = None;
-    let dossier_dir_clone = dossier_dir.clone(); // Clone the original PathBuf
-
-    if let Ok(mut entries) = read_dir(dossier_dir).await {
-        while let Ok(Some(entry)) = entries.next_entry().await {
-            let fname = entry.file_name().to_string_lossy().to_string();
-            if fname.starts_with(&filename_prefix) {
-                existing_file = Some(entry.path());
-
-                // Try to extract date
-                if let Some(caps) = Regex::new(r"_(\d{4}-\d{2}-\d{2})")?.captures(&fname) {
-                    if let Some(date_str) = caps.get(1) {
-                        if let Ok(existing_date) =
-                            NaiveDate::parse_from_str(date_str.as_str(), "%Y-%m-%d")
-                        {
-                            should_download = date >= existing_date;
+                last_roll_call = Some(stable_id.clone());
+
+                for (choice, voters) in [
+                    ("yes", &yes_voters),
+                    ("no", &no_voters),
+                    ("abstain", &abstain_voters),
+                ] {
+                    for name in voters.split(',') {
+                        let name = name.split_whitespace().join(" ");
+                        if name.is_empty() {
+                            continue;
                         }
+                        rows.ballots.push(Ballot {
+                            session_id: session_id.to_string(),
+                            meeting_id: meeting_id.to_string(),
+                            vote_id: stable_id.clone(),
+                            member_id: None,
+                            name,
+                            choice: choice.to_string(),
+                            corrected_choice: choice.to_string(),
+                        });
                     }
                 }
-                break;
+
+                vote_id += 1;
+                //   }
             }
         }
     }
 
-    if should_download {
-        if let Some(old_path) = &existing_file {
-            let _ = remove_file(old_path).await;
-        }
-
-        let today = Local::now().naive_local().date();
-        let new_filename = format!(
-            "{}/{}_{}_{}.html",
-            dossier_dir_clone.display(),
+    if let Some((kind, vote_id)) = explanation.take() {
+        push_vote_explanations(
+            &mut rows,
             session_id,
-            dossier_id,
-            today
-        );
-        let filepath = Path::new(&new_filename);
-
-        // let today = Local::now().naive_local().date();
-        // let new_filename = format!("{}/{}_{}_{}.html", dossier_dir, session_id, dossier_id, today);
-        // let filepath = Path::new(&new_filename);
-
-        let url = format!(
-            "https://www.dekamer.be/kvvcr/showpage.cfm?section=/flwb&language=nl&cfm=/site/wwwcfm/flwb/flwbn.cfm?lang=N&legislat={}&dossierID={}",
-            session_id, dossier_id
+            meeting_id,
+            kind,
+            vote_id,
+            &explanation_paragraphs,
         );
+    }
 
-        let response = client.get(&url).await?;
-        *web_request_count += 1;
-        let raw_bytes = response.bytes().await?;
-        let (decoded_str, _, _) = WINDOWS_1252.decode(&raw_bytes);
-        let content = decoded_str.to_string();
-        if let Some(parent) = filepath.parent() {
-            create_dir_all(parent).await?;
-        }
-        write(filepath, content).await?;
+    // Extract decisions without a roll call.
+    rows.votes
+        .extend(extract_decision_votes(&document, &mut ids, date)?);
+
+    // Extract the agenda items and what was said about them.
+    let (agenda_items, utterances) =
+        extract_agenda_rows(&document, session_id, meeting_id, &question_items);
+    rows.agenda_items.extend(agenda_items);
+    rows.utterances.extend(utterances);
+
+    // Extract the members who sent their apologies.
+    for excused in extract_excused(&document) {
+        rows.attendance.push(Attendance {
+            session_id: session_id.to_string(),
+            meeting_id: meeting_id.to_string(),
+            member_id: None,
+            name: excused.name,
+            status: "excused".to_string(),
+            reason_nl: excused.reason_nl,
+            reason_fr: excused.reason_fr,
+        });
     }
 
-    Ok(())
+    rows.id_mappings = ids.mappings;
+    rows.meetings.push(Meeting {
+        session_id: session_id.to_string(),
+        meeting_id: meeting_id.to_string(),
+        date,
+        time_of_day: time_of_day.to_string(),
+        start_time: Some(start_time),
+        end_time: Some(end_time),
+    });
+
+    Ok(rows)
+}
+
+/// Midnight at the start of `date` in local time.
+fn start_of_day(date: NaiveDate) -> SystemTime {
+    date.and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_local_timezone(Local)
+        .unwrap()
+        .into()
 }
 
+#[derive(Default)]
 struct QuestionData {
     questioners: Vec<String>,
     respondents: Vec<String>,
     topics: Vec<String>,
-    discussion: String,
     dossier_ids: Vec<String>,
 }
 
@@ -1567,110 +1449,9 @@ struct PropositionData {
     document_id: Option<String>,
 }
 
-struct VoteData {
-    topic: String,
-    dossier_id: Option<String>,
-    document_id: Option<String>,
-    motion_id: Option<String>,
-}
-
-impl Default for QuestionData {
-    fn default() -> Self {
-        QuestionData {
-            questioners: Vec::new(),
-            respondents: Vec::new(),
-            topics: Vec::new(),
-            discussion: String::new(),
-            dossier_ids: Vec::new(),
-        }
-    }
-}
-
-fn get_discussion_json(input: &str) -> String {
-    let cleaned_input = clean_text(input);
-    // println!("DISC: {}", cleaned_input);
-
-    // Regex to capture timestamps and speakers (including Le président and De voorzitter)
-    // FIX: it looks for numbers like 07.01 to find new sections, but it also fell over 20.00 which could just be an hour within the text!
-    // SO: new regex which checks that either no characters should be before it
-    // (?m)(?:^|(?:NEWPARAGRAPH))[\n\r\s ]*(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:
-    // instead of
-    // (?m)(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:
-    let speaker_re = Regex::new(
-        r"(?m)(?:^|(?:NEWPARAGRAPH))[\n\r\s ]*(\d{2}\.\d{2})[\n\r\s ]+([^:]+):|(?:Le  président|De  voorzitter)\s*:"
-    ).unwrap();
-
-    let speaker_name_re = Regex::new(r"^[^(,:\n\r]+").unwrap();
-    let title_re = Regex::new(r"^(Minister|De heer|Mevrouw|Le ministre|La ministre|Monsieur|Madame|Eerste minister|Staatssecretaris)\s+").unwrap();
-
-    let mut discussion = Vec::new();
-    let mut current_speaker = String::new();
-    let mut last_end = 0;
-
-    for cap in speaker_re.captures_iter(&cleaned_input) {
-        let match_start = cap.get(0).unwrap().start();
-        let text_segment = cleaned_input[last_end..match_start].trim();
-
-        if !current_speaker.is_empty() && !text_segment.is_empty() {
-            let clean_text_segment = text_segment
-                .replace("Het incident is gesloten.", "")
-                .replace("L'incident est clos.", "")
-                //.replace("NEWPARAGRAPH", "\n")
-                .trim()
-                .to_string();
-
-            if !clean_text_segment.is_empty() {
-                // println!("SPEAKER: {}", current_speaker);
-
-                discussion.push(json!({
-                    "speaker": current_speaker.trim(),
-                    "text": clean_text_segment
-                }));
-            }
-        }
-
-        // Determine speaker
-        current_speaker = if let Some(full_speaker) = cap.get(2) {
-            let speaker_raw = full_speaker.as_str().trim();
-            let speaker_stripped = title_re.replace(speaker_raw, "").to_string();
-            speaker_name_re
-                .captures(&speaker_stripped)
-                .and_then(|c| c.get(0))
-                .map_or("Onbekend".to_string(), |m| m.as_str().to_string())
-        } else {
-            // It's the chairperson
-            "Voorzitter".to_string()
-        };
-        // println!("{}", current_speaker);
-
-        last_end = cap.get(0).unwrap().end();
-    }
-
-    // Handle last speaker's segment
-    if !current_speaker.is_empty() && last_end < cleaned_input.len() {
-        let text_segment = &cleaned_input[last_end..];
-        let clean_text_segment = text_segment
-            .replace("Het incident is gesloten.", "")
-            .replace("L'incident est clos.", "")
-            .replace("NEWPARAGRAPH", "\n")
-            .trim()
-            .to_string();
-
-        if !clean_text_segment.is_empty() {
-            // println!("{}", current_speaker);
-            discussion.push(json!({
-                "speaker": current_speaker.trim(),
-                "text": clean_text_segment
-            }));
-        }
-    }
-
-    serde_json::to_string_pretty(&discussion).unwrap()
-}
-
-async fn extract_proposition_data(
+fn extract_proposition_data(
     proposition_text: String,
-) -> Result<PropositionData, Box<dyn Error>> {
+) -> Result<PropositionData, Box<dyn Error + Send + Sync>> {
     let regex_1 = Regex::new(
         r#"^((?:Voorstel van resolutie|Proposition de résolution|Wetsvoorstel|Proposition de loi|Wetsontwerp|Projet de loi|Voorstel tot|Proposition visant).*)\((\d+)\/(\d+(?:-\d+)?)\)$"#,
     )?;
@@ -1720,81 +1501,41 @@ async fn extract_proposition_data(
     }
 }
 
-async fn extract_vote_data(vote_text: String) -> Result<VoteData, Box<dyn Error>> {
-    let regex_1 = Regex::new(r#"^(.*)\((\d+)/(\d+(?:-\d+)?)\)\s*$"#)?; // with (123/1-2)
-    let regex_2 = Regex::new(r#"^(.*)\s+\((?:nr\.|n°)\s*(\d+)\)\s*$"#)?; // with (nr. 12)
-    let regex_3 = Regex::new(r#"^([^(]*)"#)?; // without any number
-
-    if let Some(capture) = regex_1.captures(&vote_text) {
-        let topic = capture
-            .get(1)
-            .ok_or("Missing topic capture")?
-            .as_str()
-            .trim()
-            .to_string();
-        let dossier_id = capture
-            .get(2)
-            .ok_or("Missing dossier_id capture")?
-            .as_str()
-            .trim()
-            .to_string();
-        let document_id = capture
-            .get(3)
-            .ok_or("Missing document_id capture")?
-            .as_str()
-            .trim()
-            .to_string();
-
-        Ok(VoteData {
-            topic,
-            dossier_id: Some(dossier_id),
-            document_id: Some(document_id),
-            motion_id: None,
-        })
-    } else if let Some(capture) = regex_2.captures(&vote_text) {
-        let topic = capture
-            .get(1)
-            .ok_or("Missing topic capture")?
-            .as_str()
-            .trim()
-            .to_string();
-        let motion_id = capture
-            .get(2)
-            .ok_or("Missing motion_id capture")?
-            .as_str()
-            .trim()
-            .to_string();
-
-        Ok(VoteData {
-            topic,
-            dossier_id: None,
-            document_id: None,
-            motion_id: Some(motion_id),
-        })
-    } else if let Some(capture) = regex_3.captures(&vote_text) {
-        let topic = capture
-            .get(1)
-            .ok_or("Missing topic capture")?
-            .as_str()
-            .trim()
-            .to_string();
-
-        Ok(VoteData {
-            topic,
-            dossier_id: None,
-            document_id: None,
-            motion_id: None,
-        })
-    } else {
-        Err("No regex matched for vote text".into())
+/// Adds the explanations of vote in `paragraphs`, the speech turns of the members after the chair
+/// asked for them.
+fn push_vote_explanations(
+    rows: &mut MeetingRows,
+    session_id: u32,
+    meeting_id: u32,
+    kind: &str,
+    vote_id: Option<String>,
+    paragraphs: &[String],
+) {
+    let party_regex = Regex::new(r"\(([^()]+)\)").unwrap();
+    for turn in split_turns(paragraphs.iter().map(String::as_str)) {
+        if turn.role != "member" || turn.paragraphs.is_empty() {
+            continue;
+        }
+        rows.vote_explanations.push(VoteExplanation {
+            session_id: session_id.to_string(),
+            meeting_id: meeting_id.to_string(),
+            vote_id: vote_id.clone(),
+            kind: kind.to_string(),
+            party: party_regex
+                .captures(&turn.label)
+                .map(|captures| captures[1].trim().to_string()),
+            speaker: turn.label,
+            member_id: None,
+            paragraphs: turn.paragraphs,
+            marker: turn.marker,
+        });
     }
 }
 
-async fn extract_question_data(
+fn extract_question_data(
     typo_map: HashMap<String, String>,
     question_text: String,
-    discussion_text: String,
-) -> Result<QuestionData, Box<dyn Error>> {
+) -> Result<QuestionData, Box<dyn Error + Send + Sync>> {
     // Below are some of the issues found in meeting reports.
     // ISSUE: plenary meeting report 071: questions did not have dossier ids mentioned. -> Adjusted regex to not require this
     // let regex = Regex::new(
@@ -1820,6 +1561,7 @@ async fn extract_question_data(
             .as_str()
             .trim()
             .replace("- ", "")
+            .trim()
             .to_string();
         let questioner = typo_map
             .get(&questioner_raw)
@@ -1877,7 +1619,6 @@ async fn extract_question_data(
         questioners,
         respondents,
         topics,
-        discussion: get_discussion_json(&discussion_text),
         dossier_ids,
     })
 }
@@ -1899,14 +1640,14 @@ fn convert_name(name: &str) -> String {
     let last_name = parts[..parts.len() - 1].join(" ");
 
     // Combine them in "First Name Last Name" format
-    format!("{} {}", first_name, last_name)
+    normalize_person_name(&format!("{} {}", first_name, last_name))
 }
 
 struct VoteRecord {
     name: String,
-    yes: u32,
-    no: u32,
-    abstain: u32,
+    yes: i32,
+    no: i32,
+    abstain: i32,
 }
 
 fn extract_vote(table: ElementRef) -> VoteRecord {
@@ -1944,7 +1685,7 @@ fn extract_vote(table: ElementRef) -> VoteRecord {
             let value_text: String = cells[1].text().collect::<Vec<_>>().join(" "); // Store in a String
             let value_trimmed = value_text.trim(); // Now trim it safely
 
-            if let Ok(value) = value_trimmed.parse::<u32>() {
+            if let Ok(value) = value_trimmed.parse::<i32>() {
                 match label.as_str() {
                     "Ja" => yes = value,
                     "Nee" => no = value,
@@ -1953,7 +1694,7 @@ fn extract_vote(table: ElementRef) -> VoteRecord {
                 }
             }
 
-            if let Ok(value) = value_text.parse::<u32>() {
+            if let Ok(value) = value_text.parse::<i32>() {
                 match label.as_str() {
                     "Ja" => yes = value,
                     "Nee" => no = value,
@@ -1979,7 +1720,6 @@ fn extract_voter_names(document: &Html, vote_index: &str) -> (String, String, St
 
     let span_selector = Selector::parse("span").unwrap();
     let td_selector = Selector::parse("td").unwrap();
-    let p_selector = Selector::parse("p").unwrap();
 
     // 1. find the three result‑tables that belong to the requested vote
     let mut tables = Vec::new();
@@ -2199,11 +1939,13 @@ fn extract_voter_names(document: &Html, vote_index: &str) -> (String, String, St
 //     (yes_voters, no_voters, abstain_voters)
 // }
 
-fn extract_time_from_document(document: &Html, keyword: &str) -> Result<String, Box<dyn Error>> {
+fn extract_time_from_document(
+    document: &Html,
+    keyword: &str,
+) -> Result<NaiveTime, Box<dyn Error + Send + Sync>> {
     let span_selector = Selector::parse("span").unwrap();
-    let re = Regex::new(r"(\d{1,2})\.(\d{2})\s*0?uur").unwrap();
 
-    let mut last_time: Option<String> = None;
+    let mut last_time: Option<NaiveTime> = None;
 
     // Iterate through all spans.
     for span in document.select(&span_selector) {
@@ -2215,10 +1957,9 @@ fn extract_time_from_document(document: &Html, keyword: &str) -> Result<String,
             .contains(keyword.to_lowercase().as_str())
         {
             // println!("TEXT: {}", text);
-            // Try to capture the time pattern.
-            if let Some(caps) = re.captures(&text) {
-                // Update last_time with the most recent time match.
-                last_time = Some(format!("{}h{}", &caps[1], &caps[2]));
+            // Update last_time with the most recent time match.
+            if let Some(time) = parse_time(&text) {
+                last_time = Some(time);
             }
         }
     }
@@ -2230,7 +1971,9 @@ fn extract_time_from_document(document: &Html, keyword: &str) -> Result<String,
     }
 }
 
-fn extract_start_time_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_start_time_from_document(
+    document: &Html,
+) -> Result<NaiveTime, Box<dyn Error + Send + Sync>> {
     let phrases = vec!["wordt geopend", "wordt hervat"];
 
     for phrase in phrases {
@@ -2242,7 +1985,9 @@ fn extract_start_time_from_document(document: &Html) -> Result<String, Box<dyn E
     Err("Could not extract start time from the document".into())
 }
 
-fn extract_end_time_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_end_time_from_document(
+    document: &Html,
+) -> Result<NaiveTime, Box<dyn Error + Send + Sync>> {
     let phrases = vec![
         "De vergadering wordt gesloten",
         "De vergadering wordt geschorst",
@@ -2257,28 +2002,20 @@ fn extract_end_time_from_document(document: &Html) -> Result<String, Box<dyn Err
     Err("Could not extract end time from the document".into())
 }
 
-fn extract_time_of_day_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_time_of_day_from_document(
+    document: &Html,
+) -> Result<String, Box<dyn Error + Send + Sync>> {
     let span_selector = Selector::parse("span").unwrap();
     for span in document.select(&span_selector) {
-        let text = span
-            .text()
-            .collect::<Vec<_>>()
-            .join(" ")
-            .trim()
-            .to_lowercase();
-
-        if text == "namiddag" {
-            return Ok("afternoon".to_string());
-        } else if text == "voormiddag" {
-            return Ok("morning".to_string());
-        } else if text == "avond" {
-            return Ok("evening".to_string());
+        let text = span.text().collect::<Vec<_>>().join(" ");
+        if let Some(day_part) = DayPart::from_label(&text) {
+            return Ok(day_part.to_string());
         }
     }
     Err("Could not extract time of day from the document".into())
 }
 
-fn extract_date_from_document(document: &Html) -> Result<String, Box<dyn Error>> {
+fn extract_date_from_document(document: &Html) -> Result<NaiveDate, Box<dyn Error + Send + Sync>> {
     let table_selector = Selector::parse("table").unwrap();
     if let Some(first_table) = document.select(&table_selector).next() {
         let span_selector = Selector::parse("span").unwrap();
@@ -2290,26 +2027,8 @@ fn extract_date_from_document(document: &Html) -> Result<String, Box<dyn Error>>
             extracted_text.push(' ');
         }
 
-        let re = Regex::new(r"(\d{1,2})\s+([a-zA-Z]+)\s+(\d{4})").unwrap();
-        if let Some(caps) = re.captures(&extracted_text) {
-            let day = format!("{:02}", caps[1].parse::<u8>()?);
-            let month = match &caps[2].to_lowercase()[..] {
-                "januari" => "01",
-                "februari" => "02",
-                "maart" => "03",
-                "april" => "04",
-                "mei" => "05",
-                "juni" => "06",
-                "juli" => "07",
-                "augustus" => "08",
-                "september" => "09",
-                "oktober" => "10",
-                "november" => "11",
-                "december" => "12",
-                _ => return Err("Invalid month name".into()),
-            };
-            let year = &caps[3];
-            return Ok(format!("{}-{}-{}", year, month, day));
+        if let Some(date) = parse_date(&extracted_text) {
+            return Ok(date);
         }
     }
     Err("Could not extract date from the document".into())
@@ -0,0 +1,223 @@
+use model::{
+    AgendaItem, Attendance, Ballot, IdMapping, Meeting, Proposition, Question, Table, Utterance,
+    Vote, VoteCorrection, VoteExplanation,
+};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Rows scraped from a plenary meeting report.
+#[derive(Default)]
+pub struct MeetingRows {
+    pub meetings: Vec<Meeting>,
+    pub votes: Vec<Vote>,
+    pub ballots: Vec<Ballot>,
+    pub questions: Vec<Question>,
+    pub propositions: Vec<Proposition>,
+    pub id_mappings: Vec<IdMapping>,
+    /// Only those of plenary meetings, the commission scraper writes the others.
+    pub utterances: Vec<Utterance>,
+    pub agenda_items: Vec<AgendaItem>,
+    /// Only the excused members until `fill_attendance` adds the others.
+    pub attendance: Vec<Attendance>,
+    pub vote_explanations: Vec<VoteExplanation>,
+    pub vote_corrections: Vec<VoteCorrection>,
+}
+
+impl MeetingRows {
+    /// Reads the tables in `root`, or returns `None` when one of them does not exist yet or has an
+    /// older schema.
+    pub fn read(root: &Path) -> Result<Option<MeetingRows>, model::Error> {
+        fn read_table<T: Table>(root: &Path) -> Result<Option<Vec<T>>, model::Error> {
+            let path = root.join(T::FILE_NAME);
+            if !path.exists() || model::schema_version(&path)? != model::SCHEMA_VERSION {
+                return Ok(None);
+            }
+            T::read(&path).map(Some)
+        }
+
+        let (
+            Some(meetings),
+            Some(votes),
+            Some(ballots),
+            Some(questions),
+            Some(propositions),
+            Some(id_mappings),
+            Some(utterances),
+            Some(agenda_items),
+            Some(attendance),
+            Some(vote_explanations),
+            Some(vote_corrections),
+        ) = (
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table::<Utterance>(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+            read_table(root)?,
+        )
+        else {
+            return Ok(None);
+        };
+        Ok(Some(MeetingRows {
+            meetings,
+            votes,
+            ballots,
+            questions,
+            propositions,
+            id_mappings,
+            utterances: utterances
+                .into_iter()
+                .filter(|utterance| utterance.meeting_type == "plenary")
+                .collect(),
+            agenda_items,
+            attendance,
+            vote_explanations,
+            vote_corrections,
+        }))
+    }
+
+    /// Replaces the tables in `root`.
+    pub fn write(&self, root: &Path) -> Result<(), model::Error> {
+        Meeting::write(&root.join(Meeting::FILE_NAME), &self.meetings)?;
+        Vote::write(&root.join(Vote::FILE_NAME), &self.votes)?;
+        Ballot::write(&root.join(Ballot::FILE_NAME), &self.ballots)?;
+        Question::write(&root.join(Question::FILE_NAME), &self.questions)?;
+        Proposition::write(&root.join(Proposition::FILE_NAME), &self.propositions)?;
+        IdMapping::write(&root.join(IdMapping::FILE_NAME), &self.id_mappings)?;
+        AgendaItem::write(&root.join(AgendaItem::FILE_NAME), &self.agenda_items)?;
+        Attendance::write(&root.join(Attendance::FILE_NAME), &self.attendance)?;
+        VoteExplanation::write(
+            &root.join(VoteExplanation::FILE_NAME),
+            &self.vote_explanations,
+        )?;
+        VoteCorrection::write(
+            &root.join(VoteCorrection::FILE_NAME),
+            &self.vote_corrections,
+        )?;
+
+        // Keep the utterances of commission meetings.
+        let utterances_path = root.join(Utterance::FILE_NAME);
+        let mut utterances = match utterances_path.exists() {
+            true => Utterance::read(&utterances_path)?,
+            false => vec![],
+        };
+        utterances.retain(|utterance| utterance.meeting_type != "plenary");
+        utterances.extend(self.utterances.iter().cloned());
+        Utterance::write(&utterances_path, &utterances)?;
+        Ok(())
+    }
+
+    /// Keeps only the rows of the sessions for which `keep` returns true.
+    pub fn retain_sessions(&mut self, keep: impl Fn(u32) -> bool) {
+        let keep = |session_id: &str| keep(session_id.parse().unwrap_or_default());
+        self.meetings.retain(|row| keep(&row.session_id));
+        self.votes.retain(|row| keep(&row.session_id));
+        self.ballots.retain(|row| keep(&row.session_id));
+        self.questions.retain(|row| keep(&row.session_id));
+        self.propositions.retain(|row| keep(&row.session_id));
+        self.id_mappings.retain(|row| keep(&row.session_id));
+        self.utterances.retain(|row| keep(&row.session_id));
+        self.agenda_items.retain(|row| keep(&row.session_id));
+        self.attendance.retain(|row| keep(&row.session_id));
+        self.vote_explanations.retain(|row| keep(&row.session_id));
+        self.vote_corrections.retain(|row| keep(&row.session_id));
+    }
+
+    /// Adds the rows of the next meeting.
+    pub fn append(&mut self, other: MeetingRows) {
+        self.meetings.extend(other.meetings);
+        self.votes.extend(other.votes);
+        self.ballots.extend(other.ballots);
+        self.questions.extend(other.questions);
+        self.propositions.extend(other.propositions);
+        self.id_mappings.extend(other.id_mappings);
+        self.utterances.extend(other.utterances);
+        self.agenda_items.extend(other.agenda_items);
+        self.attendance.extend(other.attendance);
+        self.vote_explanations.extend(other.vote_explanations);
+        self.vote_corrections.extend(other.vote_corrections);
+    }
+
+    /// Replaces the rows of the meetings in `parsed` by its rows, keeping every table ordered by
+    /// meeting.
+    pub fn upsert(&mut self, parsed: MeetingRows) {
+        fn upsert_rows<T>(
+            rows: &mut Vec<T>,
+            parsed: Vec<T>,
+            meetings: &HashSet<(u32, u32)>,
+            key: impl Fn(&T) -> (u32, u32),
+        ) {
+            rows.retain(|row| !meetings.contains(&key(row)));
+            rows.extend(parsed);
+            // The sort is stable, so the rows of a meeting stay in the order they were parsed in.
+            rows.sort_by_key(&key);
+        }
+
+        let meetings: HashSet<(u32, u32)> = parsed
+            .meetings
+            .iter()
+            .map(|meeting| meeting_key(&meeting.session_id, &meeting.meeting_id))
+            .collect();
+        upsert_rows(&mut self.meetings, parsed.meetings, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(&mut self.votes, parsed.votes, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(&mut self.ballots, parsed.ballots, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(&mut self.questions, parsed.questions, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(
+            &mut self.propositions,
+            parsed.propositions,
+            &meetings,
+            |row| meeting_key(&row.session_id, &row.meeting_id),
+        );
+        upsert_rows(
+            &mut self.id_mappings,
+            parsed.id_mappings,
+            &meetings,
+            |row| meeting_key(&row.session_id, &row.meeting_id),
+        );
+        upsert_rows(&mut self.utterances, parsed.utterances, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(
+            &mut self.agenda_items,
+            parsed.agenda_items,
+            &meetings,
+            |row| meeting_key(&row.session_id, &row.meeting_id),
+        );
+        upsert_rows(&mut self.attendance, parsed.attendance, &meetings, |row| {
+            meeting_key(&row.session_id, &row.meeting_id)
+        });
+        upsert_rows(
+            &mut self.vote_explanations,
+            parsed.vote_explanations,
+            &meetings,
+            |row| meeting_key(&row.session_id, &row.meeting_id),
+        );
+        upsert_rows(
+            &mut self.vote_corrections,
+            parsed.vote_corrections,
+            &meetings,
+            |row| meeting_key(&row.session_id, &row.meeting_id),
+        );
+    }
+}
+
+/// The (session, meeting) a row belongs to, ordered numerically.
+pub fn meeting_key(session_id: &str, meeting_id: &str) -> (u32, u32) {
+    (
+        session_id.parse().unwrap_or_default(),
+        meeting_id.parse().unwrap_or_default(),
+    )
+}
@@ -0,0 +1,364 @@
+use crate::rows::MeetingRows;
+use crawl::utils::names::{MemberIndex, normalize_person_name};
+use itertools::Itertools;
+use model::{Ballot, Member, Vote, VoteIssue};
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::error::Error;
+
+/// Reads the outcome a report states for a decision, "adopted" or "rejected", and the majority the
+/// decision needed.
+///
+/// The first sentence that decides anything is the one that counts: "Bijgevolg is het amendement
+/// verworpen en is artikel 2 aangenomen." rejects the amendment that was voted on. The majority is
+/// "two_thirds" or "special" when the report says it was reached ("De tweederdemeerderheid is
+/// bereikt.", a majority in each "taalgroep") or when the decided text is a revision of the
+/// Constitution or a special law.
+pub fn extract_outcome(text: &str) -> (Option<&'static str>, &'static str) {
+    let decision = text.split(". ").find(|sentence| {
+        [
+            "aangenomen",
+            "verworpen",
+            "neemt de Kamer",
+            "keurt de Kamer",
+        ]
+        .iter()
+        .any(|keyword| sentence.contains(keyword))
+    });
+    let outcome = decision.map(|sentence| {
+        if sentence.contains("verworpen") {
+            "rejected"
+        } else {
+            "adopted"
+        }
+    });
+
+    let text = text.to_lowercase();
+    let majority = if text.contains("taalgroep")
+        || decision.is_some_and(|sentence| sentence.contains("bijzondere wet"))
+    {
+        "special"
+    } else if text.contains("tweederdemeerderheid")
+        || text.contains("twee derde")
+        || decision.is_some_and(|sentence| sentence.contains("van de Grondwet"))
+    {
+        "two_thirds"
+    } else {
+        "simple"
+    };
+
+    (outcome, majority)
+}
+
+/// A correction of a ballot in a note after the result of a vote.
+pub struct CorrectionNote {
+    pub name: String,
+    /// "yes", "no" or "abstain", `None` when the member voted like their group.
+    pub choice: Option<&'static str>,
+    pub like_group: bool,
+    pub text: String,
+}
+
+/// Reads the corrections of ballots in the notes of a paragraph: "(De heer Steven Vandeput heeft
+/// voorgestemd.)", "(Mevrouw Alexia Bertrand, de heer Steven Coenegrachts en mevrouw Petra De
+/// Sutter hebben tegengestemd.)", "(M. Denis Ducarme a voté comme son groupe.)".
+pub fn extract_vote_corrections(text: &str) -> Vec<CorrectionNote> {
+    if !text.contains('(') {
+        return Vec::new();
+    }
+    let note_regex = Regex::new(r"\(\s*([^()]+?)\s*\)").unwrap();
+    let correction_regex =
+        Regex::new(r"^(.+?)\s+(?:heeft|hebben|a|ont|s'est|se sont)\s+(.+?)[\s.]*$").unwrap();
+    let title_regex = Regex::new(r"(?i)^(de heer|mevrouw|monsieur|madame|m\.|mme)\s+").unwrap();
+
+    let mut corrections = Vec::new();
+    for note in note_regex.captures_iter(text) {
+        let Some(captures) = correction_regex.captures(&note[1]) else {
+            continue;
+        };
+        let action = captures[2].to_lowercase();
+        let like_group = action.contains("fractie") || action.contains("groupe");
+        let choice = if like_group {
+            None
+        } else if action.contains("onthouden") || action.contains("abstenu") {
+            Some("abstain")
+        } else if action.contains("tegen") || action.contains("contre") {
+            Some("no")
+        } else if action.contains("voor") || action.contains("pour") {
+            Some("yes")
+        } else {
+            continue;
+        };
+        let names = captures[1].replace(" en ", ", ").replace(" et ", ", ");
+        for name in names.split(',') {
+            let name = normalize_person_name(&title_regex.replace(name.trim(), ""));
+            if name.is_empty() {
+                continue;
+            }
+            corrections.push(CorrectionNote {
+                name,
+                choice,
+                like_group,
+                text: note[1].to_string(),
+            });
+        }
+    }
+    corrections
+}
+
+/// Checks every roll-call vote against its ballots: the tally of each choice should equal the
+/// number of names listed for it, and every name should be listed once and match a member of the
+/// session.
+pub fn check_vote_tallies(votes: &[Vote], ballots: &[Ballot]) -> Vec<VoteIssue> {
+    let mut vote_ballots: HashMap<(&str, &str, &str), Vec<&Ballot>> = HashMap::new();
+    for ballot in ballots {
+        vote_ballots
+            .entry((&ballot.session_id, &ballot.meeting_id, &ballot.vote_id))
+            .or_default()
+            .push(ballot);
+    }
+
+    let mut issues = vec![];
+    for vote in votes.iter().filter(|vote| vote.vote_kind == "roll_call") {
+        let ballots = vote_ballots
+            .get(&(
+                vote.session_id.as_str(),
+                vote.meeting_id.as_str(),
+                vote.vote_id.as_str(),
+            ))
+            .map_or(&[][..], Vec::as_slice);
+        let issue = |kind: &str, choice: &str| VoteIssue {
+            session_id: vote.session_id.clone(),
+            meeting_id: vote.meeting_id.clone(),
+            vote_id: vote.vote_id.clone(),
+            kind: kind.to_string(),
+            choice: choice.to_string(),
+            ..Default::default()
+        };
+
+        for (choice, counted) in [
+            ("yes", vote.yes),
+            ("no", vote.no),
+            ("abstain", vote.abstain),
+        ] {
+            let listed = ballots
+                .iter()
+                .filter(|ballot| ballot.choice == choice)
+                .count() as i32;
+            if counted != Some(listed) {
+                issues.push(VoteIssue {
+                    counted,
+                    listed: Some(listed),
+                    ..issue("count_mismatch", choice)
+                });
+            }
+        }
+
+        let mut seen = HashSet::new();
+        for ballot in ballots {
+            if !seen.insert(&ballot.name) {
+                issues.push(VoteIssue {
+                    name: Some(ballot.name.clone()),
+                    ..issue("duplicate_name", &ballot.choice)
+                });
+            }
+            if ballot.member_id.is_none() {
+                issues.push(VoteIssue {
+                    name: Some(ballot.name.clone()),
+                    ..issue("unknown_name", &ballot.choice)
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// The title of a vote and the numbers it mentions.
+pub struct VoteData {
+    pub topic: String,
+    /// "558" in "(558/1-5)".
+    pub dossier_id: Option<String>,
+    /// "1-5" in "(558/1-5)".
+    pub document_id: Option<String>,
+    /// "12" in "(nr. 12)".
+    pub motion_id: Option<String>,
+}
+
+/// Splits the title of a vote into its topic and the dossier, document or motion number at its end.
+pub fn extract_vote_data(vote_text: String) -> Result<VoteData, Box<dyn Error + Send + Sync>> {
+    let regex_1 = Regex::new(r#"^(.*)\((\d+)/(\d+(?:-\d+)?)\)\s*$"#)?; // with (123/1-2)
+    let regex_2 = Regex::new(r#"^(.*)\s+\((?:nr\.|n°)\s*(\d+)\)\s*$"#)?; // with (nr. 12)
+    let regex_3 = Regex::new(r#"^([^(]*)"#)?; // without any number
+
+    if let Some(capture) = regex_1.captures(&vote_text) {
+        let topic = capture
+            .get(1)
+            .ok_or("Missing topic capture")?
+            .as_str()
+            .trim()
+            .to_string();
+        let dossier_id = capture
+            .get(2)
+            .ok_or("Missing dossier_id capture")?
+            .as_str()
+            .trim()
+            .to_string();
+        let document_id = capture
+            .get(3)
+            .ok_or("Missing document_id capture")?
+            .as_str()
+            .trim()
+            .to_string();
+
+        Ok(VoteData {
+            topic,
+            dossier_id: Some(dossier_id),
+            document_id: Some(document_id),
+            motion_id: None,
+        })
+    } else if let Some(capture) = regex_2.captures(&vote_text) {
+        let topic = capture
+            .get(1)
+            .ok_or("Missing topic capture")?
+            .as_str()
+            .trim()
+            .to_string();
+        let motion_id = capture
+            .get(2)
+            .ok_or("Missing motion_id capture")?
+            .as_str()
+            .trim()
+            .to_string();
+
+        Ok(VoteData {
+            topic,
+            dossier_id: None,
+            document_id: None,
+            motion_id: Some(motion_id),
+        })
+    } else if let Some(capture) = regex_3.captures(&vote_text) {
+        let topic = capture
+            .get(1)
+            .ok_or("Missing topic capture")?
+            .as_str()
+            .trim()
+            .to_string();
+
+        Ok(VoteData {
+            topic,
+            dossier_id: None,
+            document_id: None,
+            motion_id: None,
+        })
+    } else {
+        Err("No regex matched for vote text".into())
+    }
+}
+
+/// Sets `tally_mismatch` on the votes whose counts or names cannot be trusted: those with a count
+/// mismatch or a duplicate name. A name that matches no member is only a gap in the member list,
+/// so it stays a diagnostic.
+pub fn flag_tally_mismatches(votes: &mut [Vote], issues: &[VoteIssue]) {
+    let mismatched: HashSet<_> = issues
+        .iter()
+        .filter(|issue| issue.kind != "unknown_name")
+        .map(|issue| (&issue.session_id, &issue.meeting_id, &issue.vote_id))
+        .collect();
+    for vote in votes {
+        vote.tally_mismatch =
+            mismatched.contains(&(&vote.session_id, &vote.meeting_id, &vote.vote_id));
+    }
+}
+
+/// Matches the names in the vote corrections to members and fills in the choice on their ballot
+/// and, for members who voted like their group, the choice most other members of their party made.
+/// That choice is left empty when their party is unknown or its members are split evenly. Then sets the `corrected_choice` of every ballot. Returns the names that match no member.
+pub fn apply_vote_corrections(
+    rows: &mut MeetingRows,
+    members: &[Member],
+    member_index: &MemberIndex,
+) -> Vec<String> {
+    let parties: HashMap<(&str, &str), &str> = members
+        .iter()
+        .map(|member| {
+            (
+                (member.session_id.as_str(), member.member_id.as_str()),
+                member.party.as_str(),
+            )
+        })
+        .collect();
+    let mut unmatched = BTreeSet::new();
+    for correction in &mut rows.vote_corrections {
+        correction.member_id = member_index
+            .find(&correction.session_id, &correction.name)
+            .map(str::to_string);
+        let Some(member_id) = correction.member_id.as_deref() else {
+            unmatched.insert(correction.name.clone());
+            continue;
+        };
+        let ballots = rows.ballots.iter().filter(|ballot| {
+            ballot.session_id == correction.session_id
+                && ballot.meeting_id == correction.meeting_id
+                && ballot.vote_id == correction.vote_id
+        });
+        correction.recorded_choice = ballots
+            .clone()
+            .find(|ballot| ballot.member_id.as_deref() == Some(member_id))
+            .map(|ballot| ballot.choice.clone());
+
+        if correction.like_group {
+            let Some(party) = parties
+                .get(&(correction.session_id.as_str(), member_id))
+                .filter(|party| !party.is_empty())
+            else {
+                continue;
+            };
+            let mut counts: HashMap<&str, usize> = HashMap::new();
+            for ballot in ballots {
+                let Some(voter) = ballot.member_id.as_deref() else {
+                    continue;
+                };
+                let same_party =
+                    parties.get(&(correction.session_id.as_str(), voter)) == Some(party);
+                if voter != member_id && same_party {
+                    *counts.entry(&ballot.choice).or_default() += 1;
+                }
+            }
+            correction.intended_choice = match counts
+                .into_iter()
+                .max_set_by_key(|&(_, count)| count)
+                .as_slice()
+            {
+                [(choice, _)] => Some(choice.to_string()),
+                _ => None,
+            };
+        }
+    }
+
+    let corrected: HashMap<(&str, &str, &str, &str), &str> = rows
+        .vote_corrections
+        .iter()
+        .filter_map(|correction| {
+            let key = (
+                correction.session_id.as_str(),
+                correction.meeting_id.as_str(),
+                correction.vote_id.as_str(),
+                correction.member_id.as_deref()?,
+            );
+            Some((key, correction.intended_choice.as_deref()?))
+        })
+        .collect();
+    for ballot in &mut rows.ballots {
+        let key = (
+            ballot.session_id.as_str(),
+            ballot.meeting_id.as_str(),
+            ballot.vote_id.as_str(),
+            ballot.member_id.as_deref().unwrap_or_default(),
+        );
+        ballot.corrected_choice = corrected
+            .get(&key)
+            .unwrap_or(&ballot.choice.as_str())
+            .to_string();
+    }
+    unmatched.into_iter().collect()
+}
@@ -0,0 +1,196 @@
+use plenary_scraper::agenda::{agenda_item_type, extract_agenda_rows, split_agenda_items};
+use scraper::Html;
+use std::collections::HashMap;
+
+const REPORT: &str = r#"
+    <h2>01 Agenda</h2>
+    <h2>01 Ordre du jour</h2>
+    <p>Bijgevolg is de agenda aangenomen.</p>
+    <h1>Mondelinge vragen</h1>
+    <h1>Questions orales</h1>
+    <h2>02 Samengevoegde vragen van</h2>
+    <h2>- Sarah Schlitz aan Frank Vandenbroucke (VEM Sociale Zaken) over "De betoging"</h2>
+    <h2>- Sofie Merckx aan Frank Vandenbroucke (VEM Sociale Zaken) over "De actie"</h2>
+    <h2>02 Questions jointes de</h2>
+    <h2>- Sarah Schlitz à Frank Vandenbroucke (VPM Affaires sociales) sur "La manifestation"</h2>
+    <p>02.01 Sarah Schlitz (Ecolo): Monsieur le ministre, ...</p>
+    <p></p>
+    <p>02.02 Minister Frank Vandenbroucke: Mevrouw Schlitz, ...</p>
+    <h1>Naamstemmingen</h1>
+    <h1>Votes nominatifs</h1>
+    <h2>03 Wetsontwerp houdende diverse bepalingen</h2>
+    <h2>03 Projet de loi portant des dispositions diverses</h2>
+    <table><tr><td><p>Ja</p></td><td><p>80</p></td></tr></table>
+    <p>Bijgevolg is het wetsontwerp aangenomen.</p>
+    <h1>DETAIL VAN DE NAAMSTEMMINGEN</h1>
+    <h2>04 Not an item</h2>
+    <p>Vote nominatif - Naamstemming: 001</p>
+"#;
+
+#[test]
+fn splits_a_report_into_agenda_items() {
+    let items = split_agenda_items(&Html::parse_document(REPORT));
+
+    let numbers: Vec<_> = items.iter().map(|item| item.number.as_str()).collect();
+    assert_eq!(numbers, ["01", "02", "03"]);
+    assert_eq!(items[0].section, "");
+    assert_eq!(items[1].section, "Questions orales");
+    assert_eq!(items[2].section, "Votes nominatifs");
+    assert_eq!(items[0].paragraphs, ["Bijgevolg is de agenda aangenomen."]);
+}
+
+#[test]
+fn keeps_joined_questions_under_their_heading() {
+    let items = split_agenda_items(&Html::parse_document(REPORT));
+
+    assert_eq!(
+        items[1].headings,
+        [
+            "Samengevoegde vragen van\n\
+             - Sarah Schlitz aan Frank Vandenbroucke (VEM Sociale Zaken) over 'De betoging'\n\
+             - Sofie Merckx aan Frank Vandenbroucke (VEM Sociale Zaken) over 'De actie'",
+            "Questions jointes de\n\
+             - Sarah Schlitz à Frank Vandenbroucke (VPM Affaires sociales) sur 'La manifestation'",
+        ]
+    );
+    assert_eq!(
+        items[1].paragraphs,
+        [
+            "02.01 Sarah Schlitz (Ecolo): Monsieur le ministre, ...",
+            "02.02 Minister Frank Vandenbroucke: Mevrouw Schlitz, ...",
+        ]
+    );
+}
+
+#[test]
+fn skips_tables_and_the_vote_details() {
+    let items = split_agenda_items(&Html::parse_document(REPORT));
+
+    assert_eq!(
+        items[2].paragraphs,
+        ["Bijgevolg is het wetsontwerp aangenomen."]
+    );
+    assert!(items.iter().all(|item| item.number != "04"));
+}
+
+#[test]
+fn classifies_items_by_section_and_heading() {
+    let cases = [
+        ("", "Regeling van de werkzaamheden", "point_of_order"),
+        ("Mondelinge vragen", "Persoonlijk feit", "point_of_order"),
+        ("", "Verklaring van de regering", "government_statement"),
+        (
+            "",
+            "Vraag van Dieter Keuten aan Pierre-Yves Dermagne",
+            "question",
+        ),
+        ("Questions orales", "Questions jointes de", "question"),
+        // A questions section can end with other items.
+        ("Mondelinge vragen", "Mededelingen", "other"),
+        (
+            "Interpellaties",
+            "Interpellatie van Kurt Moons",
+            "interpellation",
+        ),
+        (
+            "Naamstemmingen",
+            "Wetsontwerp houdende diverse bepalingen",
+            "vote",
+        ),
+        ("Mededelingen", "Commissies", "communication"),
+        (
+            "Wetsontwerpen en wetsvoorstellen",
+            "Wetsontwerp tot goedkeuring",
+            "bill",
+        ),
+        ("Begroting", "Algemene uitgavenbegroting", "bill"),
+        ("Varia", "Benoemingscommissies voor het notariaat", "other"),
+    ];
+
+    for (section, headings, item_type) in cases {
+        assert_eq!(
+            agenda_item_type(section, headings),
+            item_type,
+            "{} / {}",
+            section,
+            headings
+        );
+    }
+}
+
+#[test]
+fn builds_agenda_items_and_their_utterances() {
+    let question_items = HashMap::from([("02".to_string(), "56000551P".to_string())]);
+    let (items, utterances) =
+        extract_agenda_rows(&Html::parse_document(REPORT), 56, 36, &question_items);
+
+    let items: Vec<_> = items
+        .iter()
+        .map(|item| {
+            (
+                item.item_id.as_str(),
+                item.item_type.as_str(),
+                item.title_nl.lines().next().unwrap_or_default(),
+                item.title_fr.lines().next().unwrap_or_default(),
+                item.question_id.as_deref(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            ("01", "other", "Agenda", "Ordre du jour", None),
+            (
+                "02",
+                "question",
+                "Samengevoegde vragen van",
+                "Questions jointes de",
+                Some("56000551P")
+            ),
+            (
+                "03",
+                "vote",
+                "Wetsontwerp houdende diverse bepalingen",
+                "Projet de loi portant des dispositions diverses",
+                None
+            ),
+        ]
+    );
+
+    let utterances: Vec<_> = utterances
+        .iter()
+        .map(|utterance| {
+            (
+                utterance.item_id.as_deref(),
+                utterance.question_id.as_deref(),
+                utterance.sequence,
+                utterance.role.as_str(),
+                utterance.language.as_deref(),
+            )
+        })
+        .collect();
+    // The minister's turn is too short to tell its language.
+    assert_eq!(
+        utterances,
+        [
+            (Some("02"), Some("56000551P"), 0, "member", Some("fr")),
+            (Some("02"), Some("56000551P"), 1, "minister", None),
+        ]
+    );
+}
+
+#[test]
+fn reads_dossier_and_question_numbers_from_the_headings() {
+    let report = r#"
+        <h1>Wetsontwerpen en wetsvoorstellen</h1>
+        <h2>05 Wetsontwerp houdende diverse bepalingen (558/1-5)</h2>
+        <h2>05 Projet de loi portant des dispositions diverses (558/1-5)</h2>
+        <h2>06 Vraag van Kurt Moons aan Annelies Verlinden over "De gevangenissen" (56000182I)</h2>
+    "#;
+    let (items, _) = extract_agenda_rows(&Html::parse_document(report), 56, 36, &HashMap::new());
+
+    assert_eq!(items[0].dossier_ids, ["558"]);
+    assert_eq!(items[0].item_type, "bill");
+    assert_eq!(items[1].dossier_ids, ["56000182I"]);
+    assert_eq!(items[1].title_fr, "");
+}
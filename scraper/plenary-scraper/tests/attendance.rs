@@ -0,0 +1,257 @@
+use chrono::NaiveDate;
+use crawl::utils::names::MemberIndex;
+use model::{Attendance, Ballot, Meeting, Member};
+use plenary_scraper::attendance::{extract_excused, fill_attendance};
+use plenary_scraper::rows::MeetingRows;
+use scraper::Html;
+
+fn excused(body: &str) -> Vec<(String, Option<String>, Option<String>)> {
+    extract_excused(&Html::parse_document(body))
+        .into_iter()
+        .map(|excused| (excused.name, excused.reason_nl, excused.reason_fr))
+        .collect()
+}
+
+fn entry(name: &str, reasons: Option<(&str, &str)>) -> (String, Option<String>, Option<String>) {
+    (
+        name.to_string(),
+        reasons.map(|(nl, _)| nl.to_string()),
+        reasons.map(|(_, fr)| fr.to_string()),
+    )
+}
+
+#[test]
+fn reads_names_with_their_reasons() {
+    let body = r#"
+        <p>De vergadering wordt geopend om 14.17 uur.</p>
+        <p>Berichten van verhindering</p>
+        <p>Excusés</p>
+        <p>Jan Peeters, Anna Smet, ziek / malade;</p>
+        <p>Kristof Calvo, met zending buitenslands / en mission à l'étranger.</p>
+        <p></p>
+        <p>Vanessa Matz, Servais Verherstraeten.</p>
+    "#;
+
+    assert_eq!(
+        excused(body),
+        [
+            entry("Jan Peeters", Some(("ziek", "malade"))),
+            entry("Anna Smet", Some(("ziek", "malade"))),
+            entry(
+                "Kristof Calvo",
+                Some(("met zending buitenslands", "en mission à l'étranger"))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn reads_names_without_a_reason() {
+    let body = r#"
+        <p>Hebben zich verontschuldigd:</p>
+        <p>Se sont excusés:</p>
+        <p>Ludivine Dedonder, Alexander De Croo, Theo Francken.</p>
+        <h1>Mondelinge vragen</h1>
+        <p>Pierre-Yves Dermagne, Petra De Sutter.</p>
+    "#;
+
+    assert_eq!(
+        excused(body),
+        [
+            entry("Ludivine Dedonder", None),
+            entry("Alexander De Croo", None),
+            entry("Theo Francken", None),
+        ]
+    );
+}
+
+#[test]
+fn reads_a_list_in_the_paragraph_of_its_heading() {
+    let body = r#"
+        <p>Berichten van verhindering - Excusés: Sophie Rohonyi, zwangerschapsverlof / congé de
+        maternité</p>
+        <p></p>
+    "#;
+
+    assert_eq!(
+        excused(body),
+        [entry(
+            "Sophie Rohonyi",
+            Some(("zwangerschapsverlof", "congé de maternité"))
+        )]
+    );
+}
+
+#[test]
+fn finds_nothing_without_the_heading() {
+    let body = r#"
+        <p>De vergadering wordt geopend om 14.17 uur.</p>
+        <p>Jan Peeters, Anna Smet, ziek / malade</p>
+    "#;
+
+    assert!(excused(body).is_empty());
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+fn member(member_id: &str, first_name: &str, last_name: &str, start: u32, active: bool) -> Member {
+    Member {
+        member_id: member_id.to_string(),
+        session_id: "56".to_string(),
+        first_name: first_name.to_string(),
+        last_name: last_name.to_string(),
+        active,
+        start: Some(date(start)),
+        ..Default::default()
+    }
+}
+
+fn meeting(meeting_id: &str, day: u32) -> Meeting {
+    Meeting {
+        session_id: "56".to_string(),
+        meeting_id: meeting_id.to_string(),
+        date: date(day),
+        ..Default::default()
+    }
+}
+
+fn ballot(meeting_id: &str, member_id: &str) -> Ballot {
+    Ballot {
+        session_id: "56".to_string(),
+        meeting_id: meeting_id.to_string(),
+        vote_id: "1".to_string(),
+        member_id: Some(member_id.to_string()),
+        ..Default::default()
+    }
+}
+
+fn excused_row(meeting_id: &str, name: &str) -> Attendance {
+    Attendance {
+        session_id: "56".to_string(),
+        meeting_id: meeting_id.to_string(),
+        name: name.to_string(),
+        status: "excused".to_string(),
+        ..Default::default()
+    }
+}
+
+/// An attendance row as (meeting_id, member_id, status).
+type Row = (String, Option<String>, String);
+
+/// Fills the attendance and returns it with the unmatched names.
+fn fill(mut rows: MeetingRows, members: &[Member]) -> (Vec<Row>, Vec<String>) {
+    let index = MemberIndex::new(members.iter().map(|member| {
+        (
+            member.session_id.as_str(),
+            member.member_id.as_str(),
+            member.first_name.as_str(),
+            member.last_name.as_str(),
+        )
+    }));
+    let unmatched = fill_attendance(&mut rows, members, &index);
+    let attendance = rows
+        .attendance
+        .into_iter()
+        .map(|row| (row.meeting_id, row.member_id, row.status))
+        .collect();
+    (attendance, unmatched)
+}
+
+fn row(meeting_id: &str, member_id: &str, status: &str) -> Row {
+    (
+        meeting_id.to_string(),
+        Some(member_id.to_string()),
+        status.to_string(),
+    )
+}
+
+#[test]
+fn an_excused_member_who_voted_stays_excused() {
+    let members = [
+        member("1", "Jan", "Peeters", 1, true),
+        member("2", "Anna", "Smet", 1, true),
+        member("3", "Kurt", "Moons", 1, true),
+    ];
+    let rows = MeetingRows {
+        meetings: vec![meeting("10", 6)],
+        ballots: vec![ballot("10", "1"), ballot("10", "2")],
+        attendance: vec![
+            excused_row("10", "Jan Peeters"),
+            excused_row("10", "Sarah Schlitz"),
+        ],
+        ..Default::default()
+    };
+
+    let (attendance, unmatched) = fill(rows, &members);
+
+    assert_eq!(
+        attendance,
+        [
+            row("10", "1", "excused"),
+            ("10".to_string(), None, "excused".to_string()),
+            row("10", "2", "voted"),
+            row("10", "3", "absent"),
+        ]
+    );
+    assert_eq!(unmatched, ["Sarah Schlitz"]);
+}
+
+#[test]
+fn leaves_out_members_outside_their_mandate() {
+    let members = [
+        member("1", "Jan", "Peeters", 1, true),
+        // Sworn in after the first meeting.
+        member("2", "Anna", "Smet", 10, true),
+        // No longer a member, and last voted at the first meeting.
+        member("3", "Kurt", "Moons", 1, false),
+    ];
+    let rows = MeetingRows {
+        meetings: vec![meeting("10", 6), meeting("11", 13)],
+        ballots: vec![
+            ballot("10", "1"),
+            ballot("10", "3"),
+            ballot("11", "1"),
+            ballot("11", "2"),
+        ],
+        ..Default::default()
+    };
+
+    let (attendance, _) = fill(rows, &members);
+
+    assert_eq!(
+        attendance,
+        [
+            row("10", "1", "voted"),
+            row("10", "3", "voted"),
+            row("11", "1", "voted"),
+            row("11", "2", "voted"),
+        ]
+    );
+}
+
+#[test]
+fn skips_meetings_without_a_roll_call_vote() {
+    let members = [
+        member("1", "Jan", "Peeters", 1, true),
+        member("2", "Anna", "Smet", 1, true),
+    ];
+    let rows = MeetingRows {
+        meetings: vec![meeting("10", 6), meeting("11", 13)],
+        ballots: vec![ballot("11", "1")],
+        attendance: vec![excused_row("10", "Anna Smet")],
+        ..Default::default()
+    };
+
+    let (attendance, _) = fill(rows, &members);
+
+    assert_eq!(
+        attendance,
+        [
+            row("10", "2", "excused"),
+            row("11", "1", "voted"),
+            row("11", "2", "absent"),
+        ]
+    );
+}
@@ -0,0 +1,132 @@
+use chrono::NaiveDate;
+use plenary_scraper::decisions::{extract_decision_votes, extract_decisions};
+use plenary_scraper::ids::RowIds;
+use scraper::Html;
+
+fn decisions(body: &str) -> Vec<(&'static str, String, String, Option<&'static str>)> {
+    extract_decisions(&Html::parse_document(body))
+        .into_iter()
+        .map(|decision| {
+            (
+                decision.kind,
+                decision.title_nl,
+                decision.title_fr,
+                decision.outcome,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn finds_decisions_by_sitting_and_standing() {
+    let body = r#"
+        <h2>20 Urgentieverzoek van de regering</h2>
+        <h2>20 Demande d'urgence émanant du gouvernement</h2>
+        <p>De voorzitter: Ik stel u voor om ons over deze vraag uit te spreken.</p>
+        <p>Je vous propose de nous prononcer sur cette demande.</p>
+        <p>De urgentie wordt aangenomen bij zitten en opstaan.</p>
+        <p>L'urgence est adoptée par assis et levé.</p>
+        <h2>21 Urgentieverzoek van de heer Van Lommel</h2>
+        <h2>21 Demande d'urgence de M. Van Lommel</h2>
+        <p>De urgentie wordt verworpen bij zitten en opstaan.</p>
+    "#;
+
+    assert_eq!(
+        decisions(body),
+        [
+            (
+                "show_of_hands",
+                "Urgentieverzoek van de regering".to_string(),
+                "Demande d'urgence émanant du gouvernement".to_string(),
+                Some("adopted")
+            ),
+            (
+                "show_of_hands",
+                "Urgentieverzoek van de heer Van Lommel".to_string(),
+                "Demande d'urgence de M. Van Lommel".to_string(),
+                Some("rejected")
+            ),
+        ]
+    );
+}
+
+#[test]
+fn finds_texts_adopted_without_objection() {
+    // The French heading comes first in some items.
+    let body = r#"
+        <h2>13 Ordre du jour</h2>
+        <h2>13 Agenda</h2>
+        <p>Overeenkomstig het advies van de Conferentie van voorzitters hebt u een gewijzigde agenda
+        ontvangen.</p>
+        <p>Zijn er dienaangaande opmerkingen? (Nee)</p>
+        <p>Y a-t-il une observation à ce sujet? (Non)</p>
+        <p>Bijgevolg is de agenda aangenomen.</p>
+        <p>En conséquence, l'ordre du jour est adopté.</p>
+    "#;
+
+    assert_eq!(
+        decisions(body),
+        [(
+            "unanimous",
+            "Agenda".to_string(),
+            "Ordre du jour".to_string(),
+            Some("adopted")
+        )]
+    );
+}
+
+#[test]
+fn an_objection_question_only_applies_to_the_next_paragraph() {
+    let body = r#"
+        <h2>14 Wetsontwerp houdende diverse bepalingen</h2>
+        <h2>14 Projet de loi portant des dispositions diverses</h2>
+        <p>Zijn er opmerkingen? (Nee)</p>
+        <p>De algemene bespreking is gesloten.</p>
+        <p>Het wetsontwerp wordt later aangenomen.</p>
+    "#;
+
+    assert!(decisions(body).is_empty());
+}
+
+#[test]
+fn skips_decisions_outside_of_an_agenda_item() {
+    let body = r#"
+        <h1>Geloofsbrieven</h1>
+        <p>Geen bezwaar? (Nee)</p>
+        <p>De besluiten van de commissie zijn aangenomen.</p>
+    "#;
+
+    assert!(decisions(body).is_empty());
+}
+
+#[test]
+fn turns_decisions_into_votes_without_a_tally() {
+    let body = r#"
+        <h2>20 Wetsontwerp houdende diverse bepalingen (558/1-5)</h2>
+        <h2>20 Projet de loi portant des dispositions diverses (558/1-5)</h2>
+        <p>Het wetsontwerp wordt aangenomen bij zitten en opstaan.</p>
+    "#;
+    let mut ids = RowIds::new(56, 36);
+    let date = NaiveDate::from_ymd_opt(2025, 3, 6).unwrap();
+
+    let votes = extract_decision_votes(&Html::parse_document(body), &mut ids, date).unwrap();
+
+    assert_eq!(votes.len(), 1);
+    let vote = &votes[0];
+    assert!(vote.vote_id.starts_with('d'));
+    assert_eq!(vote.session_id, "56");
+    assert_eq!(vote.meeting_id, "36");
+    assert_eq!(vote.title_nl, "Wetsontwerp houdende diverse bepalingen");
+    assert_eq!(
+        vote.title_fr,
+        "Projet de loi portant des dispositions diverses"
+    );
+    assert_eq!(vote.dossier_id.as_deref(), Some("558"));
+    assert_eq!(vote.document_id.as_deref(), Some("1-5"));
+    assert_eq!(vote.vote_kind, "show_of_hands");
+    assert_eq!(vote.outcome.as_deref(), Some("adopted"));
+    assert_eq!((vote.yes, vote.no, vote.abstain), (None, None, None));
+    assert!(!vote.tally_mismatch);
+    // Decisions never had an old ID.
+    assert!(ids.mappings.is_empty());
+}
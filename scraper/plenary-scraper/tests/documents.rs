@@ -0,0 +1,223 @@
+use model::DocumentText;
+use pdf_extract::content::{Content, Operation};
+use pdf_extract::{Document, Object, Stream, dictionary};
+use plenary_scraper::documents::{TextRun, extract_document_text, page_columns};
+
+/// Draws `lines` in 10 point Helvetica from (`x`, `y`) down, 12 points apart. The font is in
+/// WinAnsi, so the accents of the French text are one Latin-1 byte each.
+fn draw(operations: &mut Vec<Operation>, x: f64, y: f64, lines: &[&str]) {
+    for (i, line) in lines.iter().enumerate() {
+        operations.extend([
+            Operation::new("BT", vec![]),
+            Operation::new("Tf", vec!["F1".into(), 10.into()]),
+            Operation::new("Td", vec![x.into(), (y - 12.0 * i as f64).into()]),
+            Operation::new(
+                "Tj",
+                vec![Object::string_literal(
+                    line.chars().map(|c| c as u8).collect::<Vec<_>>(),
+                )],
+            ),
+            Operation::new("ET", vec![]),
+        ]);
+    }
+}
+
+/// An A4 PDF with a page for each list of operations.
+fn pdf(pages: Vec<Vec<Operation>>) -> Vec<u8> {
+    let mut document = Document::with_version("1.5");
+    let pages_id = document.new_object_id();
+    let font_id = document.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type1",
+        "BaseFont" => "Helvetica",
+        "Encoding" => "WinAnsiEncoding",
+    });
+    let resources_id =
+        document.add_object(dictionary! { "Font" => dictionary! { "F1" => font_id } });
+    let count = pages.len() as i64;
+    let kids: Vec<Object> = pages
+        .into_iter()
+        .map(|operations| {
+            let content = Content { operations }.encode().unwrap();
+            let content_id = document.add_object(Stream::new(dictionary! {}, content));
+            document
+                .add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "Contents" => content_id,
+                })
+                .into()
+        })
+        .collect();
+    document.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => count,
+            "Resources" => resources_id,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+        }),
+    );
+    let catalog_id = document.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    document.trailer.set("Root", catalog_id);
+
+    let mut bytes = vec![];
+    document.save_to(&mut bytes).unwrap();
+    bytes
+}
+
+/// A bilingual document as the Chamber prints them: a heading across the page, the Dutch text in
+/// the left column and the French text in the right one, then a page in Dutch only and a page with
+/// a table of figures.
+fn bilingual_document() -> Vec<u8> {
+    let mut first = vec![];
+    draw(&mut first, 250.0, 800.0, &["DOC 56 0456/002"]);
+    draw(
+        &mut first,
+        50.0,
+        760.0,
+        &[
+            "Het amendement wordt door de",
+            "commissie aangenomen en het is",
+            "niet van toepassing op de wet",
+            "die voor ons ligt.",
+        ],
+    );
+    draw(
+        &mut first,
+        320.0,
+        760.0,
+        &[
+            "L'amendement est adopté par la",
+            "commission et il ne s'applique",
+            "pas à la loi qui est devant nous",
+            "dans cette assemblée.",
+        ],
+    );
+    let mut second = vec![];
+    draw(
+        &mut second,
+        50.0,
+        760.0,
+        &[
+            "Dit is een regel die over de hele breedte van de pagina loopt en niet in kolommen staat.",
+            "Het is ook een tweede regel van de tekst die voor de wet is en die wij willen.",
+        ],
+    );
+    let mut third = vec![];
+    draw(
+        &mut third,
+        50.0,
+        760.0,
+        &["2024 2025 2026", "1.250 1.300 1.375"],
+    );
+    pdf(vec![first, second, third])
+}
+
+fn extract(pdf: &[u8]) -> DocumentText {
+    extract_document_text(DocumentText::default(), pdf).unwrap()
+}
+
+#[test]
+fn splits_bilingual_pages_into_their_languages() {
+    let text = extract(&bilingual_document());
+
+    assert_eq!(text.pages, 3);
+    let nl = text.text_nl.unwrap();
+    let fr = text.text_fr.unwrap();
+    assert!(nl.starts_with("DOC 56 0456/002\nHet amendement wordt door de\n"));
+    assert!(nl.contains("die voor ons ligt."));
+    assert!(nl.contains("Dit is een regel die over de hele breedte"));
+    assert!(!nl.contains("L'amendement"));
+    assert_eq!(
+        fr,
+        "L'amendement est adopté par la\ncommission et il ne s'applique\npas à la loi qui est \
+         devant nous\ndans cette assemblée."
+    );
+}
+
+#[test]
+fn keeps_columns_in_no_language_apart() {
+    let text = extract(&bilingual_document());
+
+    assert_eq!(
+        text.text_other.as_deref(),
+        Some("2024 2025 2026\n1.250 1.300 1.375")
+    );
+    // The full text has every column, in reading order.
+    let columns: Vec<_> = text.text.split("\n\n").collect();
+    assert_eq!(columns.len(), 4);
+    assert!(columns[0].starts_with("DOC 56 0456/002"));
+    assert!(columns[1].starts_with("L'amendement"));
+    assert!(columns[2].starts_with("Dit is een regel"));
+    assert_eq!(columns[3], "2024 2025 2026\n1.250 1.300 1.375");
+}
+
+#[test]
+fn leaves_a_language_without_pages_empty() {
+    let mut page = vec![];
+    draw(
+        &mut page,
+        50.0,
+        760.0,
+        &["La séance est ouverte et la Chambre est en nombre."],
+    );
+
+    let text = extract(&pdf(vec![page]));
+
+    assert_eq!(text.text_nl, None);
+    assert_eq!(
+        text.text_fr.as_deref(),
+        Some("La séance est ouverte et la Chambre est en nombre.")
+    );
+    assert_eq!(text.text_other, None);
+}
+
+#[test]
+fn fails_on_files_that_are_not_pdfs() {
+    assert!(extract_document_text(DocumentText::default(), b"%PDF-1.4 garbage").is_err());
+    assert!(extract_document_text(DocumentText::default(), b"<html>Not found</html>").is_err());
+}
+
+fn run(left: f64, right: f64, y: f64, text: &str) -> TextRun {
+    TextRun {
+        left,
+        right,
+        y,
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn splits_a_page_in_columns_only_when_lines_keep_to_their_half() {
+    // The heading is less than a tenth of the text.
+    let columns = [
+        run(280.0, 320.0, 40.0, "DOC 56"),
+        run(50.0, 270.0, 80.0, "Het wetsontwerp wordt door de"),
+        run(320.0, 540.0, 80.0, "Le projet de loi est adopté par"),
+        run(50.0, 270.0, 92.0, "commissie eenparig aangenomen."),
+        run(320.0, 540.0, 92.0, "la commission à l'unanimité."),
+    ];
+    assert_eq!(
+        page_columns(297.5, &columns),
+        [
+            "DOC 56\nHet wetsontwerp wordt door de\ncommissie eenparig aangenomen.",
+            "Le projet de loi est adopté par\nla commission à l'unanimité.",
+        ]
+    );
+
+    let full_width = [
+        run(
+            50.0,
+            540.0,
+            80.0,
+            "Een regel over de hele breedte van de pagina.",
+        ),
+        run(50.0, 200.0, 92.0, "Een korte regel."),
+    ];
+    assert_eq!(
+        page_columns(297.5, &full_width),
+        ["Een regel over de hele breedte van de pagina.\nEen korte regel."]
+    );
+}
@@ -0,0 +1,183 @@
+use model::{DocumentStatus, DocumentType};
+use plenary_scraper::dossiers::{dossier_event_kind, parse_dated_entries, scrape_dossier};
+use scraper::Html;
+
+/// The page of dossier 56K0717, without its images, links and sidebar.
+const DOSSIER_PAGE: &str = r##"
+    <div id="story">
+    <h3><center>Parlementair Document 56K0717</center></h3>
+    <h4><center>
+        Gewijzigd huishoudelijk Reglement van de Centrale toezichtsraad voor het gevangeniswezen.
+    </center></h4>
+    <table>
+    <tr><td class="td1x"> </td><td class="td0x"> 13/02/2025 </td></tr>
+    <tr><td class="td1x"> Stemming Kamer</td><td class="td0x">27/02/2025</td></tr>
+    <tr>
+        <td class="td1x" valign="top"><b>Document Kamer</b></td>
+        <td class="td0x">
+            <a href="/FLWB/PDF/56/0717/56K0717001.pdf" target="_blank">56K0717001</a> 410 Kb<br>
+            VERSLAG - KAMER<br>
+            Zittingsperiode : 56 - Zitting : 2024/2025-0
+        </td>
+    </tr>
+    <tr><td class="td1x"> Indieningsdatum</td><td class="td0x">13/02/2025</td></tr>
+    <tr><td class="td1x"> Document type</td><td class="td0x">23 VERSLAG</td></tr>
+    <tr><td class="td1x" valign="top"> Auteur(s)</td>
+        <td class="td0x">Commission/Commissie (AUTEUR)<br></td></tr>
+    <tr><td class="td1x"> Einddatum</td><td class="td0x">27/02/2025</td></tr>
+    <tr><td class="td1x"> Status</td><td class="td0x">16 AANGENOMEN </td></tr>
+    <tr>
+        <td class="td1x" valign="top"><b>PLENAIRE VERGADERING KAMER</b></td>
+        <td class="td0x">PLENUMVERGADERING (OPENBAAR)<br></td>
+    </tr>
+    <tr>
+        <td class="td1x" valign="top"> Kalender</td>
+        <td class="td0x">
+            27/02/2025 &nbsp; IN BESPREKING <br>
+            27/02/2025 &nbsp; START VERGADERING <br>
+            27/02/2025 &nbsp; AANGENOMEN <br>
+        </td>
+    </tr>
+    <tr>
+        <td class="td1x" valign="top"> Subdocumenten </td>
+        <td class="td0x">
+            <table>
+            <tr>
+                <td class="td0xs"><a href="/FLWB/PDF/56/0717/56K0717002.pdf">002</a></td>
+                <td class="td0xs">20/02/2025 <font color="#006600">TABELLEN OF LIJSTEN</font></td>
+            </tr>
+            <tr><td class="td0xs1"> Datum ronddeling</td><td class="td0xs">20/02/2025</td></tr>
+            <tr><td class="td0xs">&nbsp;</td></tr>
+            </table>
+        </td>
+    </tr>
+    <tr><td class="td1x" valign="top"><b>Eurovoc-hoofddescriptor</b></td>
+        <td class="td0x">OPENBARE VEILIGHEID</td></tr>
+    <tr><td class="td1x" valign="top"><b>Eurovoc descriptoren</b></td>
+        <td class="td0x">CONTROLEORGAAN | GEVANGENISWEZEN</td></tr>
+    </table>
+    </div>
+"##;
+
+#[test]
+fn reads_a_dossier_page() {
+    let dossier = scrape_dossier("0717", &Html::parse_document(DOSSIER_PAGE)).unwrap();
+
+    assert_eq!(
+        dossier.title,
+        "Gewijzigd huishoudelijk Reglement van de Centrale toezichtsraad voor het gevangeniswezen."
+    );
+    assert_eq!(dossier.submission_date, "13/02/2025");
+    assert_eq!(dossier.vote_date, "27/02/2025");
+    assert_eq!(dossier.end_date, "27/02/2025");
+    assert_eq!(dossier.document_type, Ok(DocumentType::Verslag));
+    assert_eq!(dossier.status, Ok(DocumentStatus::Aangenomen));
+    assert_eq!(
+        dossier
+            .events
+            .iter()
+            .map(|event| (event.date.as_str(), event.kind, event.stage))
+            .collect::<Vec<_>>(),
+        [
+            ("13/02/2025", "submitted", None),
+            ("27/02/2025", "discussed", Some("plenary")),
+            ("27/02/2025", "meeting", Some("plenary")),
+            ("27/02/2025", "adopted", Some("plenary")),
+        ]
+    );
+    assert_eq!(
+        dossier.documents,
+        [
+            (
+                "001".to_string(),
+                "/FLWB/PDF/56/0717/56K0717001.pdf".to_string()
+            ),
+            (
+                "002".to_string(),
+                "/FLWB/PDF/56/0717/56K0717002.pdf".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn reads_the_subdocuments_and_keywords_of_a_dossier() {
+    let dossier = scrape_dossier("0717", &Html::parse_document(DOSSIER_PAGE)).unwrap();
+
+    assert_eq!(
+        dossier
+            .subdocuments
+            .iter()
+            .map(|subdocument| (
+                subdocument.id.as_str(),
+                subdocument.date.as_str(),
+                subdocument.document_type.clone()
+            ))
+            .collect::<Vec<_>>(),
+        [("002", "20/02/2025", Ok(DocumentType::TabellenOfLijsten))]
+    );
+    assert_eq!(
+        dossier.keywords,
+        [
+            ("main_descriptor", "OPENBARE VEILIGHEID".to_string()),
+            ("descriptor", "CONTROLEORGAAN".to_string()),
+            ("descriptor", "GEVANGENISWEZEN".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn fails_on_a_page_without_a_dossier() {
+    let page = r#"<div id="story"><h4><center>Geen document gevonden</center></h4></div>"#;
+
+    assert!(scrape_dossier("9999", &Html::parse_document(page)).is_err());
+}
+
+#[test]
+fn fails_on_subdocuments_without_their_table() {
+    let page = r#"
+        <table><tbody>
+        <tr><td class="td1x"> Subdocumenten </td><td class="td0x">002</td></tr>
+        </tbody></table>
+    "#;
+
+    assert!(scrape_dossier("0717", &Html::parse_document(page)).is_err());
+}
+
+#[test]
+fn splits_a_calendar_into_dated_entries() {
+    assert_eq!(
+        parse_dated_entries(
+            "24/09/2025 IN BESPREKING (Zie opmerking in volledige fiche) 21/10/2025   AANGENOMEN"
+        ),
+        [
+            ("24/09/2025".to_string(), "IN BESPREKING".to_string()),
+            ("21/10/2025".to_string(), "AANGENOMEN".to_string()),
+        ]
+    );
+    assert!(parse_dated_entries("Geen kalender").is_empty());
+}
+
+#[test]
+fn classifies_calendar_entries() {
+    let cases = [
+        ("IN BESPREKING", "discussed"),
+        ("START VERGADERING", "meeting"),
+        ("AANGENOMEN", "adopted"),
+        ("VERWERPING AANGENOMEN", "rejected"),
+        ("Urgentie aangevraagd en aangenomen", "urgency_adopted"),
+        ("URGENTIE VERWORPEN", "urgency_rejected"),
+        ("ZONDER VERSLAG", "without_report"),
+        ("TERUGZENDING NAAR COMMISSIE", "sent_back"),
+        (
+            "VRAAG OM ADVIES VAN DE RAAD VAN STATE",
+            "council_of_state_requested",
+        ),
+        ("BEEINDIGD", "ended"),
+        ("AMENDEMENTEN INGEDIEND", "other"),
+    ];
+
+    for (description, kind) in cases {
+        assert_eq!(dossier_event_kind(description), kind, "{}", description);
+    }
+}
@@ -0,0 +1,123 @@
+use plenary_scraper::ids::{
+    RowIds, content_id, decision_stable_id, question_stable_id, vote_number, vote_stable_id,
+};
+
+fn mappings(ids: &RowIds) -> Vec<(&str, &str, &str, &str, &str)> {
+    ids.mappings
+        .iter()
+        .map(|mapping| {
+            (
+                mapping.session_id.as_str(),
+                mapping.meeting_id.as_str(),
+                mapping.kind.as_str(),
+                mapping.old_id.as_str(),
+                mapping.new_id.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn identifies_roll_call_votes_by_their_number() {
+    // "(Stemming/vote 3)" heads the vote whose names are listed under "Naamstemming: 3".
+    let number = vote_number("Stemming 3");
+
+    assert_eq!(number, "3");
+    assert_eq!(
+        vote_stable_id(&number, "Wetsontwerp houdende diverse bepalingen"),
+        "3"
+    );
+    assert_eq!(
+        vote_stable_id(&vote_number(""), "Wetsontwerp houdende diverse bepalingen"),
+        "375358f3ec20"
+    );
+}
+
+#[test]
+fn identifies_questions_by_their_first_question_number() {
+    let heading = "Mondelinge vraag van Kurt Moons over de spoorwegen";
+    let dossier_ids = [
+        "56K0717".to_string(),
+        "Q".to_string(),
+        "Q56001154P".to_string(),
+        "Q56001160P".to_string(),
+    ];
+
+    assert_eq!(question_stable_id(&dossier_ids, heading), "56001154P");
+    assert_eq!(
+        question_stable_id(&dossier_ids[..2], heading),
+        "0f116e4a2f7c"
+    );
+    assert_eq!(question_stable_id(&[], heading), "0f116e4a2f7c");
+}
+
+#[test]
+fn hashes_rows_without_a_number() {
+    assert_eq!(
+        content_id("Wetsontwerp houdende diverse bepalingen"),
+        "375358f3ec20"
+    );
+    assert_eq!(
+        content_id("  Wetsontwerp houdende diverse bepalingen\n"),
+        "375358f3ec20"
+    );
+    assert_eq!(
+        decision_stable_id(
+            "unanimous",
+            "Motie van aanbeveling",
+            "De Kamer neemt de motie aan."
+        ),
+        "db4b42b8c1e56"
+    );
+}
+
+#[test]
+fn keeps_hashed_ids_when_rows_move() {
+    let bill = content_id("Wetsontwerp houdende diverse bepalingen");
+    let motion = content_id("Motie van wantrouwen");
+    let mut before = RowIds::new(56, 12);
+    let mut after = RowIds::new(56, 12);
+
+    let bill_before = before.assign("proposition", "1".to_string(), bill.clone());
+    let motion_before = before.assign("proposition", "2".to_string(), motion.clone());
+    // A correction of the report puts the motion first.
+    let motion_after = after.assign("proposition", "1".to_string(), motion);
+    let bill_after = after.assign("proposition", "2".to_string(), bill);
+
+    assert_eq!(bill_before, bill_after);
+    assert_eq!(motion_before, motion_after);
+    assert_eq!(mappings(&before)[0].3, "1");
+    assert_eq!(mappings(&after)[1].3, "2");
+    assert_eq!(mappings(&after)[1].4, bill_before);
+}
+
+#[test]
+fn numbers_rows_that_share_an_id() {
+    let mut ids = RowIds::new(56, 12);
+
+    assert_eq!(ids.unique("vote", "3".to_string()), "3");
+    assert_eq!(ids.unique("vote", "3".to_string()), "3-2");
+    assert_eq!(ids.unique("vote", "3".to_string()), "3-3");
+    // Each kind has its own IDs.
+    assert_eq!(ids.unique("proposition", "3".to_string()), "3");
+}
+
+#[test]
+fn records_the_positions_the_ids_replace() {
+    let mut ids = RowIds::new(56, 12);
+
+    ids.assign("vote", "1".to_string(), "3".to_string());
+    ids.assign("vote", "2".to_string(), "3".to_string());
+    ids.assign("question", "1".to_string(), "56001154P".to_string());
+    // Rows that never had a position, such as decisions without a roll call, are not mapped.
+    ids.unique("vote", "d375358f3ec20".to_string());
+
+    assert_eq!(
+        mappings(&ids),
+        [
+            ("56", "12", "vote", "1", "3"),
+            ("56", "12", "vote", "2", "3-2"),
+            ("56", "12", "question", "1", "56001154P"),
+        ]
+    );
+}
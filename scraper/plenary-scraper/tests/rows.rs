@@ -0,0 +1,122 @@
+use model::{Meeting, Utterance, Vote};
+use plenary_scraper::rows::{MeetingRows, meeting_key};
+
+fn meeting(session_id: &str, meeting_id: &str) -> Meeting {
+    Meeting {
+        session_id: session_id.to_string(),
+        meeting_id: meeting_id.to_string(),
+        ..Meeting::default()
+    }
+}
+
+fn vote(session_id: &str, meeting_id: &str, vote_id: &str) -> Vote {
+    Vote {
+        session_id: session_id.to_string(),
+        meeting_id: meeting_id.to_string(),
+        vote_id: vote_id.to_string(),
+        ..Vote::default()
+    }
+}
+
+fn rows(meetings: Vec<Meeting>, votes: Vec<Vote>) -> MeetingRows {
+    MeetingRows {
+        meetings,
+        votes,
+        ..MeetingRows::default()
+    }
+}
+
+fn vote_ids(rows: &MeetingRows) -> Vec<(&str, &str, &str)> {
+    rows.votes
+        .iter()
+        .map(|vote| {
+            (
+                vote.session_id.as_str(),
+                vote.meeting_id.as_str(),
+                vote.vote_id.as_str(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn orders_meetings_numerically() {
+    assert!(meeting_key("56", "9") < meeting_key("56", "10"));
+    assert!(meeting_key("55", "300") < meeting_key("56", "1"));
+}
+
+#[test]
+fn upsert_replaces_the_rows_of_parsed_meetings() {
+    let mut existing = rows(
+        vec![meeting("56", "1"), meeting("56", "2")],
+        vec![
+            vote("56", "1", "1"),
+            vote("56", "2", "1"),
+            vote("56", "2", "2"),
+        ],
+    );
+
+    // Meeting 2 was parsed again and lost a vote, meeting 10 is new.
+    existing.upsert(rows(
+        vec![meeting("56", "10"), meeting("56", "2")],
+        vec![vote("56", "10", "1"), vote("56", "2", "3")],
+    ));
+
+    assert_eq!(
+        existing
+            .meetings
+            .iter()
+            .map(|meeting| meeting.meeting_id.as_str())
+            .collect::<Vec<_>>(),
+        ["1", "2", "10"]
+    );
+    assert_eq!(
+        vote_ids(&existing),
+        [("56", "1", "1"), ("56", "2", "3"), ("56", "10", "1")]
+    );
+}
+
+#[test]
+fn upsert_keeps_the_order_rows_were_parsed_in() {
+    let mut existing = rows(vec![meeting("56", "1")], vec![vote("56", "1", "1")]);
+
+    existing.upsert(rows(
+        vec![meeting("56", "1")],
+        vec![vote("56", "1", "2"), vote("56", "1", "1")],
+    ));
+
+    assert_eq!(vote_ids(&existing), [("56", "1", "2"), ("56", "1", "1")]);
+}
+
+#[test]
+fn retains_only_the_requested_sessions() {
+    let mut existing = rows(
+        vec![meeting("55", "1"), meeting("56", "1")],
+        vec![vote("55", "1", "1"), vote("56", "1", "1")],
+    );
+
+    existing.retain_sessions(|session_id| session_id != 56);
+
+    assert_eq!(existing.meetings.len(), 1);
+    assert_eq!(vote_ids(&existing), [("55", "1", "1")]);
+}
+
+#[test]
+fn reads_back_the_tables_it_wrote() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(MeetingRows::read(dir.path()).unwrap().is_none());
+
+    let mut written = rows(vec![meeting("56", "1")], vec![vote("56", "1", "1")]);
+    written.utterances = vec![Utterance {
+        session_id: "56".to_string(),
+        meeting_id: "1".to_string(),
+        meeting_type: "plenary".to_string(),
+        ..Utterance::default()
+    }];
+    written.write(dir.path()).unwrap();
+
+    let read = MeetingRows::read(dir.path()).unwrap().unwrap();
+    assert_eq!(read.meetings, written.meetings);
+    assert_eq!(read.votes, written.votes);
+    assert_eq!(read.utterances, written.utterances);
+}
@@ -0,0 +1,391 @@
+use crawl::utils::names::MemberIndex;
+use model::{Ballot, Member, Vote, VoteCorrection};
+use plenary_scraper::rows::MeetingRows;
+use plenary_scraper::votes::{
+    apply_vote_corrections, check_vote_tallies, extract_outcome, extract_vote_corrections,
+    extract_vote_data, flag_tally_mismatches,
+};
+
+#[test]
+fn reads_the_outcome_of_a_vote() {
+    assert_eq!(
+        extract_outcome("Bijgevolg is het wetsontwerp aangenomen. Il est par conséquent adopté."),
+        (Some("adopted"), "simple")
+    );
+    assert_eq!(
+        extract_outcome("Bijgevolg is de motie verworpen."),
+        (Some("rejected"), "simple")
+    );
+    assert_eq!(
+        extract_outcome("Bijgevolg neemt de Kamer de motie aan."),
+        (Some("adopted"), "simple")
+    );
+    assert_eq!(extract_outcome("(Stemming/vote 3)"), (None, "simple"));
+}
+
+#[test]
+fn the_first_sentence_that_decides_counts() {
+    assert_eq!(
+        extract_outcome("Bijgevolg is het amendement verworpen en is artikel 2 aangenomen."),
+        (Some("rejected"), "simple")
+    );
+    assert_eq!(
+        extract_outcome("Het amendement is aangenomen. Het volgende wordt later verworpen."),
+        (Some("adopted"), "simple")
+    );
+}
+
+#[test]
+fn reads_the_required_majority() {
+    assert_eq!(
+        extract_outcome(
+            "De tweederdemeerderheid is bereikt. Bijgevolg is het voorstel aangenomen."
+        ),
+        (Some("adopted"), "two_thirds")
+    );
+    assert_eq!(
+        extract_outcome(
+            "Bijgevolg is het voorstel tot herziening van artikel 7bis van de Grondwet aangenomen."
+        ),
+        (Some("adopted"), "two_thirds")
+    );
+    assert_eq!(
+        extract_outcome(
+            "De meerderheid in elke taalgroep is bereikt. Bijgevolg is het wetsvoorstel aangenomen."
+        ),
+        (Some("adopted"), "special")
+    );
+    assert_eq!(
+        extract_outcome("Bijgevolg is het voorstel van bijzondere wet aangenomen."),
+        (Some("adopted"), "special")
+    );
+}
+
+fn corrections(text: &str) -> Vec<(String, Option<&'static str>, bool)> {
+    extract_vote_corrections(text)
+        .into_iter()
+        .map(|note| (note.name, note.choice, note.like_group))
+        .collect()
+}
+
+#[test]
+fn reads_the_corrections_of_ballots() {
+    assert_eq!(
+        corrections(
+            "Bijgevolg is de motie aangenomen. (De heer Steven Vandeput heeft voorgestemd.)"
+        ),
+        [("Steven Vandeput".to_string(), Some("yes"), false)]
+    );
+    assert_eq!(
+        corrections("(Mme Sophie Thémont s'est abstenue.)"),
+        [("Sophie Thémont".to_string(), Some("abstain"), false)]
+    );
+    assert_eq!(
+        corrections("(M. Denis Ducarme a voté comme son groupe.)"),
+        [("Denis Ducarme".to_string(), None, true)]
+    );
+}
+
+#[test]
+fn reads_corrections_of_several_members() {
+    let text = "(Mevrouw Alexia Bertrand, de heer Steven Coenegrachts en mevrouw Petra De Sutter \
+                hebben tegengestemd.)";
+
+    assert_eq!(
+        corrections(text),
+        [
+            ("Alexia Bertrand".to_string(), Some("no"), false),
+            ("Steven Coenegrachts".to_string(), Some("no"), false),
+            ("Petra De Sutter".to_string(), Some("no"), false),
+        ]
+    );
+    assert_eq!(
+        extract_vote_corrections(text)[0].text,
+        "Mevrouw Alexia Bertrand, de heer Steven Coenegrachts en mevrouw Petra De Sutter hebben \
+         tegengestemd."
+    );
+}
+
+#[test]
+fn ignores_other_notes() {
+    assert!(corrections("Bijgevolg is het wetsontwerp aangenomen.").is_empty());
+    assert!(corrections("(Applaus op de banken van de meerderheid)").is_empty());
+    assert!(corrections("(De heer Peter De Roover heeft het woord gevraagd.)").is_empty());
+}
+
+fn roll_call(vote_id: &str, yes: i32, no: i32, abstain: i32) -> Vote {
+    Vote {
+        session_id: "56".to_string(),
+        meeting_id: "60".to_string(),
+        vote_id: vote_id.to_string(),
+        vote_kind: "roll_call".to_string(),
+        yes: Some(yes),
+        no: Some(no),
+        abstain: Some(abstain),
+        ..Vote::default()
+    }
+}
+
+fn ballot(vote_id: &str, name: &str, choice: &str, member_id: Option<&str>) -> Ballot {
+    Ballot {
+        session_id: "56".to_string(),
+        meeting_id: "60".to_string(),
+        vote_id: vote_id.to_string(),
+        name: name.to_string(),
+        choice: choice.to_string(),
+        member_id: member_id.map(str::to_string),
+        ..Ballot::default()
+    }
+}
+
+#[test]
+fn finds_no_issues_when_the_names_match_the_tally() {
+    let votes = [
+        roll_call("1", 2, 0, 1),
+        Vote {
+            vote_kind: "unanimous".to_string(),
+            ..roll_call("d1", 0, 0, 0)
+        },
+    ];
+    let ballots = [
+        ballot("1", "Jan Peeters", "yes", Some("1")),
+        ballot("1", "Anna Smet", "yes", Some("2")),
+        ballot("1", "Kristof Calvo", "abstain", Some("3")),
+    ];
+
+    assert!(check_vote_tallies(&votes, &ballots).is_empty());
+}
+
+#[test]
+fn finds_tallies_that_differ_from_the_names() {
+    let votes = [roll_call("1", 2, 1, 0)];
+    let ballots = [
+        ballot("1", "Jan Peeters", "yes", Some("1")),
+        ballot("1", "Anna Smet", "no", Some("2")),
+        ballot("1", "Kristof Calvo", "no", Some("3")),
+    ];
+
+    let issues: Vec<_> = check_vote_tallies(&votes, &ballots)
+        .into_iter()
+        .map(|issue| (issue.kind, issue.choice, issue.counted, issue.listed))
+        .collect();
+    assert_eq!(
+        issues,
+        [
+            (
+                "count_mismatch".to_string(),
+                "yes".to_string(),
+                Some(2),
+                Some(1)
+            ),
+            (
+                "count_mismatch".to_string(),
+                "no".to_string(),
+                Some(1),
+                Some(2)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn finds_duplicate_and_unknown_names() {
+    let votes = [roll_call("1", 2, 1, 0)];
+    let ballots = [
+        ballot("1", "Jan Peeters", "yes", Some("1")),
+        ballot("1", "Jan Peeters", "yes", Some("1")),
+        ballot("1", "Jan Peters", "no", None),
+    ];
+
+    let issues: Vec<_> = check_vote_tallies(&votes, &ballots)
+        .into_iter()
+        .map(|issue| (issue.kind, issue.choice, issue.name))
+        .collect();
+    assert_eq!(
+        issues,
+        [
+            (
+                "duplicate_name".to_string(),
+                "yes".to_string(),
+                Some("Jan Peeters".to_string())
+            ),
+            (
+                "unknown_name".to_string(),
+                "no".to_string(),
+                Some("Jan Peters".to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn flags_votes_with_wrong_counts_or_duplicate_names_only() {
+    let mut votes = [
+        roll_call("1", 1, 0, 0),
+        roll_call("2", 1, 0, 0),
+        roll_call("3", 1, 0, 0),
+        roll_call("4", 1, 0, 0),
+    ];
+    let ballots = [
+        // A count mismatch.
+        ballot("1", "Jan Peeters", "yes", Some("1")),
+        ballot("1", "Anna Smet", "yes", Some("2")),
+        // A duplicate name, and the count is right without it.
+        ballot("2", "Jan Peeters", "yes", Some("1")),
+        ballot("2", "Jan Peeters", "yes", Some("1")),
+        // Only a name that matches no member.
+        ballot("3", "Jan Peters", "yes", None),
+        ballot("4", "Jan Peeters", "yes", Some("1")),
+    ];
+
+    let issues = check_vote_tallies(&votes, &ballots);
+    flag_tally_mismatches(&mut votes, &issues);
+
+    let flagged: Vec<_> = votes.iter().map(|vote| vote.tally_mismatch).collect();
+    assert_eq!(flagged, [true, true, false, false]);
+}
+
+#[test]
+fn splits_the_numbers_off_a_vote_title() {
+    let data =
+        extract_vote_data("Wetsontwerp houdende diverse bepalingen (558/1-5)".into()).unwrap();
+    assert_eq!(data.topic, "Wetsontwerp houdende diverse bepalingen");
+    assert_eq!(data.dossier_id.as_deref(), Some("558"));
+    assert_eq!(data.document_id.as_deref(), Some("1-5"));
+    assert_eq!(data.motion_id, None);
+
+    let data =
+        extract_vote_data("Moties ingediend tot besluit van de interpellatie (nr. 12)".into())
+            .unwrap();
+    assert_eq!(data.motion_id.as_deref(), Some("12"));
+    assert_eq!(data.dossier_id, None);
+}
+
+fn member(member_id: &str, first_name: &str, last_name: &str, party: &str) -> Member {
+    Member {
+        member_id: member_id.to_string(),
+        session_id: "56".to_string(),
+        first_name: first_name.to_string(),
+        last_name: last_name.to_string(),
+        party: party.to_string(),
+        active: true,
+        ..Member::default()
+    }
+}
+
+fn correction(name: &str, intended_choice: Option<&str>) -> VoteCorrection {
+    VoteCorrection {
+        session_id: "56".to_string(),
+        meeting_id: "60".to_string(),
+        vote_id: "1".to_string(),
+        name: name.to_string(),
+        intended_choice: intended_choice.map(str::to_string),
+        like_group: intended_choice.is_none(),
+        ..VoteCorrection::default()
+    }
+}
+
+/// Applies `corrections` to the ballots of vote 1, cast by members 1 to 5 in that order.
+fn apply(choices: [&str; 5], corrections: Vec<VoteCorrection>) -> MeetingRows {
+    let members = [
+        member("1", "Steven", "Vandeput", "N-VA"),
+        member("2", "Kristien", "Van Vaerenbergh", "N-VA"),
+        member("3", "Michael", "Freilich", "N-VA"),
+        member("4", "Denis", "Ducarme", "MR"),
+        member("5", "Jan", "Peeters", ""),
+    ];
+    let index = MemberIndex::new(members.iter().map(|member| {
+        (
+            member.session_id.as_str(),
+            member.member_id.as_str(),
+            member.first_name.as_str(),
+            member.last_name.as_str(),
+        )
+    }));
+    let mut rows = MeetingRows {
+        ballots: members
+            .iter()
+            .zip(choices)
+            .filter(|(_, choice)| !choice.is_empty())
+            .map(|(member, choice)| ballot("1", &member.last_name, choice, Some(&member.member_id)))
+            .collect(),
+        vote_corrections: corrections,
+        ..MeetingRows::default()
+    };
+    let unmatched = apply_vote_corrections(&mut rows, &members, &index);
+    assert!(unmatched.is_empty());
+    rows
+}
+
+fn corrected_choices(rows: &MeetingRows) -> Vec<&str> {
+    rows.ballots
+        .iter()
+        .map(|ballot| ballot.corrected_choice.as_str())
+        .collect()
+}
+
+#[test]
+fn corrects_the_ballot_of_a_member() {
+    let rows = apply(
+        ["no", "no", "yes", "yes", "no"],
+        vec![correction("Steven Vandeput", Some("yes"))],
+    );
+
+    let correction = &rows.vote_corrections[0];
+    assert_eq!(correction.member_id.as_deref(), Some("1"));
+    assert_eq!(correction.recorded_choice.as_deref(), Some("no"));
+    assert_eq!(corrected_choices(&rows), ["yes", "no", "yes", "yes", "no"]);
+}
+
+#[test]
+fn a_member_who_voted_like_their_group_takes_the_choice_of_most_of_their_party() {
+    let rows = apply(
+        ["abstain", "yes", "yes", "no", "no"],
+        vec![correction("Steven Vandeput", None)],
+    );
+
+    assert_eq!(
+        rows.vote_corrections[0].intended_choice.as_deref(),
+        Some("yes")
+    );
+    assert_eq!(corrected_choices(&rows), ["yes", "yes", "yes", "no", "no"]);
+}
+
+#[test]
+fn a_group_split_evenly_or_unknown_gives_no_choice() {
+    let rows = apply(
+        ["abstain", "yes", "no", "no", "abstain"],
+        vec![
+            correction("Steven Vandeput", None),
+            // No other member of the party voted.
+            correction("Denis Ducarme", None),
+            // No party.
+            correction("Jan Peeters", None),
+        ],
+    );
+
+    let intended: Vec<_> = rows
+        .vote_corrections
+        .iter()
+        .map(|correction| correction.intended_choice.as_deref())
+        .collect();
+    assert_eq!(intended, [None, None, None]);
+    assert_eq!(
+        corrected_choices(&rows),
+        ["abstain", "yes", "no", "no", "abstain"]
+    );
+}
+
+#[test]
+fn keeps_the_correction_of_a_member_without_a_ballot() {
+    let rows = apply(
+        ["", "yes", "yes", "no", "no"],
+        vec![correction("Steven Vandeput", Some("no"))],
+    );
+
+    let correction = &rows.vote_corrections[0];
+    assert_eq!(correction.member_id.as_deref(), Some("1"));
+    assert_eq!(correction.recorded_choice, None);
+    assert_eq!(correction.intended_choice.as_deref(), Some("no"));
+    assert_eq!(corrected_choices(&rows), ["yes", "yes", "no", "no"]);
+}
@@ -8,25 +8,20 @@
 //! chrono = "0.4"
 //! regex = "1"
 //! crawl = { path = "../crawl" }
-//! parquet = "54.3.0"
-//! arrow = "54.3.0"
+//! model = { path = "../../model" }
 //! ```
 
-use arrow::array::{ArrayRef, StringArray};
-use arrow::datatypes::{DataType, Field, Schema};
-use arrow::record_batch::RecordBatch;
 use chrono::NaiveDate;
 use crawl::client::ScrapingClient;
-use crawl::utils::{dutch_language_to_language_code, dutch_month_to_number, slugify_name};
-use parquet::arrow::ArrowWriter;
+use crawl::utils::{dutch_language_to_language_code, dutch_month_to_number};
+use model::{LobbyEntry, Table};
 use regex::Regex;
 use scraper::{ElementRef, Html, Selector};
 use std::error::Error;
-use std::fs::{read_to_string, File};
+use std::fs::read_to_string;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
@@ -34,25 +29,13 @@ use tokio::io::AsyncWriteExt;
 async fn main() -> Result<(), Box<dyn Error>> {
     // Create folders.
     let root = PathBuf::from("web/src/data");
-    let lobby_path = root.join("lobby.parquet");
+    let lobby_path = root.join(LobbyEntry::FILE_NAME);
     if let Some(parent) = lobby_path.parent() {
         fs::create_dir_all(parent).await?;
     }
 
-    // Create parquet file.
-    let lobby_file = File::create(lobby_path).unwrap();
-    let lobby_schema = Arc::new(Schema::new(vec![
-        Field::new("name", DataType::Utf8, false),
-        Field::new("contacts", DataType::Utf8, false),
-        Field::new("interests", DataType::Utf8, false),
-        Field::new("url", DataType::Utf8, false),
-    ]));
-
     // Scrape data.
-    let mut names = vec![];
-    let mut contacts = vec![];
-    let mut interests = vec![];
-    let mut urls = vec![];
+    let mut entries = vec![];
 
     // Check if file already exists.
     let filename = format!("scraper/data/sources/lobby/lobbyregister.html");
@@ -66,29 +49,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let content = read_to_string(filepath)?;
     let document = Html::parse_document(&content);
 
-    extract_lobby(
-        document,
-        &mut names,
-        &mut contacts,
-        &mut interests,
-        &mut urls
-    )
-    .await?;
-
-
-    let batch = RecordBatch::try_new(
-        lobby_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(names)) as ArrayRef,
-            Arc::new(StringArray::from(contacts)),
-            Arc::new(StringArray::from(interests)),
-            Arc::new(StringArray::from(urls)),
-        ],
-    )?;
-
-    let mut lobby = ArrowWriter::try_new(lobby_file, lobby_schema, None)?;
-    lobby.write(&batch)?;
-    lobby.close().unwrap();
+    extract_lobby(document, &mut entries).await?;
+
+    // Create parquet file.
+    LobbyEntry::write(&lobby_path, &entries)?;
 
     Ok(())
 }
@@ -96,10 +60,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
 async fn extract_lobby(
     parent_document: Html,
-    names: &mut Vec<String>,
-    contacts: &mut Vec<String>,
-    interests: &mut Vec<String>,
-    urls: &mut Vec<String>,
+    entries: &mut Vec<LobbyEntry>,
 ) -> Result<(), Box<dyn Error>> {
     let total_rows = parent_document.select(&Selector::parse("tr").unwrap()).count();
 
@@ -116,10 +77,12 @@ async fn extract_lobby(
         let interest = extract_from_row(&row, 2);
         let url = extract_from_row(&row, 3);
 
-        names.push(name.to_string());
-        contacts.push(contact.to_string());
-        interests.push(interest.to_string());
-        urls.push(url.to_string());
+        entries.push(LobbyEntry {
+            name: name.to_string(),
+            contacts: contact.to_string(),
+            interests: interest.to_string(),
+            url: Some(url).filter(|url| !url.is_empty()),
+        });
 
         processed_rows += 1;
     }
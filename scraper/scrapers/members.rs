@@ -5,41 +5,44 @@
 //! scraper = "0.12"
 //! tokio = { version = "1", features = ["full"] }
 //! csv = "1.1"
-//! chrono = "0.4"
 //! regex = "1"
+//! chrono = "0.4"
 //! crawl = { path = "../crawl" }
-//! parquet = "54.3.0"
-//! arrow = "54.3.0"
+//! model = { path = "../../model" }
 //! ```
 
-use arrow::array::{ArrayRef, StringArray};
-use arrow::datatypes::{DataType, Field, Schema};
-use arrow::record_batch::RecordBatch;
 use chrono::NaiveDate;
+use crawl::cache::{CachePolicy, HttpCache};
 use crawl::client::ScrapingClient;
-use crawl::utils::{dutch_language_to_language_code, dutch_month_to_number, slugify_name};
-use parquet::arrow::ArrowWriter;
+use crawl::urls::Urls;
+use crawl::utils::dates::parse_date;
+use crawl::utils::dutch_language_to_language_code;
+use crawl::utils::names::{normalize_person_name, person_slug};
+use model::Table;
 use regex::Regex;
 use scraper::{ElementRef, Html, Selector};
 use std::collections::HashSet;
 use std::error::Error;
-use std::fs::{File, read_to_string};
+use std::fs::File;
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
+/// Member lists and details change when someone joins, leaves or switches party, so cached copies
+/// are revalidated weekly.
+const MEMBER_PAGE_MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     // Create client.
-    let client = ScrapingClient::new();
+    let client = ScrapingClient::new().with_cache(HttpCache::new("scraper/data/cache"));
+    let urls = Urls::from_env();
 
     // NOTE: !!! Page 56 here is not same as 'today' so for session 56 also add today
 
-    //https://www.dekamer.be/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=cvlist54.cfm?legis=56&today=n
-
     let mut seen_members: HashSet<(i32, u64)> = HashSet::new();
 
     let mut sessions: Vec<((i32, bool), String)> = Vec::new();
@@ -48,7 +51,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     sessions.push(
         (
             (56, true),
-            "https://www.dekamer.be/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=/site/wwwcfm/depute/cvlist54.cfm".to_string()
+            urls.member_list()
         )
     );
 
@@ -59,97 +62,33 @@ async fn main() -> Result<(), Box<dyn Error>> {
             .map(|i| {
                 (
                     i,
-                    format!(
-                        "https://www.dekamer.be/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=cvlist54.cfm?legis={:02}&today=n",
-                        i.0
-                    )
+                    urls.session_member_list(i.0 as u32)
                 )
             })
     );
 
     // Create folders.
     let root = PathBuf::from("./web/src/data");
-    let members_path = root.join("members.parquet");
+    let members_path = root.join(model::Member::FILE_NAME);
     if let Some(parent) = members_path.parent() {
         fs::create_dir_all(parent).await?;
     }
 
-    // Create parquet file.
-    let members_file = File::create(members_path).unwrap();
-    let members_schema = Arc::new(Schema::new(vec![
-        Field::new("member_id", DataType::Utf8, false),
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("first_name", DataType::Utf8, false),
-        Field::new("last_name", DataType::Utf8, false),
-        Field::new("gender", DataType::Utf8, false),
-        Field::new("date_of_birth", DataType::Utf8, false),
-        Field::new("place_of_birth", DataType::Utf8, false),
-        Field::new("language", DataType::Utf8, false),
-        Field::new("constituency", DataType::Utf8, false),
-        Field::new("party", DataType::Utf8, false),
-        Field::new("fraction", DataType::Utf8, false),
-        Field::new("email", DataType::Utf8, false),
-        Field::new("active", DataType::Utf8, false),
-        Field::new("start", DataType::Utf8, false),
-    ]));
-
     // Scrape data.
-    let mut member_ids = vec![];
-    let mut session_ids = vec![];
-    let mut first_names = vec![];
-    let mut last_names = vec![];
-    let mut genders = vec![];
-    let mut birth_dates = vec![];
-    let mut birth_places = vec![];
-    let mut languages = vec![];
-    let mut constituencies = vec![];
-    let mut parties = vec![];
-    let mut fractions = vec![];
-    let mut emails = vec![];
-    let mut actives = vec![];
-    let mut starts = vec![];
-
-    let mut web_request_count = 0;
+    let mut members = vec![];
 
-    for session in sessions {
-        // Check if file already exists.
-        let filename = format!("scraper/data/sources/sessions/{:?}/index.html", session.0);
-        let filepath = Path::new(&filename);
-
-        // Download members html if it does not exist yet.
-        if !filepath.exists() {
-            let response = client.get(&session.1).await?;
-            web_request_count += 1;
-            let content = response.text().await?;
-            if let Some(parent) = filepath.parent() {
-                std::fs::create_dir_all(parent).unwrap();
-            }
-            std::fs::write(filepath, content).unwrap();
-        }
 
+    for session in sessions {
         // Fetch and parse the HTML content.
-        let content = read_to_string(filepath)?;
-        let document = Html::parse_document(&content);
+        let page = client.fetch(&session.1, CachePolicy::MaxAge(MEMBER_PAGE_MAX_AGE)).await?;
+        let document = Html::parse_document(&page.text());
 
         extract_members(
             &client,
+            &urls,
             document,
             session.0,
-            &mut session_ids,
-            &mut member_ids,
-            &mut first_names,
-            &mut last_names,
-            &mut genders,
-            &mut birth_dates,
-            &mut birth_places,
-            &mut languages,
-            &mut constituencies,
-            &mut parties,
-            &mut fractions,
-            &mut emails,
-            &mut actives,
-            &mut starts,
-            &mut web_request_count,
+            &mut members,
             &mut seen_members,
         )
         .await?;
@@ -157,48 +96,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Add hardcoded members.
     append_hardcoded_members(
-        &mut session_ids,
-        &mut member_ids,
-        &mut first_names,
-        &mut last_names,
-        &mut genders,
-        &mut birth_dates,
-        &mut birth_places,
-        &mut languages,
-        &mut constituencies,
-        &mut parties,
-        &mut fractions,
-        &mut emails,
-        &mut actives,
-        &mut starts,
+        &mut members,
         &mut seen_members,
     );
 
-    let batch = RecordBatch::try_new(
-        members_schema.clone(),
-        vec![
-            Arc::new(StringArray::from(member_ids)) as ArrayRef,
-            Arc::new(StringArray::from(session_ids)),
-            Arc::new(StringArray::from(first_names)),
-            Arc::new(StringArray::from(last_names)),
-            Arc::new(StringArray::from(genders)),
-            Arc::new(StringArray::from(birth_dates)),
-            Arc::new(StringArray::from(birth_places)),
-            Arc::new(StringArray::from(languages)),
-            Arc::new(StringArray::from(constituencies)),
-            Arc::new(StringArray::from(parties)),
-            Arc::new(StringArray::from(fractions)),
-            Arc::new(StringArray::from(emails)),
-            Arc::new(StringArray::from(actives)),
-            Arc::new(StringArray::from(starts)),
-        ],
-    )?;
-
-    let mut members = ArrowWriter::try_new(members_file, members_schema, None)?;
-    members.write(&batch)?;
-    members.close().unwrap();
-
-    println!("Scraped data using {} web request(s).", web_request_count);
+    // Create parquet file.
+    model::Member::write(&members_path, &members)?;
+
+    println!("Scraped data using {}.", client.stats());
 
     Ok(())
 }
@@ -212,23 +117,10 @@ struct Member {
 
 async fn extract_members(
     client: &ScrapingClient,
+    urls: &Urls,
     parent_document: Html,
     session_info: (i32, bool),
-    session_ids: &mut Vec<String>,
-    member_ids: &mut Vec<String>,
-    first_names: &mut Vec<String>,
-    last_names: &mut Vec<String>,
-    genders: &mut Vec<String>,
-    birth_dates: &mut Vec<String>,
-    birth_places: &mut Vec<String>,
-    languages: &mut Vec<String>,
-    constituencies: &mut Vec<String>,
-    parties: &mut Vec<String>,
-    fractions: &mut Vec<String>,
-    emails: &mut Vec<String>,
-    actives: &mut Vec<String>,
-    starts: &mut Vec<String>,
-    web_request_count: &mut u32,
+    members: &mut Vec<model::Member>,
     seen_members: &mut HashSet<(i32, u64)>,
 ) -> Result<(), Box<dyn Error>> {
     let total_rows = parent_document
@@ -261,9 +153,9 @@ async fn extract_members(
                 let mut parts: Vec<&str> = name.split_whitespace().collect();
                 if parts.len() > 1 {
                     let first_name = parts.pop().unwrap(); // Take the last word as the first name
-                    format!("{} {}", first_name, parts.join(" ")) // Join the rest as the last name
+                    normalize_person_name(&format!("{} {}", first_name, parts.join(" "))) // Join the rest as the last name
                 } else {
-                    name // If there's only one word, return it as is
+                    normalize_person_name(&name) // If there's only one word, return it as is
                 }
             }
         };
@@ -277,26 +169,10 @@ async fn extract_members(
             Some(email) => email.chars().rev().collect::<String>(),
         };
 
-        // Check if file already exists.
-        let filename = format!("scraper/data/sources/members/{}/details.html", name);
-        let filepath = Path::new(&filename);
-
-        // Download details html if it does not exist yet.
-        if !filepath.exists() {
-            let member_page_url = format!("https://www.dekamer.be/kvvcr/{}", link);
-
-            // Fetch and parse the HTML content.
-            let content = client.get(&member_page_url).await?.text().await?;
-            *web_request_count += 1;
-            if let Some(parent) = filepath.parent() {
-                std::fs::create_dir_all(parent).unwrap();
-            }
-            std::fs::write(filepath, content).unwrap();
-        }
-
         // Fetch and parse the HTML content.
-        let content = read_to_string(filepath)?;
-        let member_document = Html::parse_document(&content);
+        let member_page_url = urls.member_page(&link);
+        let page = client.fetch(&member_page_url, CachePolicy::MaxAge(MEMBER_PAGE_MAX_AGE)).await?;
+        let member_document = Html::parse_document(&page.text());
 
         // Extract language.
         let language = match extract_sibling_text(&member_document, &language_selector, "Taal") {
@@ -437,26 +313,7 @@ async fn extract_members(
             })
             .unwrap_or_default();
 
-        let birth_date = if !birth_date_raw.is_empty() {
-            let parts: Vec<&str> = birth_date_raw.split_whitespace().collect();
-            if parts.len() == 3 {
-                if let (Ok(day), Some(month), Ok(year)) = (
-                    parts[0].parse::<u32>(),
-                    dutch_month_to_number(parts[1]),
-                    parts[2].parse::<i32>(),
-                ) {
-                    NaiveDate::from_ymd_opt(year, month, day)
-                        .map(|d| d.format("%Y-%m-%d").to_string())
-                        .unwrap_or_default()
-                } else {
-                    "".to_string()
-                }
-            } else {
-                "".to_string()
-            }
-        } else {
-            "".to_string()
-        };
+        let birth_date = parse_date(&birth_date_raw);
 
         // Extract start data.
         // Extract start date (e.g., sedert 5 december 2024)
@@ -466,24 +323,10 @@ async fn extract_members(
             .map(|el| el.text().collect::<String>())
             .unwrap_or_default();
 
-        let default_start_date = "2024-06-09".to_string();
+        let default_start_date = NaiveDate::from_ymd_opt(2024, 6, 9).unwrap();
         let start_date = if let Some(sedert_pos) = start_date_text.find("sedert") {
             let after_sedert = &start_date_text[sedert_pos + 6..]; // skip 'sedert'
-            let re = Regex::new(r"(\d{1,2}) (\w+) (\d{4})").unwrap();
-            if let Some(caps) = re.captures(after_sedert) {
-                let day = caps.get(1).unwrap().as_str().parse::<u32>().unwrap_or(0);
-                let month_str = caps.get(2).unwrap().as_str();
-                let year = caps.get(3).unwrap().as_str().parse::<i32>().unwrap_or(0);
-                if let Some(month) = dutch_month_to_number(month_str) {
-                    NaiveDate::from_ymd_opt(year, month, day)
-                        .map(|d| d.format("%Y-%m-%d").to_string())
-                        .unwrap_or_default()
-                } else {
-                    default_start_date
-                }
-            } else {
-                default_start_date
-            }
+            parse_date(after_sedert).unwrap_or(default_start_date)
         } else {
             default_start_date
         };
@@ -519,7 +362,7 @@ async fn extract_members(
             })
             .unwrap_or_else(|| "".to_string());
 
-        scrape_image(&client, &name, member_document, web_request_count).await?;
+        scrape_image(client, urls, &name, member_document).await?;
 
         let parts: Vec<&str> = name.split_whitespace().collect();
         let first_name = parts[0].to_string();
@@ -543,20 +386,22 @@ async fn extract_members(
         seen_members.insert(key);
 
         // Now push safely
-        member_ids.push(member_id.to_string());
-        session_ids.push(session_info.0.to_string());
-        first_names.push(first_name);
-        last_names.push(last_name);
-        genders.push(gender);
-        birth_dates.push(birth_date);
-        birth_places.push(birth_place);
-        languages.push(language);
-        constituencies.push(constituency);
-        parties.push(party);
-        fractions.push(fraction);
-        emails.push(email);
-        actives.push(session_info.1.to_string());
-        starts.push(start_date);
+        members.push(model::Member {
+            member_id: member_id.to_string(),
+            session_id: session_info.0.to_string(),
+            first_name,
+            last_name,
+            gender: non_empty(gender),
+            date_of_birth: birth_date,
+            place_of_birth: non_empty(birth_place),
+            language: non_empty(language),
+            constituency: non_empty(constituency),
+            party,
+            fraction: non_empty(fraction),
+            email: non_empty(email),
+            active: session_info.1,
+            start: Some(start_date),
+        });
 
         processed_rows += 1;
 
@@ -567,6 +412,11 @@ async fn extract_members(
     Ok(())
 }
 
+/// `None` for the empty strings of values that were not found on the page.
+fn non_empty(text: String) -> Option<String> {
+    Some(text).filter(|text| !text.is_empty())
+}
+
 fn calculate_hash<T: Hash>(t: &T) -> u64 {
     let mut s = DefaultHasher::new();
     t.hash(&mut s);
@@ -575,18 +425,18 @@ fn calculate_hash<T: Hash>(t: &T) -> u64 {
 
 async fn scrape_image(
     client: &ScrapingClient,
+    urls: &Urls,
     name: &String,
     member_document: Html,
-    web_request_count: &mut u32,
 ) -> Result<(), Box<dyn Error>> {
     let image_selector = Selector::parse("img[alt='Picture']").unwrap();
 
     if let Some(img_element) = member_document.select(&image_selector).next() {
         if let Some(img_src) = img_element.value().attr("src") {
-            let img_url = format!("https://www.dekamer.be{}", img_src);
+            let img_url = urls.dekamer_path(img_src);
 
             // Convert name to a file-safe format
-            let filename = slugify_name(name.as_str());
+            let filename = person_slug(name);
 
             let img_path = format!("./web/src/content/members/img/{}.jpg", filename);
 
@@ -602,7 +452,6 @@ async fn scrape_image(
 
                 // Download and save image
                 let img_bytes = client.get(&img_url).await?.bytes().await?;
-                *web_request_count += 1;
                 let mut file = File::create(&img_path).unwrap();
                 file.write_all(&img_bytes)?;
                 println!("Saved image: {}", img_path);
@@ -637,20 +486,7 @@ fn extract_sibling_text(
 }
 
 fn append_hardcoded_members(
-    session_ids: &mut Vec<String>,
-    member_ids: &mut Vec<String>,
-    first_names: &mut Vec<String>,
-    last_names: &mut Vec<String>,
-    genders: &mut Vec<String>,
-    birth_dates: &mut Vec<String>,
-    birth_places: &mut Vec<String>,
-    languages: &mut Vec<String>,
-    constituencies: &mut Vec<String>,
-    parties: &mut Vec<String>,
-    fractions: &mut Vec<String>,
-    emails: &mut Vec<String>,
-    actives: &mut Vec<String>,
-    starts: &mut Vec<String>,
+    members: &mut Vec<model::Member>,
     seen_members: &mut HashSet<(i32, u64)>,
 ) {
     let hardcoded_members = vec![
@@ -666,7 +502,7 @@ fn append_hardcoded_members(
             "Vooruit".to_string(),
             "".to_string(),
             "".to_string(),
-            "false".to_string(),
+            false,
         ),
         (
             56,
@@ -680,7 +516,7 @@ fn append_hardcoded_members(
             "N-VA".to_string(),
             "".to_string(),
             "".to_string(),
-            "false".to_string(),
+            false,
         ),
         (
             56,
@@ -694,7 +530,7 @@ fn append_hardcoded_members(
             "MR".to_string(),
             "".to_string(),
             "".to_string(),
-            "false".to_string(),
+            false,
         ),
         (
             56,
@@ -708,7 +544,7 @@ fn append_hardcoded_members(
             "MR".to_string(),
             "".to_string(),
             "".to_string(),
-            "false".to_string(),
+            false,
         ),
         (
             56,
@@ -722,7 +558,7 @@ fn append_hardcoded_members(
             "CD&V".to_string(),
             "".to_string(),
             "".to_string(),
-            "false".to_string(),
+            false,
         ),
     ];
 
@@ -756,19 +592,21 @@ fn append_hardcoded_members(
 
         seen_members.insert(key);
 
-        session_ids.push(session_id.to_string());
-        member_ids.push(member_id.to_string());
-        first_names.push(first);
-        last_names.push(last);
-        genders.push(gender);
-        birth_dates.push(birth_date);
-        birth_places.push(birth_place);
-        languages.push(lang);
-        constituencies.push(constituency);
-        parties.push(party);
-        fractions.push(fraction);
-        emails.push(email);
-        actives.push(active);
-        starts.push("NA".to_string());
+        members.push(model::Member {
+            member_id: member_id.to_string(),
+            session_id: session_id.to_string(),
+            first_name: first,
+            last_name: last,
+            gender: non_empty(gender),
+            date_of_birth: birth_date.parse().ok(),
+            place_of_birth: non_empty(birth_place),
+            language: non_empty(lang),
+            constituency: non_empty(constituency),
+            party,
+            fraction: non_empty(fraction),
+            email: non_empty(email),
+            active,
+            start: None,
+        });
     }
 }
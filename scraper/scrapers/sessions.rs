@@ -11,28 +11,22 @@
 //! csv = "1.1"
 //! chrono = "0.4"
 //! regex = "1"
-//! crawl = { path = "../../crawl" }
-//! parquet = "54.3.0"
-//! arrow = "54.3.0"
+//! crawl = { path = "../crawl" }
+//! model = { path = "../../model" }
 //! ```
 
-use arrow::array::StringArray;
-use arrow::datatypes::{DataType, Field, Schema};
-use arrow::record_batch::RecordBatch;
+use crawl::cache::CachePolicy;
 use crawl::client::ScrapingClient;
-use parquet::arrow::ArrowWriter;
+use crawl::urls::Urls;
+use model::{Session, Table};
 use scraper::{ElementRef, Html, Selector};
 use std::error::Error;
-use std::fs::File;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
 use std::path::PathBuf;
-use std::sync::Arc;
 use tokio::fs;
 use tokio::io::AsyncWriteExt;
 
-const URL: &'static str = "https://www.dekamer.be/kvvcr/showpage.cfm?section=/depute&language=nl&cfm=/site/wwwcfm/depute/cvlist54.cfm";
-
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
     // Create client.
@@ -40,57 +34,30 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Create folders.
     let root = PathBuf::from("./data");
-    let sessions_path = root.join("sessions/sessions.parquet");
+    let sessions_path = root.join("sessions").join(Session::FILE_NAME);
     if let Some(parent) = sessions_path.parent() {
         fs::create_dir_all(parent).await?;
     }
 
     // Scrape data.
-    let mut session_ids = vec![];
-    let mut start_dates_ = vec![];
-    let mut end_dates = vec![];
+    let mut sessions = vec![];
 
     // Fetch and parse the HTML content.
-    let response = client.get(&URL).await?;
-    let content = response.text().await?;
-    let document = Html::parse_document(&content);
+    let page = client
+        .fetch(&Urls::from_env().member_list(), CachePolicy::Revalidate)
+        .await?;
+    let document = Html::parse_document(&page.text());
 
-    extract_sessions(
-        document,
-        &mut session_ids,
-        &mut start_dates_,
-        &mut end_dates,
-    )
-    .await?;
+    extract_sessions(document, &mut sessions).await?;
 
     // Create parquet file.
-    let file = File::create(sessions_path).unwrap();
-    let schema = Arc::new(Schema::new(vec![
-        Field::new("session_id", DataType::Utf8, false),
-        Field::new("start_date", DataType::Utf8, false),
-        Field::new("end_date", DataType::Utf8, false),
-    ]));
-
-    let batch = RecordBatch::try_new(
-        schema.clone(),
-        vec![
-            Arc::new(StringArray::from(session_ids)),
-            Arc::new(StringArray::from(start_dates_)),
-            Arc::new(StringArray::from(end_dates)),
-        ],
-    )?;
-
-    let mut sessions = ArrowWriter::try_new(file, schema, None)?;
-    sessions.write(&batch)?;
-    sessions.close().unwrap();
+    Session::write(&sessions_path, &sessions)?;
 
     Ok(())
 }
 async fn extract_sessions(
     document: Html,
-    session_ids: &mut Vec<String>,
-    start_dates: &mut Vec<String>,
-    end_dates: &mut Vec<String>,
+    sessions: &mut Vec<Session>,
 ) -> Result<(), Box<dyn Error>> {
 
     // Extract sessions.
@@ -106,13 +73,18 @@ async fn extract_sessions(
 
                     // If there are start and end dates, we can split them
                     if dates.len() == 2 {
-                        let start_date = dates[0].trim().to_string();
-                        let end_date = dates[1].trim().to_string();
-
-                        // Push extracted information to the vectors
-                        session_ids.push(session_id.to_string());
-                        start_dates.push(start_date);
-                        end_dates.push(end_date);
+                        // The end year is missing for the current session.
+                        let start_date = dates[0].trim().parse();
+                        let end_date = dates[1].trim().parse().ok();
+
+                        // Push extracted information to the vector
+                        if let Ok(start_date) = start_date {
+                            sessions.push(Session {
+                                session_id: session_id.to_string(),
+                                start_date,
+                                end_date,
+                            });
+                        }
                     }
                 }
             }
@@ -207,7 +207,7 @@ export default async function (eleventyConfig) {
                 ...vote,
                 lang,
                 permalink:
-                  `/sessions/${meeting.session_id}/meetings/plenary/${meeting.meeting_id}/votes/${vote.vote_id}/`,
+                  `/sessions/${meeting.session_id}/meetings/plenary/${meeting.meeting_id}/votes/stemming-${vote.vote_id}/`,
               });
             }
           }
@@ -0,0 +1,51 @@
+import fs from "fs";
+import { readParquet } from "./parquet.js";
+
+// Titles the reports put before the name of a speaker, as in `crawl::utils::debate`.
+const TITLE =
+  /^(Vice-eersteminister|Eerste minister|Minister|Staatssecretaris|De heer|Mevrouw|Le ministre|La ministre|Monsieur|Madame)\s+/i;
+
+// The name a discussion shows for a speaker: "Voorzitter" for the chair, otherwise the label in the
+// report without title, party or function ("Minister Jan Jambon" and "Jan Jambon (N-VA)" are both
+// "Jan Jambon").
+function speakerName(label, role) {
+  if (role === "chair") {
+    return "Voorzitter";
+  }
+  return label.split(/[(,]/)[0].trim().replace(TITLE, "").trim();
+}
+
+// Key of the debate on a question in the map returned by `readDiscussions`.
+export function discussionKey(meetingType, sessionId, meetingId, questionId) {
+  return `${meetingType}/${sessionId}/${meetingId}/${questionId}`;
+}
+
+// Reads the debates on questions from `utterances.parquet`: for every question, its speech turns in
+// order, each with the name of the speaker and the paragraphs they said.
+export async function readDiscussions(connection, filePath) {
+  const discussions = new Map();
+  if (!fs.existsSync(filePath)) {
+    return discussions;
+  }
+
+  // Columns: session_id, meeting_id, meeting_type, question_id, sequence, speaker, member_id,
+  // minister, role, language, paragraphs, ...
+  const rows = await readParquet(connection, filePath);
+  rows.sort((a, b) => a[4] - b[4]);
+  rows.forEach((row) => {
+    const questionId = row[3];
+    if (questionId === null) {
+      return;
+    }
+
+    const key = discussionKey(row[2], row[0], row[1], questionId);
+    if (!discussions.has(key)) {
+      discussions.set(key, []);
+    }
+    discussions.get(key).push({
+      speaker: speakerName(row[5], row[8]),
+      paragraphs: row[10],
+    });
+  });
+  return discussions;
+}
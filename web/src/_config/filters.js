@@ -33,15 +33,25 @@ export default function (eleventyConfig) {
     return DateTime.fromJSDate(dateObj, { zone: "utc" }).toFormat("yyyy-LL-dd");
   });
 
+  // Missing dates become null, which `new Date` would turn into 1 January 1970.
+  function toDate(date) {
+    if (!date) return null;
+    const dateObj = new Date(date);
+    return isNaN(dateObj) ? null : dateObj;
+  }
+
   // Date Formatting Filter (dd/mm/yyyy)
   eleventyConfig.addFilter("date", function (date) {
+    const dateObj = toDate(date);
+    if (!dateObj) return "";
     const options = { year: "numeric", month: "long", day: "numeric" };
-    return new Intl.DateTimeFormat("nl-BE", options).format(new Date(date));
+    return new Intl.DateTimeFormat("nl-BE", options).format(dateObj);
   });
 
   // Date Formatting Filter (dd/mm/yy)
   eleventyConfig.addFilter("shortDate", function (date, format = "short") {
-    const dateObj = new Date(date);
+    const dateObj = toDate(date);
+    if (!dateObj) return "";
 
     if (format === "short") {
       const day = String(dateObj.getDate()).padStart(2, "0");
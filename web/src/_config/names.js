@@ -0,0 +1,23 @@
+// Slugs of people, matching `crawl::utils::names::person_slug` in the scrapers, so member lookups, URLs and
+// image names agree no matter how a name is spelled ("Benoît Lutgen", "Christoph D'Haese").
+const SPECIAL_LETTERS = {
+  ä: "ae", Ä: "Ae", ö: "oe", Ö: "Oe", ü: "ue", Ü: "Ue", ß: "ss",
+  æ: "ae", Æ: "Ae", œ: "oe", Œ: "Oe", ø: "o", Ø: "O", ł: "l", Ł: "L",
+  đ: "d", ð: "d", Đ: "D", Ð: "D", ı: "i",
+};
+
+export function slugify(text) {
+  return String(text ?? "")
+    .normalize("NFC")
+    .replace(/[äÄöÖüÜßæÆœŒøØłŁđðĐÐı]/g, (c) => SPECIAL_LETTERS[c])
+    .normalize("NFKD")
+    .replace(/\p{M}/gu, "")
+    .replace(/([a-z0-9])([A-Z])/g, "$1 $2")
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, "-")
+    .replace(/^-+|-+$/g, "");
+}
+
+export function personSlug(name) {
+  return slugify(String(name ?? "").replace(/,/g, " "));
+}
@@ -0,0 +1,35 @@
+// Reads the rows of a Parquet file in `src/data` as arrays of plain values, in the order of the columns.
+//
+// The files have typed columns (schema version 2, see `model::SCHEMA_VERSION`). Dates are returned as "2025-06-12",
+// times as "14h15", lists as arrays and large integers as numbers, so the pages can use them as they are. Missing
+// values are null.
+export async function readParquet(connection, filePath) {
+  const columns = (
+    await connection.runAndReadAll(`DESCRIBE SELECT * FROM read_parquet('${filePath}')`)
+  ).getRows();
+
+  const selects = columns.map(([name, type]) => {
+    const column = `"${name}"`;
+    if (type === "DATE") {
+      return `strftime(${column}, '%Y-%m-%d')`;
+    }
+    if (type === "TIME") {
+      return `lpad(hour(${column})::VARCHAR, 2, '0') || 'h' || lpad(minute(${column})::VARCHAR, 2, '0')`;
+    }
+    if (type.endsWith("[]")) {
+      return `to_json(${column})::VARCHAR`;
+    }
+    if (type === "BIGINT") {
+      return `${column}::DOUBLE`;
+    }
+    return column;
+  });
+  const isList = columns.map(([, type]) => type.endsWith("[]"));
+
+  const result = await connection.runAndReadAll(
+    `SELECT ${selects.join(", ")} FROM read_parquet('${filePath}')`,
+  );
+  return result
+    .getRows()
+    .map((row) => row.map((value, index) => (isList[index] && value !== null ? JSON.parse(value) : value)));
+}
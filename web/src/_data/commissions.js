@@ -1,5 +1,6 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
+import { readParquet } from '../_config/parquet.js';
 
 export default async function () {
   try {
@@ -13,8 +14,7 @@ export default async function () {
     const instance = await DuckDBInstance.create(':memory:');
     const connection = await instance.connect();
 
-    const commissionsResult = await connection.runAndReadAll(`SELECT * FROM read_parquet('${commissionsFilePath}')`);
-    const commissionsRows = commissionsResult.getRows();
+    const commissionsRows = await readParquet(connection, commissionsFilePath);
 
 
     let commissions = {}
@@ -23,6 +23,10 @@ export default async function () {
       const sessionId = row[0];
       const commissionsId = row[1];
 
+      if (row[2] === null) {
+        return; // the report does not exist
+      }
+
       if (!commissions[sessionId]) {
         commissions[sessionId] = [];
       }
@@ -1,5 +1,6 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
+import { readParquet } from '../_config/parquet.js';
 
 export default async function () {
     try {
@@ -23,31 +24,18 @@ export default async function () {
         const instance = await DuckDBInstance.create(':memory:');
         const connection = await instance.connect();
 
-        const readParquet = async (filePath) => {
-            const result = await connection.runAndReadAll(`SELECT * FROM read_parquet('${filePath}')`);
-            return result.getRows();
-        };
-
         const [
             dossiersRows,
             subdocumentsRows,
             membersRows,
             votesRows
         ] = await Promise.all([
-            readParquet(dossiersFilePath),
-            readParquet(subdocumentsFilePath),
-            readParquet(membersFilePath),
-            readParquet(votesFilePath)
+            readParquet(connection, dossiersFilePath),
+            readParquet(connection, subdocumentsFilePath),
+            readParquet(connection, membersFilePath),
+            readParquet(connection, votesFilePath)
         ]);
 
-        // Utility functions.
-        const convertDate = (rawDate) => {
-            if (!rawDate || typeof rawDate !== 'string') return null;
-            const [day, month, year] = rawDate.split('/');
-            if (!day || !month || !year) return null;
-            return `${year}-${month.padStart(2, '0')}-${day.padStart(2, '0')}`;
-        };
-
         // Lookup party by member.
         const memberPartyLookup = {};
         membersRows.forEach(row => {
@@ -59,6 +47,9 @@ export default async function () {
         // Lookup votes by dossier + subdocument.
         const votesByDossierAndDoc = {};
         votesRows.forEach(row => {
+            // Decisions without a roll call have no counts to show.
+            if (row[15] !== "roll_call") return;
+
             const dossierId = row[12];
             const documentIdRaw = String(row[13]);
             const docIdMatch = documentIdRaw.match(/(\d+)$/); // Extract final number
@@ -74,10 +65,12 @@ export default async function () {
                 no_count: row[7],
                 abstain_count: row[8],
                 dossier_id: row[12],
-                document_id: row[13]
+                document_id: row[13],
+                outcome: row[16],
+                majority: row[17]
             }
 
-            const key = `${dossierId}_${docId}`;
+            const key = `${row[1]}-${dossierId}_${docId}`;
 
             if (!votesByDossierAndDoc[key]) {
                 votesByDossierAndDoc[key] = [];
@@ -86,27 +79,22 @@ export default async function () {
             votesByDossierAndDoc[key].push(vote);
         });
 
-        // Map of dossier_id to its subdocuments
+        // Map of session_id + dossier_id to its subdocuments
         const subdocumentsByDossier = {};
         subdocumentsRows.forEach(row => {
-            const dossierId = row[0];
+            const dossierId = `${row[5]}-${row[0]}`;
 
             const subdocId = row[1];
             const voteKey = `${dossierId}_${subdocId}`;
 
             const subdoc = {
                 id: row[1],
-                date: convertDate(row[2]),
+                date: row[2],
                 type: row[3],
-                authors: row[4]
-                    ? row[4].split(',').map(author => {
-                        const name = author.trim();
-                        return {
-                            name,
-                            party: (memberPartyLookup[name] || "Unknown").trim()
-                        };
-                    })
-                    : [],
+                authors: row[4].map(name => ({
+                    name,
+                    party: (memberPartyLookup[name] || "Unknown").trim()
+                })),
                 votes: votesByDossierAndDoc[voteKey] || []
             };
 
@@ -127,21 +115,16 @@ export default async function () {
             const dossier = {
                 dossier_id: dossierId,
                 title: row[2],
-                authors: row[3]
-                    ? row[3].split(',').map(author => {
-                        const name = author.trim();
-                        return {
-                            name,
-                            party: (memberPartyLookup[name] || "Unknown").trim()
-                        };
-                    })
-                    : [],
-                submission_date: convertDate(row[4]),
-                end_date: convertDate(row[5]),
-                vote_date: convertDate(row[6]),
+                authors: row[3].map(name => ({
+                    name,
+                    party: (memberPartyLookup[name] || "Unknown").trim()
+                })),
+                submission_date: row[4],
+                end_date: row[5],
+                vote_date: row[6],
                 document_type: row[7],
                 status: row[8],
-                subdocuments: subdocumentsByDossier[dossierId] || []
+                subdocuments: subdocumentsByDossier[`${sessionId}-${dossierId}`] || []
             };
 
             if (!dossiersBySession[sessionId]) {
@@ -148,6 +148,9 @@ export default {
     VotedOn: "Gestemd op",
     passed: "aangenomen",
     failed: "verworpen",
+    twoThirdsMajorityRequired: "Hiervoor was een tweederdemeerderheid nodig.",
+    specialMajorityRequired: "Hiervoor was een bijzondere meerderheid nodig, met een meerderheid in elke taalgroep.",
+    tallyMismatch: "Let op: de namen in het verslag komen niet overeen met het aantal stemmen, dus de aantallen en namen hieronder kunnen fouten bevatten.",
     withoutSubject: "zonder onderwerp",
     Results: "Resultaten",
     BACK: "TERUG",
@@ -170,6 +173,24 @@ export default {
     Aangenomen: "Aangenomen",
     Verworpen: "Verworpen",
     VoorstelOnderzoekscommissie: "Voorstel tot onderzoekscommissie",
+    Begroting: "Begroting",
+    Kaft: "Kaft",
+    NietGeevoceerdOntwerp: "Niet-geëvoceerd ontwerp",
+    AanvullendVerslag: "Aanvullend verslag",
+    VerwijzendVerslag: "Verwijzend verslag",
+    OpmerkingenVanHetRekenhof: "Opmerkingen van het Rekenhof",
+    ArtikelenBijEersteStemmingAangenomen: "Artikelen bij eerste stemming aangenomen",
+    ArtikelenAangenomenInPlenum: "Artikelen aangenomen in plenum",
+    Beleidsnota: "Beleidsnota",
+    Verantwoording: "Verantwoording",
+    TabellenOfLijsten: "Tabellen of lijsten",
+    Naturalisatielijsten: "Naturalisatielijsten",
+    Bijlage: "Bijlage",
+    Errata: "Errata",
+    ZonderVoorwerp: "Zonder voorwerp",
+    Ingetrokken: "Ingetrokken",
+    Vervallen: "Vervallen",
+    Onbekend: "Onbekend",
     LOBBYREGISTRY: "HET LOBBYREGISTER",
   },
   fr: {
@@ -262,8 +283,43 @@ export default {
     VotedOn: "A voté",
     passed: "adoptée",
     failed: "rejetée",
+    twoThirdsMajorityRequired: "Une majorité des deux tiers était requise.",
+    specialMajorityRequired: "Une majorité spéciale était requise, avec une majorité dans chaque groupe linguistique.",
+    tallyMismatch: "Attention : les noms du compte rendu ne correspondent pas au nombre de voix, les chiffres et les noms ci-dessous peuvent donc contenir des erreurs.",
     Results: "Résultats",
     BACK: "RETOUR",
     ALLMEETINGS: "TOUTES SESSIONS",
+    Begroting: "Budget",
+    WetsOntwerp: "Projet de loi",
+    OvergezondenOntwerp: "Projet transmis",
+    WetsVoorstel: "Proposition de loi",
+    VoorstelVanResolutie: "Proposition de résolution",
+    VoorstelTotHerziening: "Proposition de révision",
+    Kaft: "Couverture",
+    VoorstelOnderzoekscommissie: "Proposition de commission d'enquête",
+    VoorstelReglement: "Proposition de modification du règlement",
+    Verslag: "Rapport",
+    NietGeevoceerdOntwerp: "Projet non évoqué",
+    AanvullendVerslag: "Rapport complémentaire",
+    VerwijzendVerslag: "Rapport (renvoi)",
+    Amendement: "Amendement",
+    Advies: "Avis",
+    AdviesVanDeRaadVanState: "Avis du Conseil d'État",
+    OpmerkingenVanHetRekenhof: "Commentaires de la Cour des comptes",
+    ArtikelenBijEersteStemmingAangenomen: "Articles adoptés en première lecture",
+    ArtikelenAangenomenInPlenum: "Articles adoptés en séance plénière",
+    AangenomenTekst: "Texte adopté",
+    Beleidsnota: "Note de politique générale",
+    Verantwoording: "Justification",
+    TabellenOfLijsten: "Tableaux ou listes",
+    Naturalisatielijsten: "Listes de naturalisations",
+    Bijlage: "Annexe",
+    Errata: "Errata",
+    Aangenomen: "Adopté",
+    Verworpen: "Rejeté",
+    ZonderVoorwerp: "Sans objet",
+    Ingetrokken: "Retiré",
+    Vervallen: "Caduc",
+    Onbekend: "Inconnu",
   },
 };
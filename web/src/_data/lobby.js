@@ -1,5 +1,6 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
+import { readParquet } from '../_config/parquet.js';
 
 export default async function () {
   try {
@@ -13,8 +14,7 @@ export default async function () {
     const instance = await DuckDBInstance.create(':memory:');
     const connection = await instance.connect();
 
-    const lobbyResult = await connection.runAndReadAll(`SELECT * FROM read_parquet('${lobbyFilePath}')`);
-    const lobbyRows = lobbyResult.getRows();
+    const lobbyRows = await readParquet(connection, lobbyFilePath);
 
     let lobbies = [];
 
@@ -1,6 +1,8 @@
 import { DuckDBInstance } from "@duckdb/node-api";
 import fs from "fs";
 import crypto from "crypto";
+import { discussionKey, readDiscussions } from "../_config/discussions.js";
+import { readParquet } from "../_config/parquet.js";
 export default async function () {
   try {
     const meetingsFilePath = "src/data/meetings.parquet";
@@ -12,6 +14,8 @@ export default async function () {
     const membersFilePath = "src/data/members.parquet";
     const summariesFilePath = "src/data/summaries.parquet";
     const dossiersFilePath = "src/data/dossiers.parquet";
+    const idMappingsFilePath = "src/data/id_mappings.parquet";
+    const utterancesFilePath = "src/data/utterances.parquet";
 
     const allFilesExist = [
       meetingsFilePath,
@@ -23,6 +27,7 @@ export default async function () {
       membersFilePath,
       summariesFilePath,
       dossiersFilePath,
+      idMappingsFilePath,
     ].every(fs.existsSync);
 
     if (!allFilesExist) {
@@ -33,13 +38,6 @@ export default async function () {
     const instance = await DuckDBInstance.create(":memory:");
     const connection = await instance.connect();
 
-    const readParquet = async (filePath) => {
-      const result = await connection.runAndReadAll(
-        `SELECT * FROM read_parquet('${filePath}')`,
-      );
-      return result.getRows();
-    };
-
     const [
       meetingsRows,
       commissionsRows,
@@ -50,17 +48,20 @@ export default async function () {
       membersRows,
       summariesRows,
       dossiersRows,
+      idMappingsRows,
     ] = await Promise.all([
-      readParquet(meetingsFilePath),
-      readParquet(commissionsFilePath),
-      readParquet(votesFilePath),
-      readParquet(questionsFilePath),
-        readParquet(commissionQuestionsFilePath),
-      readParquet(propositionsFilePath),
-      readParquet(membersFilePath),
-      readParquet(summariesFilePath),
-      readParquet(dossiersFilePath),
+      readParquet(connection, meetingsFilePath),
+      readParquet(connection, commissionsFilePath),
+      readParquet(connection, votesFilePath),
+      readParquet(connection, questionsFilePath),
+      readParquet(connection, commissionQuestionsFilePath),
+      readParquet(connection, propositionsFilePath),
+      readParquet(connection, membersFilePath),
+      readParquet(connection, summariesFilePath),
+      readParquet(connection, dossiersFilePath),
+      readParquet(connection, idMappingsFilePath),
     ]);
+    const discussions = await readDiscussions(connection, utterancesFilePath);
 
     // LOOKUP TABLS
     const summaryByHash = {};
@@ -72,7 +73,7 @@ export default async function () {
     dossiersRows.forEach((row) => {
       // Adjust these indices as per your schema
       const id = row[1]; // dossier_id
-      const authors = row[3]; // comma-separated string or array
+      const authors = row[3]; // array of names
       const document_type = row[7]; // e.g., "Proposal"
       const status = row[8]; // e.g., "Adopted"
 
@@ -93,7 +94,7 @@ export default async function () {
 
     // all members
     const activeMembers = membersRows
-        .filter(row => row[12] === "true")
+        .filter(row => row[12] === true)
         .map(row => {
           const name = row[2] + " " + row[3];
           return {
@@ -111,6 +112,20 @@ export default async function () {
       meetingDateMap.set(key, date);
     });
 
+    // Votes used to be numbered by their position in the meeting, redirect those URLs to the vote.
+    const voteRedirects = new Map();
+    idMappingsRows.forEach((row) => {
+      const [sessionId, meetingId, kind, oldId, newId] = row;
+      if (kind !== "vote") return;
+      const key = `${sessionId}-${meetingId}-${newId}`;
+      if (!voteRedirects.has(key)) {
+        voteRedirects.set(key, []);
+      }
+      voteRedirects
+        .get(key)
+        .push(`/sessions/${sessionId}/meetings/plenary/${meetingId}/votes/${oldId}/`);
+    });
+
     const commissionDateMap = new Map();
     commissionsRows.forEach((row) => {
       const sessionId = row[0];
@@ -163,6 +178,10 @@ export default async function () {
         commissionName, // e.g. "Finance-Budget"
       ] = row;
 
+      if (date === null) {
+        return; // the report does not exist
+      }
+
       if (!meetings[sessionId]) meetings[sessionId] = [];
 
       meetings[sessionId].push({
@@ -179,15 +198,10 @@ export default async function () {
         propositions: [],
         votes: [],
         allVotes: [],
-        chairs: row[7]
-                    ? row[7].split(',').map(chair => {
-                        const name = chair.trim();
-                        return {
-                            name,
-                            party: (memberPartyLookup[name] || "Unknown").trim()
-                        };
-                    })
-                    : [],
+        chairs: row[7].map(name => ({
+                    name,
+                    party: (memberPartyLookup[name] || "Unknown").trim()
+                })),
       });
     });
 
@@ -204,41 +218,35 @@ export default async function () {
           (meeting) => meeting.meeting_id === meetingId && meeting.type == "plenary",
         );
         if (meeting) {
-          const questioners = row[3].split(",").map((q) => {
-            const name = q.trim();
-            return {
-              name: name,
-              party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
-            };
-          });
+          const questioners = row[3].map((name) => ({
+            name: name,
+            party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
+          }));
 
-          const respondents = row[4].split(",").map((q) => {
-            const name = q.trim();
-            return {
-              name: name,
-              party: memberPartyLookup[name] || "Unknown",
-            };
-          });
+          const respondents = row[4].map((name) => ({
+            name: name,
+            party: memberPartyLookup[name] || "Unknown",
+          }));
 
-          const topics_nl = row[5].split(";").map((t) => t.trim());
-          const topics_fr = row[6].split(";").map((t) => t.trim());
+          const topics_nl = row[5].map((t) => t.trim());
+          const topics_fr = row[6].map((t) => t.trim());
 
-          const rawTopicsNl = row[5];
+          // Summaries are keyed by the topics joined with ';'.
+          const rawTopicsNl = row[5].join(";");
           const hash = hashText(rawTopicsNl);
 
           const topics_summary_nl = summaryByHash[hash] || null;
 
-          const discussion = JSON.parse(row[7]).map(discussionItem => ({
+          const discussion = (
+            discussions.get(discussionKey("plenary", sessionId, meetingId, row[0])) || []
+          ).map((turn) => ({
             speaker: {
-              name: discussionItem.speaker,
-              party: memberPartyLookup[discussionItem.speaker] || "Unknown"
+              name: turn.speaker,
+              party: memberPartyLookup[turn.speaker] || "Unknown",
             },
-            text: discussionItem.text
+            paragraphs: turn.paragraphs,
           }));
 
-
-          const discussion_ids = row[8].split(",").map((d) => d.trim());
-
           meeting.questions.push({
             type: 'plenary',
             question_id: row[0],
@@ -252,7 +260,6 @@ export default async function () {
             topics_summary_nl: topics_summary_nl,
             topics_summary_fr: topics_summary_nl,
             discussion: discussion,
-            discussion_ids: discussion_ids,
           });
         }
       }
@@ -262,10 +269,6 @@ export default async function () {
       const sessionId = row[1];
       const meetingId = row[2];
 
-      if (sessionId === '404') {
-        return; // skip
-      }
-
       const keyForDate = `${sessionId}-${meetingId}`;
       const date = commissionDateMap.get(keyForDate) || null;
 
@@ -275,41 +278,35 @@ export default async function () {
           (meeting) => meeting.meeting_id === meetingId && meeting.type == "commission",
         );
         if (meeting) {
-          const questioners = row[3].split(",").map((q) => {
-            const name = q.trim();
-            return {
-              name: name,
-              party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
-            };
-          });
+          const questioners = row[3].map((name) => ({
+            name: name,
+            party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
+          }));
 
-          const respondents = row[4].split(",").map((q) => {
-            const name = q.trim();
-            return {
-              name: name,
-              party: memberPartyLookup[name] || "Unknown",
-            };
-          });
+          const respondents = row[4].map((name) => ({
+            name: name,
+            party: memberPartyLookup[name] || "Unknown",
+          }));
 
-          const topics_nl = row[5].split(";").map((t) => t.trim());
-          const topics_fr = row[6].split(";").map((t) => t.trim());
+          const topics_nl = row[5].map((t) => t.trim());
+          const topics_fr = row[6].map((t) => t.trim());
 
-          const rawTopicsNl = row[5];
+          // Summaries are keyed by the topics joined with ';'.
+          const rawTopicsNl = row[5].join(";");
           const hash = hashText(rawTopicsNl);
 
           const topics_summary_nl = summaryByHash[hash] || null;
 
-          const discussion = JSON.parse(row[7]).map(discussionItem => ({
+          const discussion = (
+            discussions.get(discussionKey("commission", sessionId, meetingId, row[0])) || []
+          ).map((turn) => ({
             speaker: {
-              name: discussionItem.speaker,
-              party: memberPartyLookup[discussionItem.speaker] || "Unknown"
+              name: turn.speaker,
+              party: memberPartyLookup[turn.speaker] || "Unknown",
             },
-            text: discussionItem.text
+            paragraphs: turn.paragraphs,
           }));
 
-
-          const discussion_ids = row[8].split(",").map((d) => d.trim());
-
           meeting.questions.push({
             type: 'commission',
             question_id: row[0],
@@ -323,7 +320,6 @@ export default async function () {
             topics_summary_nl: topics_summary_nl,
             topics_summary_fr: topics_summary_nl,
             discussion: discussion,
-            discussion_ids: discussion_ids
           });
         }
       }
@@ -344,13 +340,10 @@ export default async function () {
       const dossierData = dossierById[dossier_id] || {};
 
       const authors =
-        dossierData.authors?.split(",").map((q) => {
-          const name = q.trim();
-          return {
-            name: name,
-            party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
-          };
-        }) || [];
+        dossierData.authors?.map((name) => ({
+          name: name,
+          party: memberPartyLookup[name] || "Unknown", // Same logic as in votes
+        })) || [];
 
       if (meetings[sessionId]) {
         const meeting = meetings[sessionId].find(
@@ -388,20 +381,17 @@ export default async function () {
       });
 
     votesRows.forEach((row) => {
+      // Decisions without a roll call have no counts or members to show.
+      if (row[15] !== "roll_call") return;
+
       const sessionId = row[1];
       const meetingId = row[2];
       const title_nl = row[4];
       const title_fr = row[5];
 
-      const yesMembers = row[9]
-        ? row[9].split(",").map((member) => member.trim())
-        : [];
-      const noMembers = row[10]
-        ? row[10].split(",").map((member) => member.trim())
-        : [];
-      const abstainMembers = row[11]
-        ? row[11].split(",").map((member) => member.trim())
-        : [];
+      const yesMembers = row[9];
+      const noMembers = row[10];
+      const abstainMembers = row[11];
 
       const yesMembersWithParty = yesMembers.map((member) => ({
         name: member,
@@ -473,6 +463,11 @@ export default async function () {
             grouped_votes_by_party: groupedVotesByParty,
             dossier_id: row[12],
             document_id: row[13],
+            outcome: row[16],
+            majority: row[17],
+            // Missing in files written before the tallies were checked.
+            tally_mismatch: row[18] === true,
+            redirect_from: voteRedirects.get(`${sessionId}-${meetingId}-${row[0]}`) || [],
           };
 
           // Add vote to proposition if it matches by session and dossier
@@ -3,6 +3,9 @@ import fs from "fs";
 
 import path from "path";
 import { fileURLToPath } from "url";
+import { personSlug } from "../_config/names.js";
+import { discussionKey, readDiscussions } from "../_config/discussions.js";
+import { readParquet } from "../_config/parquet.js";
 
 const __filename = fileURLToPath(import.meta.url);
 const __dirname = path.dirname(__filename);
@@ -25,6 +28,7 @@ export default async function () {
     const propositionsFilePath = "src/data/propositions.parquet";
     const dossiersFilePath = "src/data/dossiers.parquet";
     const subdocumentsFilePath = "src/data/subdocuments.parquet";
+    const utterancesFilePath = "src/data/utterances.parquet";
 
     if (
       !fs.existsSync(membersFilePath) ||
@@ -45,55 +49,20 @@ export default async function () {
     const instance = await DuckDBInstance.create(":memory:");
     const connection = await instance.connect();
 
-    const membersResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${membersFilePath}')`,
+    const membersRows = await readParquet(connection, membersFilePath);
+    const remunerationsRows = await readParquet(connection, remunerationsFilePath);
+    const questionsRows = await readParquet(connection, questionsFilePath);
+    const commissionQuestionsRows = await readParquet(connection, commissionQuestionsFilePath);
+    const meetingsRows = await readParquet(connection, meetingsFilePath);
+    const commissionsRows = await readParquet(connection, commissionsFilePath);
+    // Decisions without a roll call don't say how each member voted.
+    const votesRows = (await readParquet(connection, votesFilePath)).filter(
+      (row) => row[15] === "roll_call",
     );
-    const membersRows = membersResult.getRows();
-
-    const remunerationsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${remunerationsFilePath}')`,
-    );
-    const remunerationsRows = remunerationsResult.getRows();
-
-    const questionsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${questionsFilePath}')`,
-    );
-    const questionsRows = questionsResult.getRows();
-
-    const commissionQuestionsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${commissionQuestionsFilePath}')`,
-    );
-    const commissionQuestionsRows = commissionQuestionsResult.getRows();
-
-    const meetingsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${meetingsFilePath}')`,
-    );
-    const meetingsRows = meetingsResult.getRows();
-
-    const commissionsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${commissionsFilePath}')`,
-    );
-    const commissionsRows = commissionsResult.getRows();
-
-    const votesResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${votesFilePath}')`,
-    );
-    const votesRows = votesResult.getRows();
-
-    const propositionsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${propositionsFilePath}')`,
-    );
-    const propositionsRows = propositionsResult.getRows();
-
-    const dossiersResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${dossiersFilePath}')`,
-    );
-    const dossiersRows = dossiersResult.getRows();
-
-    const subdocumentsResult = await connection.runAndReadAll(
-      `SELECT * FROM read_parquet('${subdocumentsFilePath}')`,
-    );
-    const subdocumentsRows = subdocumentsResult.getRows();
+    const propositionsRows = await readParquet(connection, propositionsFilePath);
+    const dossiersRows = await readParquet(connection, dossiersFilePath);
+    const subdocumentsRows = await readParquet(connection, subdocumentsFilePath);
+    const discussions = await readDiscussions(connection, utterancesFilePath);
 
     /* Date map for plenary meetings. */
     const meetingDateMap = new Map();
@@ -118,13 +87,6 @@ export default async function () {
     const memberMap = new Map();
     const currentDate = new Date(); // Get the current date for age calculation
 
-    const convertDate = (rawDate) => {
-      if (!rawDate || typeof rawDate !== "string") return null;
-      const [day, month, year] = rawDate.split("/");
-      if (!day || !month || !year) return null;
-      return `${year}-${month.padStart(2, "0")}-${day.padStart(2, "0")}`;
-    };
-
     const dossierById = {};
     const dossierMap = new Map();
     dossiersRows.forEach((dossier) => {
@@ -134,10 +96,8 @@ export default async function () {
       const title = dossier[2];
       const status = dossier[8];
       const document_type = dossier[7];
-      const vote_date = convertDate(dossier[6]);
-      const authors = dossier[3]
-        ?.split(",")
-        .map((a) => a.trim().toLowerCase().replace(" ", "-")) || [];
+      const vote_date = dossier[6];
+      const authors = dossier[3].map((a) => personSlug(a));
       dossierMap.set(dossierId, { sessionId, title, authors });
 
       dossierById[id] = {
@@ -152,10 +112,10 @@ export default async function () {
     membersRows.forEach((row) => {
       const firstName = row[2];
       const lastName = row[3];
-      const key = `${firstName}-${lastName}`.toLowerCase();
+      const key = personSlug(`${firstName} ${lastName}`);
 
       if (!memberMap.has(key)) {
-        const birthDate = new Date(row[5]);
+        const birthDate = row[5] ? new Date(row[5]) : null;
         let age = null;
         if (birthDate instanceof Date && !isNaN(birthDate)) {
           age = currentDate.getFullYear() - birthDate.getFullYear(); // Start by calculating the year difference
@@ -240,10 +200,7 @@ export default async function () {
 
     // ADD SUBDOCUMENTS TO MEMBERS
     subdocumentsRows.forEach((subdoc) => {
-      const authorsRaw = subdoc[4]; // Adjust this index if needed
-      const authors = authorsRaw
-        .split(",")
-        .map((name) => name.trim().toLowerCase().replace(/\s+/g, "-"));
+      const authors = subdoc[4].map((name) => personSlug(name));
 
       //console.log(authors);
       authors.forEach((authorKey) => {
@@ -263,7 +220,7 @@ export default async function () {
     remunerationsRows.forEach((remuneration) => {
       const firstName = remuneration[0];
       const lastName = remuneration[1];
-      const key = `${firstName}-${lastName}`.toLowerCase();
+      const key = personSlug(`${firstName} ${lastName}`);
 
       if (memberMap.has(key)) {
         const member = memberMap.get(key);
@@ -279,8 +236,8 @@ export default async function () {
         const entry = {
           mandate: remuneration[3],
           institute: remuneration[4],
-          remuneration_min: parseFloat(remuneration[5]) || 0,
-          remuneration_max: parseFloat(remuneration[6]) || 0,
+          remuneration_min: remuneration[5] ?? 0,
+          remuneration_max: remuneration[6] ?? 0,
         };
 
         member.remunerations[year].entries.push(entry);
@@ -304,33 +261,25 @@ export default async function () {
       const keyForDate = `${sessionId}-${meetingId}`;
       const date = meetingDateMap.get(keyForDate) || null;
 
-      const questioners = question[3].split(",").map((q) => {
-        const name = q.trim();
-        return {
-          name: name,
-          party: memberPartyLookup[name] || "Unknown",
-        };
-      });
+      const questioners = question[3].map((name) => ({
+        name: name,
+        party: memberPartyLookup[name] || "Unknown",
+      }));
 
-      const respondents = question[4].split(",").map((q) => {
-        const name = q.trim();
-        return {
-          name: name,
-          party: memberPartyLookup[name] || "Unknown",
-        };
-      });
+      const respondents = question[4].map((name) => ({
+        name: name,
+        party: memberPartyLookup[name] || "Unknown",
+      }));
 
-      const topics_nl = question[5].split(";").map((topic) => topic.trim());
-      const topics_fr = question[6].split(";").map((topic) => topic.trim());
+      const topics_nl = question[5].map((topic) => topic.trim());
+      const topics_fr = question[6].map((topic) => topic.trim());
 
-      const discussion = JSON.parse(question[7]).map((discussionItem) => ({
-        speaker: discussionItem.speaker,
-        text: discussionItem.text,
-      }));
+      const discussion =
+        discussions.get(discussionKey("plenary", sessionId, meetingId, questionId)) || [];
 
       // Add to questioners
       questioners.forEach((q, i) => {
-        const key = q.name.toLowerCase().replace(" ", "-");
+        const key = personSlug(q.name);
         const topic_nl = topics_nl[i] || null;
         const topic_fr = topics_fr[i] || null;
 
@@ -356,7 +305,7 @@ export default async function () {
 
       // Add to respondents
       respondents.forEach((r) => {
-        const key = r.name.toLowerCase().replace(" ", "-");
+        const key = personSlug(r.name);
         const topic_nl = topics_nl[0] || null;
         const topic_fr = topics_fr[0] || null;
 
@@ -384,42 +333,30 @@ export default async function () {
     /* Go through commission questions. */
     commissionQuestionsRows.forEach((question) => {
       const questionId = question[0];
-      if (questionId === "404") {
-        // TODO: why 404s?
-        return;
-      }
       const sessionId = question[1];
       const meetingId = question[2];
       const keyForDate = `${sessionId}-${meetingId}`;
       const date = commissionDateMap.get(keyForDate) || null;
 
-      const questioners = question[3].split(",").map((q) => {
-        const name = q.trim();
-        return {
-          name: name,
-          party: memberPartyLookup[name] || "Unknown",
-        };
-      });
+      const questioners = question[3].map((name) => ({
+        name: name,
+        party: memberPartyLookup[name] || "Unknown",
+      }));
 
-      const respondents = question[4].split(",").map((q) => {
-        const name = q.trim();
-        return {
-          name: name,
-          party: memberPartyLookup[name] || "Unknown",
-        };
-      });
+      const respondents = question[4].map((name) => ({
+        name: name,
+        party: memberPartyLookup[name] || "Unknown",
+      }));
 
-      const topics_nl = question[5].split(";").map((topic) => topic.trim());
-      const topics_fr = question[6].split(";").map((topic) => topic.trim());
+      const topics_nl = question[5].map((topic) => topic.trim());
+      const topics_fr = question[6].map((topic) => topic.trim());
 
-      const discussion = JSON.parse(question[7]).map((discussionItem) => ({
-        speaker: discussionItem.speaker,
-        text: discussionItem.text,
-      }));
+      const discussion =
+        discussions.get(discussionKey("commission", sessionId, meetingId, questionId)) || [];
 
       // Add to questioners
       questioners.forEach((q, i) => {
-        const key = q.name.toLowerCase().replace(" ", "-");
+        const key = personSlug(q.name);
         const topic_nl = topics_nl[i] || null;
         const topic_fr = topics_fr[i] || null;
 
@@ -445,7 +382,7 @@ export default async function () {
 
       // Add to respondents
       respondents.forEach((r) => {
-        const key = r.name.toLowerCase().replace(" ", "-");
+        const key = personSlug(r.name);
         const topic_nl = topics_nl[0] || null;
         const topic_fr = topics_fr[0] || null;
 
@@ -472,15 +409,9 @@ export default async function () {
 
     // Handling votes
     votesRows.forEach((vote) => {
-      const membersYes = vote[9]
-        .split(",")
-        .map((name) => name.trim().toLowerCase().replace(" ", "-"));
-      const membersNo = vote[10]
-        .split(",")
-        .map((name) => name.trim().toLowerCase().replace(" ", "-"));
-      const membersAbstain = vote[11]
-        .split(",")
-        .map((name) => name.trim().toLowerCase().replace(" ", "-"));
+      const membersYes = vote[9].map((name) => personSlug(name));
+      const membersNo = vote[10].map((name) => personSlug(name));
+      const membersAbstain = vote[11].map((name) => personSlug(name));
 
       // Group members by their party
       const partyVotes = new Map();
@@ -556,7 +487,7 @@ export default async function () {
     const partyStats = new Map();
 
     members.forEach((member) => {
-      if (member.active === "false") return;
+      if (!member.active) return;
       member.parties.forEach((party) => {
         if (!partyStats.has(party)) {
           partyStats.set(party, {
@@ -592,7 +523,7 @@ export default async function () {
     const voteToNum = { yes: 1, no: -1, abstain: 0 };
 
     // Filter only active members
-    const activeMembers = members.filter((m) => m.active !== "false");
+    const activeMembers = members.filter((m) => m.active);
 
     // Collect all unique vote titles
     const allVoteIds = Array.from(
@@ -1,6 +1,9 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
 import crypto from 'crypto';
+import { personSlug } from '../_config/names.js';
+import { discussionKey, readDiscussions } from '../_config/discussions.js';
+import { readParquet } from '../_config/parquet.js';
 
 const meetingsFilePath = 'src/data/meetings.parquet';
 const votesFilePath = 'src/data/votes.parquet';
@@ -9,6 +12,7 @@ const propositionsFilePath = 'src/data/propositions.parquet';
 const membersFilePath = 'src/data/members.parquet';
 const summariesFilePath = 'src/data/summaries.parquet';
 const dossiersFilePath = 'src/data/dossiers.parquet';
+const utterancesFilePath = 'src/data/utterances.parquet';
 
 export default async function () {
   try {
@@ -27,12 +31,13 @@ export default async function () {
     const connection = await instance.connect();
 
     const [membersRows, questionsRows, propositionsRows, dossiersRows, meetingsRows] = await Promise.all([
-      connection.runAndReadAll(`SELECT * FROM read_parquet('${membersFilePath}')`).then(r => r.getRows()),
-      connection.runAndReadAll(`SELECT * FROM read_parquet('${questionsFilePath}')`).then(r => r.getRows()),
-      connection.runAndReadAll(`SELECT * FROM read_parquet('${propositionsFilePath}')`).then(r => r.getRows()),
-      connection.runAndReadAll(`SELECT * FROM read_parquet('${dossiersFilePath}')`).then(r => r.getRows()),
-      connection.runAndReadAll(`SELECT * FROM read_parquet('${meetingsFilePath}')`).then(r => r.getRows()),
+      readParquet(connection, membersFilePath),
+      readParquet(connection, questionsFilePath),
+      readParquet(connection, propositionsFilePath),
+      readParquet(connection, dossiersFilePath),
+      readParquet(connection, meetingsFilePath),
     ]);
+    const discussions = await readDiscussions(connection, utterancesFilePath);
 
     const parties = {};
     const memberPartyMap = {};
@@ -53,8 +58,7 @@ export default async function () {
       const firstName = row[2];
       const lastName = row[3];
 
-      const name = `${firstName} ${lastName}`.toLowerCase();
-      const key = name.trim().toLowerCase().replace(/\s+/g, '-');
+      const key = personSlug(`${firstName} ${lastName}`);
       const party = row[9];
       const active = row[12];
       const date_of_birth = row[5];
@@ -88,22 +92,14 @@ export default async function () {
     });
 
     // Build dossier lookup for propositions (document type, status, vote date, authors)
-    const convertDate = (rawDate) => {
-      if (!rawDate || typeof rawDate !== 'string') return null;
-      const [day, month, year] = rawDate.split('/');
-      if (!day || !month || !year) return null;
-      return `${year}-${month.padStart(2, '0')}-${day.padStart(2, '0')}`;
-    };
-
     const dossierById = {};
     dossiersRows.forEach((dossier) => {
       const id = dossier[1];
       const document_type = dossier[7];
       const status = dossier[8];
-      const vote_date = convertDate(dossier[6]);
-      const authors = (dossier[3] || '')
-        .split(',')
-        .map((a) => a.trim().toLowerCase().replace(/\s+/g, '-'))
+      const vote_date = dossier[6];
+      const authors = dossier[3]
+        .map((a) => personSlug(a))
         .filter(Boolean);
       dossierById[id] = {
         authors,
@@ -118,22 +114,18 @@ export default async function () {
       const questionId = q[0];
       const sessionId = q[1];
       const meetingId = q[2];
-      const rawQuestioners = q[3]?.split(',') || [];
-      const rawRespondents = q[4]?.split(',') || [];
-      const topicsNl = q[5]?.split(';').map(t => t.trim()) || [];
-      const topicsFr = q[6]?.split(';').map(t => t.trim()) || [];
-      const rawTopicsNl = q[5];
-      const discussion = JSON.parse(q[7] || '[]').map(discussionItem => ({
-        speaker: discussionItem.speaker,
-        text: discussionItem.text
-      }));
-      const discussionIds = q[8]?.split(',').map(d => d.trim()) || [];
+      const rawQuestioners = q[3];
+      const rawRespondents = q[4];
+      const topicsNl = q[5].map(t => t.trim());
+      const topicsFr = q[6].map(t => t.trim());
+      const rawTopicsNl = q[5].join(';');
+      const discussion = discussions.get(discussionKey('plenary', sessionId, meetingId, questionId)) || [];
 
       const keyForDate = `${sessionId}-${meetingId}`;
       const date = meetingDateMap.get(keyForDate) || null;
 
       rawQuestioners.forEach((name, index) => {
-        const trimmed = name.trim().toLowerCase().replace(/\s+/g, '-');
+        const trimmed = personSlug(name);
         const party = memberPartyMap[trimmed];
       
         if (party && parties[party]) {
@@ -145,16 +137,15 @@ export default async function () {
             type: 'plenary', // FIXME: always plenary? no?
             questioners: rawQuestioners.map((name, idx) => ({
               name: name.trim(),
-              party: memberPartyMap[name.trim().toLowerCase().replace(/\s+/g, '-')] || "Unknown"
+              party: memberPartyMap[personSlug(name)] || "Unknown"
             })),
             respondents: rawRespondents.map(name => ({
               name: name.trim(),
-              party: memberPartyMap[name.trim().toLowerCase().replace(/\s+/g, '-')] || "Unknown"
+              party: memberPartyMap[personSlug(name)] || "Unknown"
             })),
             topics_nl: [topicsNl[index]].filter(Boolean),  // Only include matching topic
             topics_fr: [topicsFr[index]].filter(Boolean),
             discussion: discussion,
-            discussion_ids: discussionIds,
             date: date
           };
       
@@ -1,6 +1,7 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
 import crypto from 'crypto';
+import { readParquet } from '../_config/parquet.js';
 export default async function () {
     try {
         const propositionsFilePath = 'src/data/propositions.parquet';
@@ -25,11 +26,6 @@ export default async function () {
         const instance = await DuckDBInstance.create(':memory:');
         const connection = await instance.connect();
 
-        const readParquet = async (filePath) => {
-            const result = await connection.runAndReadAll(`SELECT * FROM read_parquet('${filePath}')`);
-            return result.getRows();
-        };
-
         const [
             propositionsRows,
             meetingsRows,
@@ -37,11 +33,11 @@ export default async function () {
             membersRows,
             summariesRows,
         ] = await Promise.all([
-            readParquet(propositionsFilePath),
-            readParquet(meetingsFilePath),
-            readParquet(dossiersFilePath),
-            readParquet(membersFilePath),
-            readParquet(summariesFilePath),
+            readParquet(connection, propositionsFilePath),
+            readParquet(connection, meetingsFilePath),
+            readParquet(connection, dossiersFilePath),
+            readParquet(connection, membersFilePath),
+            readParquet(connection, summariesFilePath),
         ]);
 
         // Build summary lookup.
@@ -59,29 +55,18 @@ export default async function () {
         });
         
         
-        // Utility functions
-        const convertDate = (rawDate) => {
-            if (!rawDate || typeof rawDate !== 'string') return null;
-            const [day, month, year] = rawDate.split('/');
-            if (!day || !month || !year) return null;
-            return `${year}-${month.padStart(2, '0')}-${day.padStart(2, '0')}`;
-        };
-
         // Lookup dossier by id.
         const dossierById = {};
         dossiersRows.forEach(row => {
 
-            const authors = row[3].split(",").map(q => {
-                const name = q.trim();
-                return {
-                    name: name,
-                    party: memberPartyLookup[name] || "Unknown" // Same logic as in votes
-                };
-            });
+            const authors = row[3].map(name => ({
+                name: name,
+                party: memberPartyLookup[name] || "Unknown" // Same logic as in votes
+            }));
             
             const id = row[1];   
             const document_type = row[7];
-            const vote_date = convertDate(row[6]);
+            const vote_date = row[6];
             const status = row[8];
 
             dossierById[id] = {
@@ -1,6 +1,8 @@
 import { DuckDBInstance } from '@duckdb/node-api';
 import fs from 'fs';
 import crypto from 'crypto';
+import { discussionKey, readDiscussions } from '../_config/discussions.js';
+import { readParquet } from '../_config/parquet.js';
 
 export default async function () {
     try {
@@ -10,6 +12,7 @@ export default async function () {
            const commissionsFilePath = "src/data/commissions.parquet";
         const membersFilePath = 'src/data/members.parquet';
         const summariesFilePath = 'src/data/summaries.parquet';
+        const utterancesFilePath = 'src/data/utterances.parquet';
 
         const allFilesExist = [
             questionsFilePath,
@@ -28,30 +31,6 @@ export default async function () {
         const instance = await DuckDBInstance.create(':memory:');
         const connection = await instance.connect();
 
-        const readParquet = async (filePath) => {
-            const result = await connection.runAndReadAll(`SELECT * FROM read_parquet('${filePath}')`);
-            return result.getRows();
-        };
-
-        function safeParseDiscussion(raw, questionId) {
-  try {
-    // If DuckDB already returned a JS object/array, keep it
-    const parsed = typeof raw === 'string' ? JSON.parse(raw) : raw;
-
-    if (Array.isArray(parsed)) {
-      return parsed;            // ✔️ happy path
-    }
-
-    // If we get here the JSON was valid but not an array
-    console.warn(`question ${questionId}: discussion is not an array, skipping`);
-    return [];
-  } catch (e) {
-    console.warn(`question ${questionId}: cannot parse discussion – ${e.message}`);
-    return [];
-  }
-}
-
-
         const [
             questionsRows,
               commissionQuestionsRows,
@@ -60,13 +39,14 @@ export default async function () {
             membersRows,
             summariesRows,
         ] = await Promise.all([
-            readParquet(questionsFilePath),
-             readParquet(commissionQuestionsFilePath),
-            readParquet(meetingsFilePath),
-               readParquet(commissionsFilePath),
-            readParquet(membersFilePath),
-            readParquet(summariesFilePath),
+            readParquet(connection, questionsFilePath),
+            readParquet(connection, commissionQuestionsFilePath),
+            readParquet(connection, meetingsFilePath),
+            readParquet(connection, commissionsFilePath),
+            readParquet(connection, membersFilePath),
+            readParquet(connection, summariesFilePath),
         ]);
+        const discussions = await readDiscussions(connection, utterancesFilePath);
 
             // Build summary lookup.
     const summaryByHash = {};
@@ -113,36 +93,31 @@ export default async function () {
             const date = meetingDateMap.get(keyForDate) || null;
 
 
-            const discussion = JSON.parse(row[7]).map(discussionItem => ({
+            const discussion = (discussions.get(discussionKey('plenary', sessionId, meetingId, row[0])) || []).map(turn => ({
                 speaker: {
-                    name: discussionItem.speaker,
-                    party: memberPartyLookup[discussionItem.speaker] || "Unknown"
+                    name: turn.speaker,
+                    party: memberPartyLookup[turn.speaker] || "Unknown"
                   },
-                text: discussionItem.text
+                paragraphs: turn.paragraphs
             }));
 
-            const topics_nl = row[5].split(";").map(t => t.trim());
-            const topics_fr = row[6].split(";").map(t => t.trim());
+            const topics_nl = row[5].map(t => t.trim());
+            const topics_fr = row[6].map(t => t.trim());
 
-            const rawTopicsNl = row[5];
+            // Summaries are keyed by the topics joined with ';'.
+            const rawTopicsNl = row[5].join(";");
             const hash = hashText(rawTopicsNl);
             const topics_summary_nl = summaryByHash[hash] || null;
 
-            const questioners = row[3].split(",").map(q => {
-                const name = q.trim();
-                return {
-                  name: name,
-                  party: memberPartyLookup[name] || "Unknown"
-                };
-              });
-
-            const respondents = row[4].split(",").map(q => {
-                const name = q.trim();
-                return {
-                  name: name,
-                  party: memberPartyLookup[name] || "Unknown"
-                };
-              });
+            const questioners = row[3].map(name => ({
+                name: name,
+                party: memberPartyLookup[name] || "Unknown"
+              }));
+
+            const respondents = row[4].map(name => ({
+                name: name,
+                party: memberPartyLookup[name] || "Unknown"
+              }));
 
             const question = {
                 type: "plenary",
@@ -167,44 +142,35 @@ export default async function () {
             const sessionId = row[1];
             const meetingId = row[2];
 
-            if (sessionId === '404') {
-              return; // skip
-            }
-
             const keyForDate = `${sessionId}-${meetingId}`;
             const date = commissionDateMap.get(keyForDate) || null;
 
-            const discussion = JSON.parse(row[7]).map(discussionItem => ({
+            const discussion = (discussions.get(discussionKey('commission', sessionId, meetingId, row[0])) || []).map(turn => ({
                 speaker: {
-                    name: discussionItem.speaker,
-                    party: memberPartyLookup[discussionItem.speaker] || "Unknown"
+                    name: turn.speaker,
+                    party: memberPartyLookup[turn.speaker] || "Unknown"
                   },
-                text: discussionItem.text
+                paragraphs: turn.paragraphs
             }));
 
 
-            const topics_nl = row[5].split(";").map(t => t.trim());
-            const topics_fr = row[6].split(";").map(t => t.trim());
+            const topics_nl = row[5].map(t => t.trim());
+            const topics_fr = row[6].map(t => t.trim());
 
-            const rawTopicsNl = row[5];
+            // Summaries are keyed by the topics joined with ';'.
+            const rawTopicsNl = row[5].join(";");
             const hash = hashText(rawTopicsNl);
             const topics_summary_nl = summaryByHash[hash] || null;
 
-            const questioners = row[3].split(",").map(q => {
-                const name = q.trim();
-                return {
-                  name: name,
-                  party: memberPartyLookup[name] || "Unknown"
-                };
-              });
-
-            const respondents = row[4].split(",").map(q => {
-                const name = q.trim();
-                return {
-                  name: name,
-                  party: memberPartyLookup[name] || "Unknown"
-                };
-              });
+            const questioners = row[3].map(name => ({
+                name: name,
+                party: memberPartyLookup[name] || "Unknown"
+              }));
+
+            const respondents = row[4].map(name => ({
+                name: name,
+                party: memberPartyLookup[name] || "Unknown"
+              }));
 
             const question = {
                 type: "commission",
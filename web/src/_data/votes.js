@@ -1,6 +1,7 @@
 import { DuckDBInstance } from "@duckdb/node-api";
 import fs from "fs";
 import crypto from "crypto";
+import { readParquet } from "../_config/parquet.js";
 export default async function () {
   try {
     const votesFilePath = "src/data/votes.parquet";
@@ -17,22 +18,17 @@ export default async function () {
     const instance = await DuckDBInstance.create(":memory:");
     const connection = await instance.connect();
 
-    const readParquet = async (filePath) => {
-      const result = await connection.runAndReadAll(
-        `SELECT * FROM read_parquet('${filePath}')`,
-      );
-      return result.getRows();
-    };
-
     const [
       votesRows,
     ] = await Promise.all([
-      readParquet(votesFilePath),
+      readParquet(connection, votesFilePath),
     ]);
 
     const votes = [];
 
     votesRows.forEach((row) => {
+      // Only roll-call votes are counted, members vote in those.
+      if (row[15] !== "roll_call") return;
       votes.push(row[0]);
     });
 